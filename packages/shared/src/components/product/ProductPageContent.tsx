@@ -1,5 +1,9 @@
-import React, { useState, useEffect, useCallback } from "react";
-import type { Project } from "../../types/project";
+import React, { useState, useEffect, useCallback, useRef } from "react";
+import type {
+  InteractionEvent,
+  Project,
+  RemoteNavigation,
+} from "../../types/project";
 import { usePageNavigation } from "../../hooks/usePageNavigation";
 import ConfirmDialog from "../common/ConfirmDialog";
 import ControlOverlay from "./ControlOverlay";
@@ -20,6 +24,12 @@ export interface ProductPageContentProps {
   isExporting?: boolean; // 내보내기 진행 중 여부 (옵션)
   emptyMessage?: string; // 페이지가 없을 때 메시지 (옵션)
   skipEntryPage?: boolean; // 대문페이지 건너뛰기 (미리보기용)
+  onPageChange?: (pageIndex: number, pageId?: string) => void; // 현재 페이지 변경 알림 (옵션)
+  onInteraction?: (event: InteractionEvent) => void; // 방문자 상호작용 알림 (옵션)
+  onActivity?: () => void; // 터치/클릭/키 입력 알림 (대기 시간 측정용, 옵션)
+  idleResetSignal?: number; // 값이 바뀌면 대기 화면으로 복귀 (옵션)
+  remoteNavigation?: RemoteNavigation; // 원격 제어 이동 명령 (옵션)
+  muted?: boolean; // 영상 음소거 (옵션)
 }
 
 export const ProductPageContent: React.FC<ProductPageContentProps> = ({
@@ -33,6 +43,12 @@ export const ProductPageContent: React.FC<ProductPageContentProps> = ({
   isExporting,
   emptyMessage = "페이지가 없습니다",
   skipEntryPage = false,
+  onPageChange,
+  onInteraction,
+  onActivity,
+  idleResetSignal = 0,
+  remoteNavigation,
+  muted = false,
 }) => {
   const {
     currentPageIndex,
@@ -55,6 +71,36 @@ export const ProductPageContent: React.FC<ProductPageContentProps> = ({
     null,
   );
 
+  // 마지막 터치/클릭 위치 (화면 대비 %, 탭 기록용)
+  const lastPointerRef = useRef({ x: 0, y: 0 });
+
+  const handlePointerDown = useCallback(
+    (e: React.PointerEvent) => {
+      lastPointerRef.current = {
+        x: (e.clientX / window.innerWidth) * 100,
+        y: (e.clientY / window.innerHeight) * 100,
+      };
+      onActivity?.();
+    },
+    [onActivity],
+  );
+
+  const reportTap = useCallback(
+    (target: "button" | "touchArea", targetId: string) => {
+      const page = project.pages[currentPageIndex];
+      if (!page) return;
+      onInteraction?.({
+        type: "tap",
+        pageIndex: currentPageIndex,
+        pageId: page.id,
+        target,
+        targetId,
+        ...lastPointerRef.current,
+      });
+    },
+    [project.pages, currentPageIndex, onInteraction],
+  );
+
   const handleDebugInfoUpdate = useCallback((info: VideoDebugInfo) => {
     setVideoDebugInfo(info);
   }, []);
@@ -90,9 +136,62 @@ export const ProductPageContent: React.FC<ProductPageContentProps> = ({
     }
   };
 
+  // 현재 페이지 변경 알림 (플레이어의 다음 페이지 미디어 프리페치용)
+  useEffect(() => {
+    onPageChange?.(currentPageIndex, project.pages[currentPageIndex]?.id);
+  }, [currentPageIndex, project.pages, onPageChange]);
+
+  // 대기 시간 초과 시 대기 화면으로 복귀 (지정 페이지 또는 대문페이지)
+  useEffect(() => {
+    if (idleResetSignal === 0) return;
+
+    setExitConfirm(false);
+    const attractPageId = project.settings.attractPageId;
+    if (attractPageId !== undefined && attractPageId !== "") {
+      goToPage(parseInt(attractPageId));
+    } else {
+      goToHome();
+      if (!skipEntryPage) setShowEntryPage(true);
+    }
+  }, [idleResetSignal]);
+
+  // 원격 제어 이동 (대문페이지가 표시 중이면 닫고 재생 시작)
+  useEffect(() => {
+    if (!remoteNavigation) return;
+
+    setExitConfirm(false);
+    if (showEntryPage) {
+      setShowEntryPage(false);
+      setResumePlaybackSignal((prev) => prev + 1);
+    }
+
+    if (remoteNavigation.type === "next") {
+      goToNextPage();
+    } else if (remoteNavigation.type === "home") {
+      goToHome();
+    } else if (remoteNavigation.pageId !== undefined) {
+      const pageId = remoteNavigation.pageId;
+      const index = project.pages.findIndex((page) => page.id === pageId);
+      goToPage(index >= 0 ? index : parseInt(pageId));
+    }
+  }, [remoteNavigation?.seq]);
+
+  // 페이지 진입 기록 (대문페이지가 표시된 동안은 제외)
+  useEffect(() => {
+    const page = project.pages[currentPageIndex];
+    if (showEntryPage || !page) return;
+    onInteraction?.({
+      type: "pageEnter",
+      pageIndex: currentPageIndex,
+      pageId: page.id,
+    });
+  }, [currentPageIndex, showEntryPage, project.pages, onInteraction]);
+
   // 키보드 이벤트 처리
   useEffect(() => {
     const handleKeyPress = (e: KeyboardEvent) => {
+      onActivity?.();
+
       // Meta+1 (Mac) 또는 Ctrl+1 (Windows)로 전체화면 토글
       if ((e.metaKey || e.ctrlKey) && e.key === "1") {
         e.preventDefault();
@@ -131,6 +230,7 @@ export const ProductPageContent: React.FC<ProductPageContentProps> = ({
     goToPreviousPage,
     goToHome,
     skipEntryPage,
+    onActivity,
   ]);
 
   const handleVideoEnd = useCallback(() => {
@@ -146,6 +246,7 @@ export const ProductPageContent: React.FC<ProductPageContentProps> = ({
       const button = page.buttons.find((b) => b.id === buttonId);
 
       if (!button) return;
+      reportTap("button", button.id);
 
       if (button.action.type === "next") {
         goToNextPage();
@@ -157,7 +258,7 @@ export const ProductPageContent: React.FC<ProductPageContentProps> = ({
         goToPage(targetIndex);
       }
     },
-    [project.pages, currentPageIndex, goToNextPage, goToPage],
+    [project.pages, currentPageIndex, goToNextPage, goToPage, reportTap],
   );
 
   const handleTouchAreaClick = useCallback(
@@ -166,6 +267,7 @@ export const ProductPageContent: React.FC<ProductPageContentProps> = ({
       const touchArea = page.touchAreas.find((t) => t.id === touchAreaId);
 
       if (!touchArea) return;
+      reportTap("touchArea", touchArea.id);
 
       if (touchArea.action.type === "next") {
         goToNextPage();
@@ -177,21 +279,25 @@ export const ProductPageContent: React.FC<ProductPageContentProps> = ({
         goToPage(targetIndex);
       }
     },
-    [project.pages, currentPageIndex, goToNextPage, goToPage],
+    [project.pages, currentPageIndex, goToNextPage, goToPage, reportTap],
   );
 
   const handleStartClick = useCallback(() => {
+    onInteraction?.({ type: "sessionStart" });
     setShowEntryPage(false);
     // 사용자 클릭 이후 재생 재시도를 위해 신호 증가
     setResumePlaybackSignal((prev) => prev + 1);
-  }, []);
+  }, [onInteraction]);
   // 페이지 없음
   if (project.pages.length === 0) {
     return <ErrorScreen title="페이지가 없습니다" message={emptyMessage} />;
   }
 
   return (
-    <div className="relative h-screen w-screen overflow-hidden bg-black">
+    <div
+      className="relative h-screen w-screen overflow-hidden bg-black"
+      onPointerDown={handlePointerDown}
+    >
       {/* 종료 확인 다이얼로그 */}
       <ConfirmDialog
         isOpen={exitConfirm}
@@ -250,6 +356,7 @@ export const ProductPageContent: React.FC<ProductPageContentProps> = ({
                   isCurrentPage ? handleDebugInfoUpdate : undefined
                 }
                 totalPages={project.pages.length}
+                muted={muted}
               />
             </div>
           );
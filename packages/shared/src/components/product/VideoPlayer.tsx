@@ -22,6 +22,7 @@ type VideoPlayerProps = {
   showDebugInfo?: boolean;
   onDebugInfoUpdate?: (info: VideoDebugInfo) => void;
   totalPages?: number;
+  muted?: boolean;
 };
 
 const VideoPlayer: React.FC<VideoPlayerProps> = ({
@@ -36,6 +37,7 @@ const VideoPlayer: React.FC<VideoPlayerProps> = ({
   showDebugInfo = false,
   onDebugInfoUpdate,
   totalPages = 0,
+  muted = false,
 }) => {
   const videoRef = useRef<HTMLVideoElement>(null);
   const imageRef = useRef<HTMLImageElement>(null);
@@ -43,6 +45,13 @@ const VideoPlayer: React.FC<VideoPlayerProps> = ({
   const [currentPlayCount, setCurrentPlayCount] = useState(0); // 현재 재생 횟수
   const [loopCount, setLoopCount] = useState(0); // 반복 횟수 (loop 모드용)
 
+  // 음소거 (muted 속성은 마운트 후 변경이 반영되지 않으므로 직접 설정)
+  useEffect(() => {
+    if (videoRef.current) {
+      videoRef.current.muted = muted;
+    }
+  }, [muted, mediaUrl]);
+
   // 활성 상태가 되면 비디오 재생, 비활성화되면 일시정지
   useEffect(() => {
     if (page.mediaType === "video" && videoRef.current && mediaUrl) {
@@ -35,3 +35,10 @@ export {
   type RecentFile,
 } from "./utils/recentFiles";
 export { setFavicon, setDocumentTitle } from "./utils/windowUtils";
+export {
+  LOCALE_LABELS,
+  getDefaultLocale,
+  getProjectLocales,
+  localizeProject,
+  collectProjectAssetIds,
+} from "./utils/localization";
@@ -4,6 +4,8 @@ export interface Project {
   description: string;
   appIcon?: string;
   appTitle: string;
+  /** 언어별 앱 타이틀 (기본 언어는 appTitle) */
+  localizedAppTitle?: Record<string, string>;
   pages: Page[];
   settings: ProjectSettings;
   createdAt: number;
@@ -14,11 +16,122 @@ export interface ProjectSettings {
   windowWidth: number;
   windowHeight: number;
   fullscreen: boolean;
+  display?: string; // 표시할 모니터 ("primary", "largest", "largest-touch", 번호 또는 이름 일부)
   exitKey?: string;
   showProgress: boolean;
   showHomeButton: boolean;
   showBackButton: boolean;
   loopAtEnd: boolean;
+  idleTimeoutSeconds?: number; // 입력이 없으면 대기 화면으로 돌아갈 시간 (0 또는 없음 = 사용 안 함)
+  attractPageId?: string; // 대기 화면 페이지 인덱스 (없으면 대문페이지)
+  kiosk?: KioskSettings;
+  watchdog?: WatchdogSettings;
+  /** 여러 튜토리얼을 함께 빌드했을 때 이 튜토리얼을 자동으로 재생할 일정 */
+  schedule?: ScheduleRule[];
+  /** 제공하는 언어 (예: ["ko", "en", "zh", "ja"], 없으면 단일 언어) */
+  locales?: string[];
+  /** 기본 언어 (페이지의 기본 내용이 이 언어, 없으면 locales의 첫 번째) */
+  defaultLocale?: string;
+  controlApi?: ControlApiSettings;
+  osc?: OscSettings;
+  hotFolder?: HotFolderSettings;
+  heartbeat?: HeartbeatSettings;
+  /** 플레이어 로그 수준 (기본 info, 실행 인자 --log-level이 우선) */
+  logLevel?: LogLevel;
+}
+
+/** 로그 파일에 기록할 최소 수준 */
+export type LogLevel = "error" | "warn" | "info" | "debug" | "trace";
+
+/** 플레이어 상태 보고 (주기적으로 상태 파일을 쓰고 모니터링 서버로 전송) */
+export interface HeartbeatSettings {
+  enabled: boolean;
+  /** 기록 주기 (초, 기본 60, 최소 5) */
+  intervalSeconds?: number;
+  /** 상태를 POST할 주소 (http://만 지원, 비우면 파일에만 기록) */
+  endpoint?: string;
+  /** Authorization: Bearer 헤더로 보낼 토큰 (루프백 주소로만 전송) */
+  token?: string;
+}
+
+/**
+ * 핫 폴더 콘텐츠 업데이트 (배포된 플레이어가 폴더의 새 데이터 팩을 받아 적용)
+ * 빌드 서명 키로 서명되고 재생 중인 콘텐츠와 프로젝트 ID가 같은 팩만 적용
+ */
+export interface HotFolderSettings {
+  enabled: boolean;
+  /** 감시할 폴더 (로컬 또는 연결된 네트워크 공유 폴더) */
+  path: string;
+  /** 확인 주기 (초, 기본 60, 최소 10) */
+  checkIntervalSeconds?: number;
+}
+
+/** OSC(UDP) 연동 설정 (쇼 컨트롤 시스템과 동기화) */
+export interface OscSettings {
+  enabled: boolean;
+  /** 수신할 인터페이스 주소 (기본 127.0.0.1) */
+  bind?: string;
+  /** 수신 포트 (기본 9000) */
+  port?: number;
+  /** 주소 접두사 (기본 /tutorial, 예: /tutorial/goto) */
+  prefix?: string;
+  /** 페이지가 바뀌면 {prefix}/page 메시지를 보낼 대상 ("호스트:포트") */
+  sendTo?: string;
+  /** 메시지를 받을 송신 IP 목록 (비어 있으면 모든 주소) */
+  allowFrom?: string[];
+}
+
+/** 원격 제어 API 설정 (내보낸 플레이어의 HTTP/WebSocket 서버) */
+export interface ControlApiSettings {
+  enabled: boolean;
+  /** 수신할 인터페이스 주소 (기본 127.0.0.1) */
+  bind?: string;
+  /** 포트 (기본 8765) */
+  port?: number;
+  /** 접근 토큰 (127.0.0.1 외의 주소에서 수신하려면 필수) */
+  token?: string;
+}
+
+/** 원격 제어로 받은 이동 명령 (seq가 바뀔 때마다 실행) */
+export interface RemoteNavigation {
+  seq: number;
+  type: "goto" | "next" | "home";
+  /** 이동할 페이지 ID 또는 0부터 시작하는 번호 (goto) */
+  pageId?: string;
+}
+
+/** 자동 재생 일정 (플레이어 PC의 현지 시각 기준, 지정하지 않은 항목은 제한 없음) */
+export interface ScheduleRule {
+  /** 요일 (0 = 일요일 ~ 6 = 토요일) */
+  weekdays?: number[];
+  /** 시작/종료 시각 ("HH:MM", 종료가 시작보다 이르면 자정을 넘김) */
+  startTime?: string;
+  endTime?: string;
+  /** 시작/종료 날짜 ("YYYY-MM-DD", 종료일 포함, 요일/시간 일정보다 우선) */
+  startDate?: string;
+  endDate?: string;
+}
+
+/** 자동 재시작 설정 (내보낸 플레이어를 감시 프로세스로 실행) */
+export interface WatchdogSettings {
+  /** 비정상 종료하거나 화면이 멈추면 다시 실행 */
+  enabled: boolean;
+  /** 하트비트가 이 시간 동안 없으면 멈춘 것으로 판단 (초, 기본 30, 최소 15) */
+  heartbeatTimeoutSeconds?: number;
+  /** restartWindowSeconds 안에서 허용하는 최대 재시작 횟수 (기본 5) */
+  maxRestarts?: number;
+  /** 재시작 횟수를 세는 기간 (초, 기본 600) */
+  restartWindowSeconds?: number;
+}
+
+/** 키오스크 잠금 설정 (내보낸 플레이어에서 적용) */
+export interface KioskSettings {
+  /** 창 닫기 차단, 항상 위 전체화면 유지 */
+  enabled: boolean;
+  /** 관리자 PIN의 Argon2 해시 (PHC 문자열, 없으면 종료 키만으로 종료) */
+  adminPinHash?: string;
+  /** 입력이 없으면 커서를 숨길 시간 (초, 0 또는 없음 = 항상 표시) */
+  hideCursorSeconds?: number;
 }
 
 /** 영상 압축 품질 */
@@ -34,6 +147,32 @@ export interface CompressionSettings {
   maxHeight?: number;
 }
 
+/** 콘텐츠 암호화 설정 */
+export interface EncryptionSettings {
+  /** 암호화 활성화 여부 */
+  enabled: boolean;
+  /** 잠금 해제 암호. 없으면 키를 실행 파일에 난독화하여 내장 */
+  passphrase?: string;
+}
+
+/** 라이선스 조건 (사용 기간/허용 PC) */
+export interface LicenseSettings {
+  /** 사용 시작 시각 (Unix ms) */
+  notBefore?: number;
+  /** 만료 시각 (Unix ms) */
+  notAfter?: number;
+  /** 실행을 허용할 머신 ID 목록. 비어 있으면 제한 없음 */
+  machineIds: string[];
+}
+
+/** 데이터 팩 분리 설정 (exe + .pak/볼륨 파일) */
+export interface SplitSettings {
+  /** exe 대신 옆의 데이터 팩에 콘텐츠 기록 */
+  enabled: boolean;
+  /** 볼륨 크기 (MB). 없으면 하나의 .pak 파일 */
+  volumeSizeMb?: number;
+}
+
 export interface Page {
   id: string;
   title: string;
@@ -44,6 +183,17 @@ export interface Page {
   playCount?: number;
   buttons: PageButton[];
   touchAreas: TouchArea[];
+  /** 언어별로 바꿀 내용 (기본 언어 외의 언어만) */
+  localized?: Record<string, PageLocalization>;
+}
+
+/** 언어별 페이지 내용 (없는 항목은 기본 내용을 그대로 사용) */
+export interface PageLocalization {
+  title?: string;
+  mediaId?: string;
+  mediaType?: "video" | "image";
+  /** 버튼 ID별 이미지 */
+  buttonImages?: Record<string, string>;
 }
 
 export interface PageButton {
@@ -69,6 +219,21 @@ export interface NavigationAction {
   targetPageId?: string;
 }
 
+// 플레이어에서 기록하는 방문자 상호작용 (x, y는 화면 대비 %)
+export type InteractionEvent =
+  | { type: "sessionStart" }
+  | { type: "pageEnter"; pageIndex: number; pageId: string }
+  | {
+      type: "tap";
+      pageIndex: number;
+      pageId: string;
+      target: "button" | "touchArea";
+      targetId: string;
+      x: number;
+      y: number;
+    }
+  | { type: "idleReset"; pageIndex: number; pageId: string };
+
 export interface StoredMedia {
   id: string;
   name: string;
@@ -90,20 +255,48 @@ export interface BuildProject extends Omit<Project, "appIcon"> {
   appIconBase64?: string;
 }
 
+/** 임베딩된 엔트리의 저장 인코딩 */
+export type PayloadEncoding = "zstd";
+
 export interface MediaManifestEntry {
   id: string;
   name: string;
   mimeType: string;
   offset: number;
+  /** 저장된 바이트 수 (압축된 경우 압축 후 크기) */
+  size: number;
+  encoding?: PayloadEncoding;
+  /** 압축 전 원본 크기 (압축된 경우에만) */
+  originalSize?: number;
+  /** 변환 전 원본 소스의 SHA-256 (패치 시 변경 여부 판단용) */
+  sourceHash?: string;
+}
+
+/** 여러 튜토리얼을 함께 넣은 빌드의 프로젝트 엔트리 */
+export interface ProjectManifestEntry {
+  id: string;
+  name: string;
+  offset: number;
   size: number;
+  encoding?: PayloadEncoding;
+  schedule?: ScheduleRule[];
 }
 
 export interface BuildManifest {
   projectJsonOffset: number;
   projectJsonSize: number;
+  projectJsonEncoding?: PayloadEncoding;
+  /** 함께 넣은 모든 프로젝트 (2개 이상일 때만, 미디어는 공유) */
+  projects?: ProjectManifestEntry[];
+  /** 함께 넣은 프로젝트가 제공하는 언어 */
+  locales?: string[];
   media: MediaManifestEntry[];
   appIconOffset?: number;
   appIconSize?: number;
+  /** 임베딩 데이터 시작 오프셋 (viewer exe의 크기) */
+  payloadOffset?: number;
+  /** 콘텐츠 버전 (빌드/패치 시각, ms) */
+  contentVersion?: number;
 }
 
 export interface MediaBuildInfo {
@@ -0,0 +1,124 @@
+import { describe, it, expect } from "vitest";
+import type { Project } from "../types/project";
+import {
+  collectProjectAssetIds,
+  getProjectLocales,
+  localizeProject,
+} from "./localization";
+
+const createProject = (
+  overrides: Partial<Project["settings"]> = {},
+): Project => ({
+  id: "project-1",
+  name: "프로젝트",
+  description: "",
+  appTitle: "안내",
+  localizedAppTitle: { en: "Guide" },
+  pages: [
+    {
+      id: "page-1",
+      title: "시작",
+      order: 0,
+      mediaType: "video",
+      mediaId: "media-ko",
+      playType: "loop",
+      buttons: [
+        {
+          id: "button-1",
+          imageId: "button-ko",
+          position: { x: 10, y: 10 },
+          size: { width: 10, height: 10 },
+          action: { type: "next" },
+          showTiming: "immediate",
+        },
+      ],
+      touchAreas: [],
+      localized: {
+        en: {
+          title: "Start",
+          mediaId: "media-en",
+          mediaType: "image",
+          buttonImages: { "button-1": "button-en" },
+        },
+        ja: { title: "スタート" },
+      },
+    },
+    {
+      id: "page-2",
+      title: "공통",
+      order: 1,
+      mediaType: "image",
+      mediaId: "media-shared",
+      playType: "loop",
+      buttons: [],
+      touchAreas: [],
+    },
+  ],
+  settings: {
+    windowWidth: 1920,
+    windowHeight: 1080,
+    fullscreen: true,
+    showProgress: false,
+    showHomeButton: false,
+    showBackButton: false,
+    loopAtEnd: true,
+    locales: ["ko", "en", "ja"],
+    defaultLocale: "ko",
+    ...overrides,
+  },
+  createdAt: 0,
+  updatedAt: 0,
+});
+
+describe("localization", () => {
+  describe("getProjectLocales", () => {
+    it("기본 언어를 첫 번째로 반환해야 한다", () => {
+      const project = createProject({ defaultLocale: "en" });
+      expect(getProjectLocales(project)).toEqual(["en", "ko", "ja"]);
+    });
+
+    it("언어 설정이 없으면 빈 배열을 반환해야 한다", () => {
+      const project = createProject({
+        locales: undefined,
+        defaultLocale: undefined,
+      });
+      expect(getProjectLocales(project)).toEqual([]);
+    });
+  });
+
+  describe("localizeProject", () => {
+    it("기본 언어는 프로젝트를 그대로 반환해야 한다", () => {
+      const project = createProject();
+      expect(localizeProject(project, "ko")).toBe(project);
+      expect(localizeProject(project)).toBe(project);
+    });
+
+    it("번역된 미디어, 버튼 이미지, 제목으로 바꿔야 한다", () => {
+      const localized = localizeProject(createProject(), "en");
+      expect(localized.appTitle).toBe("Guide");
+      expect(localized.pages[0].title).toBe("Start");
+      expect(localized.pages[0].mediaId).toBe("media-en");
+      expect(localized.pages[0].mediaType).toBe("image");
+      expect(localized.pages[0].buttons[0].imageId).toBe("button-en");
+      expect(localized.pages[0].buttons[0].action).toEqual({ type: "next" });
+    });
+
+    it("번역이 없는 항목은 기본 내용을 사용해야 한다", () => {
+      const localized = localizeProject(createProject(), "ja");
+      expect(localized.appTitle).toBe("안내");
+      expect(localized.pages[0].title).toBe("スタート");
+      expect(localized.pages[0].mediaId).toBe("media-ko");
+      expect(localized.pages[0].mediaType).toBe("video");
+      expect(localized.pages[1]).toEqual(createProject().pages[1]);
+    });
+  });
+
+  describe("collectProjectAssetIds", () => {
+    it("모든 언어의 미디어를 중복 없이 수집해야 한다", () => {
+      expect(collectProjectAssetIds(createProject())).toEqual({
+        mediaIds: ["media-ko", "media-en", "media-shared"],
+        buttonImageIds: ["button-ko", "button-en"],
+      });
+    });
+  });
+});
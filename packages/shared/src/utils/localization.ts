@@ -0,0 +1,98 @@
+/**
+ * 다국어 콘텐츠 관련 유틸리티 함수들
+ */
+import type { Page, Project } from "../types/project";
+
+/** 언어 코드별 표시 이름 */
+export const LOCALE_LABELS: Record<string, string> = {
+  ko: "한국어",
+  en: "English",
+  zh: "中文",
+  ja: "日本語",
+};
+
+/**
+ * 프로젝트의 기본 언어
+ * @param project - 프로젝트
+ */
+export function getDefaultLocale(project: Project): string | undefined {
+  return project.settings.defaultLocale ?? project.settings.locales?.[0];
+}
+
+/**
+ * 프로젝트가 제공하는 언어 목록 (기본 언어가 첫 번째, 단일 언어면 빈 배열)
+ * @param project - 프로젝트
+ */
+export function getProjectLocales(project: Project): string[] {
+  const defaultLocale = getDefaultLocale(project);
+  if (!defaultLocale) return [];
+
+  const others = (project.settings.locales ?? []).filter(
+    (locale) => locale !== defaultLocale,
+  );
+  return [defaultLocale, ...new Set(others)];
+}
+
+function localizePage(page: Page, locale: string): Page {
+  const localization = page.localized?.[locale];
+  if (!localization) return page;
+
+  return {
+    ...page,
+    title: localization.title || page.title,
+    mediaId: localization.mediaId || page.mediaId,
+    mediaType: localization.mediaId
+      ? (localization.mediaType ?? page.mediaType)
+      : page.mediaType,
+    buttons: page.buttons.map((button) => ({
+      ...button,
+      imageId: localization.buttonImages?.[button.id] || button.imageId,
+    })),
+  };
+}
+
+/**
+ * 선택한 언어의 내용으로 바꾼 프로젝트 (기본 언어이거나 번역이 없으면 그대로)
+ * 페이지 구성, 버튼 위치와 이동 경로는 모든 언어가 공유
+ * @param project - 프로젝트
+ * @param locale - 언어 코드
+ */
+export function localizeProject(project: Project, locale?: string): Project {
+  if (!locale || locale === getDefaultLocale(project)) return project;
+
+  return {
+    ...project,
+    appTitle: project.localizedAppTitle?.[locale] || project.appTitle,
+    pages: project.pages.map((page) => localizePage(page, locale)),
+  };
+}
+
+/**
+ * 모든 언어에서 사용하는 미디어와 버튼 이미지 ID (중복 제거)
+ * @param project - 프로젝트
+ */
+export function collectProjectAssetIds(project: Project): {
+  mediaIds: string[];
+  buttonImageIds: string[];
+} {
+  const mediaIds = new Set<string>();
+  const buttonImageIds = new Set<string>();
+
+  for (const page of project.pages) {
+    if (page.mediaId) mediaIds.add(page.mediaId);
+    for (const button of page.buttons) {
+      if (button.imageId) buttonImageIds.add(button.imageId);
+    }
+    for (const localization of Object.values(page.localized ?? {})) {
+      if (localization.mediaId) mediaIds.add(localization.mediaId);
+      for (const imageId of Object.values(localization.buttonImages ?? {})) {
+        if (imageId) buttonImageIds.add(imageId);
+      }
+    }
+  }
+
+  return {
+    mediaIds: [...mediaIds],
+    buttonImageIds: [...buttonImageIds],
+  };
+}
@@ -0,0 +1,562 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// 기록 파일 하나의 최대 크기 (넘으면 같은 날짜의 다음 번호 파일로 교체)
+const MAX_FILE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// 기록 보관 기간 (일, 지난 파일은 새 날짜 파일을 열 때 삭제)
+const RETENTION_DAYS: i64 = 180;
+
+const FILE_PREFIX: &str = "events-";
+const FILE_EXTENSION: &str = "jsonl";
+
+/// 탭 대상
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TapTarget {
+    Button,
+    TouchArea,
+}
+
+/// 방문자 상호작용 이벤트
+/// pageLeave는 프론트엔드가 보내지 않고 다음 pageEnter/idleReset 시 체류 시간과 함께 기록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AnalyticsEvent {
+    /// 시작 화면에서 시작 (새 세션)
+    SessionStart,
+    #[serde(rename_all = "camelCase")]
+    PageEnter { page_index: usize, page_id: String },
+    #[serde(rename_all = "camelCase")]
+    PageLeave {
+        page_index: usize,
+        page_id: String,
+        dwell_ms: u64,
+    },
+    /// 버튼/터치 영역 탭 (x, y는 화면 기준 비율 0~100)
+    #[serde(rename_all = "camelCase")]
+    Tap {
+        page_index: usize,
+        page_id: String,
+        target: TapTarget,
+        target_id: String,
+        x: f64,
+        y: f64,
+    },
+    /// 입력이 없어 처음 화면으로 돌아감 (세션 종료)
+    #[serde(rename_all = "camelCase")]
+    IdleReset { page_index: usize, page_id: String },
+}
+
+/// 기록 파일의 한 줄
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AnalyticsRecord {
+    /// 기록 시각 (Unix ms, UTC)
+    timestamp: u64,
+    session_id: String,
+    #[serde(flatten)]
+    event: AnalyticsEvent,
+}
+
+/// 내보내기 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+/// 내보내기 요청
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyticsExportRequest {
+    pub output_path: String,
+    pub format: ExportFormat,
+    /// 날짜 구분에 사용할 UTC 기준 시차 (분, 예: 한국 540)
+    #[serde(default)]
+    pub utc_offset_minutes: i32,
+    /// 시작 날짜 (YYYY-MM-DD, 포함)
+    #[serde(default)]
+    pub from: Option<String>,
+    /// 끝 날짜 (YYYY-MM-DD, 포함)
+    #[serde(default)]
+    pub to: Option<String>,
+}
+
+/// 하루 요약
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DaySummary {
+    pub date: String,
+    pub sessions: u64,
+    pub idle_resets: u64,
+    pub pages: Vec<PageSummary>,
+}
+
+/// 페이지별 요약
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageSummary {
+    pub page_index: usize,
+    pub page_id: String,
+    pub visits: u64,
+    pub total_dwell_ms: u64,
+    pub average_dwell_ms: u64,
+    /// 이 페이지에서 세션이 끝난 횟수 (이탈)
+    pub exits: u64,
+    pub taps: Vec<TapSummary>,
+}
+
+/// 탭 대상별 횟수
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TapSummary {
+    pub target: TapTarget,
+    pub target_id: String,
+    pub count: u64,
+}
+
+/// 방문자 분석 기록기
+/// 앱 데이터 폴더의 analytics/events-YYYY-MM-DD[-N].jsonl에 한 줄씩 추가만 함
+pub struct AnalyticsStore {
+    dir: PathBuf,
+    state: Mutex<RecorderState>,
+}
+
+#[derive(Default)]
+struct RecorderState {
+    file: Option<OpenFile>,
+    session_id: Option<String>,
+    /// 현재 페이지 (인덱스, ID, 진입 시각)
+    current_page: Option<(usize, String, u64)>,
+    session_sequence: u64,
+}
+
+struct OpenFile {
+    date: String,
+    file: File,
+    size: u64,
+}
+
+impl AnalyticsStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            state: Mutex::new(RecorderState::default()),
+        }
+    }
+
+    /// 이벤트 기록
+    /// 페이지를 옮기거나 초기화되면 직전 페이지의 체류 시간을 pageLeave로 함께 기록
+    pub fn record(&self, event: AnalyticsEvent) -> Result<(), String> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| "Analytics store is unavailable".to_string())?;
+        let now = now_ms();
+
+        if matches!(event, AnalyticsEvent::SessionStart) {
+            self.leave_current_page(&mut state, now)?;
+            state.session_id = None;
+        }
+
+        if state.session_id.is_none() {
+            state.session_sequence += 1;
+            state.session_id = Some(format!("{}-{}", now, state.session_sequence));
+        }
+
+        match event {
+            AnalyticsEvent::PageEnter {
+                page_index,
+                ref page_id,
+            } => {
+                self.leave_current_page(&mut state, now)?;
+                state.current_page = Some((page_index, page_id.clone(), now));
+                self.write(&mut state, now, event)
+            }
+            AnalyticsEvent::IdleReset { .. } => {
+                self.leave_current_page(&mut state, now)?;
+                self.write(&mut state, now, event)?;
+                state.session_id = None;
+                Ok(())
+            }
+            _ => self.write(&mut state, now, event),
+        }
+    }
+
+    fn leave_current_page(&self, state: &mut RecorderState, now: u64) -> Result<(), String> {
+        match state.current_page.take() {
+            Some((page_index, page_id, entered_at)) => self.write(
+                state,
+                now,
+                AnalyticsEvent::PageLeave {
+                    page_index,
+                    page_id,
+                    dwell_ms: now.saturating_sub(entered_at),
+                },
+            ),
+            None => Ok(()),
+        }
+    }
+
+    fn write(
+        &self,
+        state: &mut RecorderState,
+        now: u64,
+        event: AnalyticsEvent,
+    ) -> Result<(), String> {
+        let record = AnalyticsRecord {
+            timestamp: now,
+            session_id: state.session_id.clone().unwrap_or_default(),
+            event,
+        };
+        let mut line = serde_json::to_vec(&record)
+            .map_err(|e| format!("Failed to serialize analytics event: {}", e))?;
+        line.push(b'\n');
+
+        let date = format_date(now as i64);
+        let needs_new_file = match state.file {
+            Some(ref open) => open.date != date || open.size >= MAX_FILE_BYTES,
+            None => true,
+        };
+        if needs_new_file {
+            state.file = Some(self.open_file(&date)?);
+        }
+
+        let open = state.file.as_mut().expect("analytics file was just opened");
+        open.file
+            .write_all(&line)
+            .map_err(|e| format!("Failed to write analytics event: {}", e))?;
+        open.size += line.len() as u64;
+        Ok(())
+    }
+
+    /// 날짜의 마지막 기록 파일 열기 (가득 찼으면 다음 번호로 새로 만듦)
+    fn open_file(&self, date: &str) -> Result<OpenFile, String> {
+        fs::create_dir_all(&self.dir)
+            .map_err(|e| format!("Failed to create analytics folder: {}", e))?;
+        self.remove_expired_files(date);
+
+        let mut index = 0;
+        while self.file_path(date, index + 1).exists() {
+            index += 1;
+        }
+        let mut path = self.file_path(date, index);
+        let mut size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        if size >= MAX_FILE_BYTES {
+            path = self.file_path(date, index + 1);
+            size = 0;
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| format!("Failed to open analytics file: {}", e))?;
+
+        Ok(OpenFile {
+            date: date.to_string(),
+            file,
+            size,
+        })
+    }
+
+    fn file_path(&self, date: &str, index: usize) -> PathBuf {
+        let name = match index {
+            0 => format!("{}{}.{}", FILE_PREFIX, date, FILE_EXTENSION),
+            _ => format!("{}{}-{}.{}", FILE_PREFIX, date, index, FILE_EXTENSION),
+        };
+        self.dir.join(name)
+    }
+
+    /// 보관 기간이 지난 기록 파일 삭제
+    fn remove_expired_files(&self, today: &str) {
+        let Some(today_days) = parse_date(today) else {
+            return;
+        };
+        for (date, path) in self.record_files() {
+            if parse_date(&date).is_some_and(|days| today_days - days > RETENTION_DAYS) {
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+
+    /// 기록 파일 목록 (UTC 날짜, 경로)
+    fn record_files(&self) -> Vec<(String, PathBuf)> {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+
+        let mut files: Vec<(String, PathBuf)> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter_map(|path| {
+                let name = path.file_name()?.to_str()?;
+                let rest = name.strip_prefix(FILE_PREFIX)?;
+                let rest = rest.strip_suffix(FILE_EXTENSION)?.strip_suffix('.')?;
+                let date = rest.get(..10)?.to_string();
+                parse_date(&date)?;
+                Some((date, path))
+            })
+            .collect();
+        files.sort();
+        files
+    }
+
+    /// 기록을 날짜별로 요약하여 CSV/JSON 파일로 내보내기
+    /// 반환값은 요약된 날짜 수
+    pub fn export(&self, request: &AnalyticsExportRequest) -> Result<usize, String> {
+        // 기록 중인 파일 내용이 디스크에 반영되도록 비움
+        if let Ok(mut state) = self.state.lock() {
+            if let Some(ref mut open) = state.file {
+                let _ = open.file.flush();
+            }
+        }
+
+        let offset_ms = request.utc_offset_minutes as i64 * 60 * 1000;
+        let in_range = |date: &str| {
+            !matches!(request.from.as_deref(), Some(from) if date < from)
+                && !matches!(request.to.as_deref(), Some(to) if date > to)
+        };
+
+        let mut records = Vec::new();
+        for (_, path) in self.record_files() {
+            read_records(&path, &mut records);
+        }
+        records.sort_by_key(|record| record.timestamp);
+
+        let days: Vec<DaySummary> = summarize(&records, offset_ms)
+            .into_iter()
+            .filter(|day| in_range(&day.date))
+            .collect();
+
+        let output = match request.format {
+            ExportFormat::Json => serde_json::to_string_pretty(&days)
+                .map_err(|e| format!("Failed to serialize analytics summary: {}", e))?,
+            ExportFormat::Csv => to_csv(&days),
+        };
+
+        fs::write(Path::new(&request.output_path), output)
+            .map_err(|e| format!("Failed to write analytics export: {}", e))?;
+        Ok(days.len())
+    }
+}
+
+/// 기록 파일 읽기 (손상된 줄은 건너뜀)
+fn read_records(path: &Path, records: &mut Vec<AnalyticsRecord>) {
+    let Ok(file) = File::open(path) else {
+        return;
+    };
+    for line in BufReader::new(file).lines().map_while(Result::ok) {
+        if let Ok(record) = serde_json::from_str::<AnalyticsRecord>(&line) {
+            records.push(record);
+        }
+    }
+}
+
+/// 시각순으로 정렬된 기록을 날짜별로 요약
+fn summarize(records: &[AnalyticsRecord], offset_ms: i64) -> Vec<DaySummary> {
+    #[derive(Default)]
+    struct PageCounter {
+        page_id: String,
+        visits: u64,
+        total_dwell_ms: u64,
+        exits: u64,
+        taps: BTreeMap<(TapTarget, String), u64>,
+    }
+
+    #[derive(Default)]
+    struct DayCounter {
+        sessions: u64,
+        idle_resets: u64,
+        pages: BTreeMap<usize, PageCounter>,
+    }
+
+    let mut days: BTreeMap<String, DayCounter> = BTreeMap::new();
+    // 세션별 마지막으로 들어간 페이지 (이탈 페이지 계산용)
+    let mut last_pages: HashMap<&str, (String, usize, String)> = HashMap::new();
+
+    for record in records {
+        let date = format_date(record.timestamp as i64 + offset_ms);
+        let day = days.entry(date.clone()).or_default();
+
+        match record.event {
+            AnalyticsEvent::SessionStart => {}
+            AnalyticsEvent::PageEnter {
+                page_index,
+                ref page_id,
+            } => {
+                if !last_pages.contains_key(record.session_id.as_str()) {
+                    day.sessions += 1;
+                }
+                let page = day.pages.entry(page_index).or_default();
+                page.page_id.clone_from(page_id);
+                page.visits += 1;
+                last_pages.insert(&record.session_id, (date, page_index, page_id.clone()));
+            }
+            AnalyticsEvent::PageLeave {
+                page_index,
+                ref page_id,
+                dwell_ms,
+            } => {
+                let page = day.pages.entry(page_index).or_default();
+                page.page_id.clone_from(page_id);
+                page.total_dwell_ms += dwell_ms;
+            }
+            AnalyticsEvent::Tap {
+                page_index,
+                ref page_id,
+                target,
+                ref target_id,
+                ..
+            } => {
+                let page = day.pages.entry(page_index).or_default();
+                page.page_id.clone_from(page_id);
+                *page.taps.entry((target, target_id.clone())).or_insert(0) += 1;
+            }
+            AnalyticsEvent::IdleReset { .. } => day.idle_resets += 1,
+        }
+    }
+
+    for (date, page_index, page_id) in last_pages.into_values() {
+        let page = days
+            .entry(date)
+            .or_default()
+            .pages
+            .entry(page_index)
+            .or_default();
+        page.page_id = page_id;
+        page.exits += 1;
+    }
+
+    days.into_iter()
+        .map(|(date, day)| DaySummary {
+            date,
+            sessions: day.sessions,
+            idle_resets: day.idle_resets,
+            pages: day
+                .pages
+                .into_iter()
+                .map(|(page_index, page)| PageSummary {
+                    page_index,
+                    page_id: page.page_id,
+                    visits: page.visits,
+                    total_dwell_ms: page.total_dwell_ms,
+                    average_dwell_ms: page.total_dwell_ms.checked_div(page.visits).unwrap_or(0),
+                    exits: page.exits,
+                    taps: page
+                        .taps
+                        .into_iter()
+                        .map(|((target, target_id), count)| TapSummary {
+                            target,
+                            target_id,
+                            count,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// 날짜/페이지별 한 줄 CSV
+fn to_csv(days: &[DaySummary]) -> String {
+    let mut csv = String::from(
+        "date,sessions,idleResets,pageIndex,pageId,visits,totalDwellMs,averageDwellMs,exits,taps\n",
+    );
+    for day in days {
+        for page in &day.pages {
+            let taps: u64 = page.taps.iter().map(|tap| tap.count).sum();
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{}\n",
+                day.date,
+                day.sessions,
+                day.idle_resets,
+                page.page_index,
+                csv_field(&page.page_id),
+                page.visits,
+                page.total_dwell_ms,
+                page.average_dwell_ms,
+                page.exits,
+                taps
+            ));
+        }
+    }
+    csv
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// Unix ms를 YYYY-MM-DD로 변환 (그레고리력, 일 단위 계산)
+fn format_date(timestamp_ms: i64) -> String {
+    let days = timestamp_ms.div_euclid(86_400_000);
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Unix ms를 로그용 UTC 시각 문자열로 변환 (YYYY-MM-DD HH:MM:SS)
+pub fn format_timestamp(timestamp_ms: i64) -> String {
+    let seconds = timestamp_ms.div_euclid(1000).rem_euclid(86_400);
+    format!(
+        "{} {:02}:{:02}:{:02}",
+        format_date(timestamp_ms),
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+/// YYYY-MM-DD를 1970-01-01부터의 일 수로 변환
+pub fn parse_date(date: &str) -> Option<i64> {
+    let mut parts = date.splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some(era * 146_097 + day_of_era - 719_468)
+}
+
+/// 1970-01-01부터의 일 수를 (년, 월, 일)로 변환
+pub fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
@@ -0,0 +1,397 @@
+use crate::crypto::ContentKey;
+use crate::embedded::{
+    embedded_media_size, open_manifest, read_embedded_app_icon, read_embedded_media,
+    read_embedded_media_range, read_embedded_project, read_embedded_project_entry, BuildManifest,
+    DataSource, MediaManifestEntry, ProjectManifestEntry, VolumeReader,
+};
+use crate::license::{check_license, LicenseStatus};
+use crate::prefetch::{NavigationGraph, PrefetchCache, PrefetchQueue};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// 재사용을 위해 보관할 최대 파일 핸들 수
+/// 영상 구간 스트리밍과 이미지 요청이 동시에 들어와도 서로 기다리지 않을 정도
+const MAX_IDLE_READERS: usize = 4;
+
+/// 무결성 확인 시 한 번에 읽을 크기 (큰 영상도 메모리에 전부 올리지 않음)
+const VERIFY_CHUNK_SIZE: u64 = 8 * 1024 * 1024;
+
+/// 시작 시 한 번 열어 둔 임베딩 콘텐츠
+/// 매니페스트 파싱, 키 복원, ID 색인을 미리 해두고 명령마다 재사용
+pub struct EmbeddedContent {
+    manifest: BuildManifest,
+    /// 매니페스트의 SHA-256 (hex, 같은 빌드인지 구분하는 값)
+    build_hash: String,
+    key: Option<ContentKey>,
+    /// ID별 엔트리 (미디어가 버튼보다 우선)
+    entries: HashMap<String, MediaManifestEntry>,
+    /// 마지막으로 확인한 라이선스 상태
+    license_status: Mutex<LicenseStatus>,
+    readers: ReaderPool,
+    /// 다음에 이동할 가능성이 높은 페이지의 미디어 캐시
+    prefetch: PrefetchCache,
+    /// 여러 프로젝트 빌드에서 선택한 프로젝트 (None이면 기본 프로젝트)
+    selected_project: Mutex<Option<ProjectManifestEntry>>,
+    /// 페이지 이동 경로 (첫 프리페치 요청 시 프로젝트 JSON에서 생성, 프로젝트 선택 시 초기화)
+    navigation: Mutex<Option<Arc<NavigationGraph>>>,
+    /// 현재 페이지가 바뀌면 증가 (이전 페이지 기준의 프리페치 중단용)
+    prefetch_generation: AtomicU64,
+    /// 프리페치 작업 스레드에 전달할 요청
+    prefetch_requests: Arc<PrefetchQueue>,
+    /// 프리페치 작업 스레드 시작 여부
+    prefetch_started: AtomicBool,
+}
+
+impl EmbeddedContent {
+    /// 데이터를 열고 매니페스트를 읽어 색인 생성
+    /// `prefetch_budget`은 프리페치 캐시가 사용할 최대 메모리 (바이트)
+    pub fn load(
+        source: DataSource,
+        key: Option<&ContentKey>,
+        prefetch_budget: u64,
+    ) -> Result<Self, String> {
+        let opened = open_manifest(&source, key)?;
+        let manifest = opened.manifest;
+
+        let mut entries = HashMap::with_capacity(manifest.media.len() + manifest.buttons.len());
+        for entry in manifest.media.iter().chain(manifest.buttons.iter()) {
+            entries
+                .entry(entry.id.clone())
+                .or_insert_with(|| entry.clone());
+        }
+
+        let license_status = check_license(&manifest);
+        let manifest_json = serde_json::to_vec(&manifest)
+            .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+        let build_hash = Sha256::digest(&manifest_json)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+
+        Ok(Self {
+            manifest,
+            build_hash,
+            key: opened.key,
+            entries,
+            license_status: Mutex::new(license_status),
+            readers: ReaderPool::new(source),
+            prefetch: PrefetchCache::new(prefetch_budget),
+            selected_project: Mutex::new(None),
+            navigation: Mutex::new(None),
+            prefetch_generation: AtomicU64::new(0),
+            prefetch_requests: Arc::new(PrefetchQueue::default()),
+            prefetch_started: AtomicBool::new(false),
+        })
+    }
+
+    /// 마지막으로 확인한 라이선스 상태
+    pub fn license_status(&self) -> LicenseStatus {
+        self.license_status
+            .lock()
+            .map(|status| *status)
+            .unwrap_or(LicenseStatus::Invalid)
+    }
+
+    /// 라이선스 다시 확인 (장시간 실행 중 만료 감지용)
+    pub fn refresh_license(&self) -> LicenseStatus {
+        let status = check_license(&self.manifest);
+        if let Ok(mut current) = self.license_status.lock() {
+            *current = status;
+        }
+        status
+    }
+
+    /// 빌드 식별값 (매니페스트의 SHA-256)
+    pub fn build_hash(&self) -> &str {
+        &self.build_hash
+    }
+
+    /// 매니페스트에 기록된 콘텐츠 버전 (이전 버전 빌드는 None)
+    pub fn content_version(&self) -> Option<u64> {
+        self.manifest.content_version
+    }
+
+    /// ID로 엔트리 찾기
+    pub fn entry(&self, id: &str) -> Result<&MediaManifestEntry, String> {
+        self.entries
+            .get(id)
+            .ok_or_else(|| format!("Media not found: {}", id))
+    }
+
+    /// 함께 넣은 프로젝트 목록 (단일 프로젝트 빌드면 비어 있음)
+    pub fn projects(&self) -> &[ProjectManifestEntry] {
+        &self.manifest.projects
+    }
+
+    /// 매니페스트에 기록된 제공 언어
+    pub fn locales(&self) -> &[String] {
+        &self.manifest.locales
+    }
+
+    /// 선택한 프로젝트 (선택 전이거나 단일 프로젝트 빌드면 None)
+    pub fn selected_project(&self) -> Option<ProjectManifestEntry> {
+        self.selected_project.lock().ok().and_then(|selected| selected.clone())
+    }
+
+    /// 재생할 프로젝트 선택 (ID, 0부터 시작하는 번호, 이름 순으로 확인)
+    pub fn select_project(&self, selector: &str) -> Result<ProjectManifestEntry, String> {
+        let projects = &self.manifest.projects;
+        let selector = selector.trim();
+        let entry = projects
+            .iter()
+            .find(|project| project.id == selector)
+            .or_else(|| selector.parse::<usize>().ok().and_then(|index| projects.get(index)))
+            .or_else(|| {
+                projects
+                    .iter()
+                    .find(|project| project.name.eq_ignore_ascii_case(selector))
+            })
+            .cloned()
+            .ok_or_else(|| format!("Project not found: {}", selector))?;
+
+        if let Ok(mut selected) = self.selected_project.lock() {
+            *selected = Some(entry.clone());
+        }
+        if let Ok(mut navigation) = self.navigation.lock() {
+            *navigation = None;
+        }
+        self.prefetch_generation.fetch_add(1, Ordering::SeqCst);
+        Ok(entry)
+    }
+
+    /// 재생할 프로젝트 JSON (선택하지 않았으면 기본 프로젝트)
+    pub fn read_project(&self) -> Result<String, String> {
+        let key = self.key.as_ref();
+        match self.selected_project() {
+            Some(entry) => self
+                .readers
+                .with_reader(|file| read_embedded_project_entry(file, &entry, key)),
+            None => self
+                .readers
+                .with_reader(|file| read_embedded_project(file, &self.manifest, key)),
+        }
+    }
+
+    pub fn read_media(&self, id: &str) -> Result<Vec<u8>, String> {
+        if let Some(cached) = self.prefetch.get(id) {
+            return Ok(cached.to_vec());
+        }
+
+        let entry = self.entry(id)?;
+        self.readers
+            .with_reader(|file| read_embedded_media(file, entry, self.key.as_ref()))
+    }
+
+    /// 미디어의 원본 크기 (압축/암호화 전)
+    pub fn media_size(&self, id: &str) -> Result<u64, String> {
+        Ok(embedded_media_size(self.entry(id)?, self.key.as_ref()))
+    }
+
+    /// 미디어의 일부 구간과 전체 크기
+    pub fn read_media_range(
+        &self,
+        id: &str,
+        start: u64,
+        length: u64,
+    ) -> Result<(Vec<u8>, u64), String> {
+        if let Some(cached) = self.prefetch.get(id) {
+            let from = (start as usize).min(cached.len());
+            let to = (start.saturating_add(length) as usize).min(cached.len());
+            return Ok((cached[from..to].to_vec(), cached.len() as u64));
+        }
+
+        let entry = self.entry(id)?;
+        let key = self.key.as_ref();
+        let data = self
+            .readers
+            .with_reader(|file| read_embedded_media_range(file, entry, key, start, length))?;
+        Ok((data, embedded_media_size(entry, key)))
+    }
+
+    pub fn read_app_icon(&self) -> Result<Option<Vec<u8>>, String> {
+        self.readers
+            .with_reader(|file| read_embedded_app_icon(file, &self.manifest, self.key.as_ref()))
+    }
+}
+
+impl EmbeddedContent {
+    /// 모든 프로젝트 JSON, 미디어, 버튼 이미지, 아이콘을 끝까지 읽어 무결성 확인
+    /// 잘린 파일, 복호화 인증 실패, 압축 해제 실패를 찾아냄 (처음 실패한 엔트리 반환)
+    pub fn verify(&self) -> Result<(), String> {
+        let key = self.key.as_ref();
+        self.readers
+            .with_reader(|file| read_embedded_project(file, &self.manifest, key))?;
+        for entry in &self.manifest.projects {
+            self.readers
+                .with_reader(|file| read_embedded_project_entry(file, entry, key))
+                .map_err(|e| format!("Project {}: {}", entry.name, e))?;
+        }
+
+        for entry in self.manifest.media.iter().chain(self.manifest.buttons.iter()) {
+            self.verify_entry(entry)
+                .map_err(|e| format!("Media {}: {}", entry.name, e))?;
+        }
+
+        self.read_app_icon().map_err(|e| format!("App icon: {}", e))?;
+        Ok(())
+    }
+
+    /// 엔트리 하나를 구간별로 읽어 확인 (압축된 엔트리는 전체 복원)
+    fn verify_entry(&self, entry: &MediaManifestEntry) -> Result<(), String> {
+        let key = self.key.as_ref();
+        if entry.encoding.is_some() {
+            return self
+                .readers
+                .with_reader(|file| read_embedded_media(file, entry, key))
+                .map(|_| ());
+        }
+
+        let total = embedded_media_size(entry, key);
+        let mut position = 0;
+        while position < total {
+            let length = VERIFY_CHUNK_SIZE.min(total - position);
+            let data = self.readers.with_reader(|file| {
+                read_embedded_media_range(file, entry, key, position, length)
+            })?;
+            if data.len() as u64 != length {
+                return Err(format!("Truncated at {} of {} bytes", position, total));
+            }
+            position += length;
+        }
+        Ok(())
+    }
+
+    /// 현재 페이지에서 이동할 수 있는 페이지의 미디어를 백그라운드에서 미리 읽기
+    /// 예산 안에 들어가는 미디어는 캐시에 보관하고, 큰 영상은 끝까지 읽어 OS 캐시만 데워둠
+    /// 작업 스레드 하나가 가장 최근 요청만 처리하므로 페이지를 빠르게 넘겨도 스레드가 늘지 않음
+    pub fn prefetch_from(self: &Arc<Self>, page_index: usize) {
+        let generation = self.prefetch_generation.fetch_add(1, Ordering::SeqCst) + 1;
+        self.prefetch_requests.push(page_index, generation);
+        if self.prefetch_started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        // 콘텐츠가 교체되면 작업 스레드도 끝나도록 약한 참조만 보관
+        let content = Arc::downgrade(self);
+        let requests = Arc::clone(&self.prefetch_requests);
+        std::thread::spawn(move || {
+            while let Some((page_index, generation)) = requests.next() {
+                let Some(content) = content.upgrade() else {
+                    break;
+                };
+                content.run_prefetch(page_index, generation);
+            }
+        });
+    }
+
+    fn run_prefetch(&self, page_index: usize, generation: u64) {
+        let Some(navigation) = self.navigation() else {
+            return;
+        };
+
+        let mut ids: Vec<String> = Vec::new();
+        for page in navigation.reachable_pages(page_index) {
+            for id in navigation.page_media_ids(page) {
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+            }
+        }
+
+        for id in ids {
+            // 그 사이 페이지가 바뀌었으면 새 페이지 기준의 프리페치에 맡김
+            if self.prefetch_generation.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            if self.prefetch.contains(&id) {
+                continue;
+            }
+            let Ok(entry) = self.entry(&id) else {
+                continue;
+            };
+
+            let key = self.key.as_ref();
+            let result = if self.prefetch.fits(embedded_media_size(entry, key)) {
+                self.readers
+                    .with_reader(|file| read_embedded_media(file, entry, key))
+                    .map(|data| self.prefetch.insert(&id, data))
+            } else {
+                self.readers
+                    .with_reader(|file| warm_region(file, entry.offset, entry.size))
+            };
+
+            if let Err(e) = result {
+                log::warn!("Failed to prefetch media {}: {}", id, e);
+            }
+        }
+    }
+
+    /// 프로젝트 JSON에서 만든 페이지 이동 경로 (파싱 실패 시 None)
+    fn navigation(&self) -> Option<Arc<NavigationGraph>> {
+        let mut navigation = self.navigation.lock().ok()?;
+        if navigation.is_none() {
+            *navigation = self
+                .read_project()
+                .and_then(|json| NavigationGraph::parse(&json))
+                .map(Arc::new)
+                .map_err(|e| log::warn!("Prefetch disabled: {}", e))
+                .ok();
+        }
+        navigation.clone()
+    }
+}
+
+impl Drop for EmbeddedContent {
+    /// 콘텐츠가 교체되면 대기 중인 프리페치 작업 스레드 종료
+    fn drop(&mut self) {
+        self.prefetch_requests.close();
+    }
+}
+
+/// 영역을 끝까지 읽고 버려 OS 파일 캐시에 올려둠
+fn warm_region<R: Read + Seek>(file: &mut R, offset: u64, size: u64) -> Result<(), String> {
+    file.seek(SeekFrom::Start(offset))
+        .map_err(|e| format!("Failed to seek to media: {}", e))?;
+    std::io::copy(&mut file.take(size), &mut std::io::sink())
+        .map_err(|e| format!("Failed to read media: {}", e))?;
+    Ok(())
+}
+
+/// 열린 파일 핸들 풀
+/// 요청마다 exe를 다시 열지 않고 사용이 끝난 핸들을 돌려받아 재사용
+struct ReaderPool {
+    source: DataSource,
+    idle: Mutex<Vec<VolumeReader>>,
+}
+
+impl ReaderPool {
+    fn new(source: DataSource) -> Self {
+        Self {
+            source,
+            idle: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn with_reader<T, F>(&self, read: F) -> Result<T, String>
+    where
+        F: FnOnce(&mut VolumeReader) -> Result<T, String>,
+    {
+        let idle = self.idle.lock().ok().and_then(|mut idle| idle.pop());
+        let mut reader = match idle {
+            Some(reader) => reader,
+            None => self.source.open()?,
+        };
+
+        let result = read(&mut reader);
+
+        if let Ok(mut idle) = self.idle.lock() {
+            if idle.len() < MAX_IDLE_READERS {
+                idle.push(reader);
+            }
+        }
+        result
+    }
+}
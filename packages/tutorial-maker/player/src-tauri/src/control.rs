@@ -0,0 +1,517 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{IpAddr, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use subtle::ConstantTimeEq;
+use tungstenite::handshake::derive_accept_key;
+use tungstenite::protocol::Role;
+use tungstenite::{Message, WebSocket};
+
+/// 원격 제어 명령을 프론트엔드에 전달하는 이벤트
+pub const CONTROL_COMMAND_EVENT: &str = "control-command";
+
+/// 요청 헤더 최대 크기
+const MAX_HEADER_BYTES: usize = 8 * 1024;
+
+/// 요청 본문 최대 크기 (명령 JSON만 받음)
+const MAX_BODY_BYTES: usize = 4 * 1024;
+
+/// 요청을 다 받을 때까지 기다리는 시간
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// WebSocket 연결에서 수신 확인과 이벤트 전송을 번갈아 하는 주기
+const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 프로젝트 설정의 원격 제어 (settings.controlApi)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ControlApiSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// 수신할 인터페이스 주소 (기본 127.0.0.1)
+    #[serde(default = "default_bind")]
+    pub bind: String,
+    #[serde(default = "default_port")]
+    pub port: u16,
+    /// 접근 토큰 (Authorization: Bearer 헤더, 없으면 루프백에서만 허용)
+    /// token 쿼리는 URL이 로그/프록시에 남을 수 있으므로 루프백에 열었을 때만 받음
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+fn default_bind() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_port() -> u16 {
+    8765
+}
+
+#[derive(Deserialize)]
+struct ProjectControl {
+    settings: SettingsControl,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SettingsControl {
+    #[serde(default)]
+    control_api: Option<ControlApiSettings>,
+}
+
+impl ControlApiSettings {
+    /// 프로젝트 JSON에서 원격 제어 설정 읽기 (꺼져 있으면 None)
+    pub fn from_project_json(project_json: &str) -> Result<Option<Self>, String> {
+        let project: ProjectControl = serde_json::from_str(project_json)
+            .map_err(|e| format!("Failed to parse control API settings: {}", e))?;
+        Ok(project.settings.control_api.filter(|control| control.enabled))
+    }
+
+    fn token(&self) -> Option<&str> {
+        self.token.as_deref().map(str::trim).filter(|token| !token.is_empty())
+    }
+
+    /// 수신 주소 (토큰 없이 외부 인터페이스에 여는 것은 거부)
+    fn address(&self) -> Result<SocketAddr, String> {
+        let ip: IpAddr = self
+            .bind
+            .trim()
+            .parse()
+            .map_err(|e| format!("Invalid control API address '{}': {}", self.bind, e))?;
+        if !ip.is_loopback() && self.token().is_none() {
+            return Err("A token is required to listen on a non-loopback address".to_string());
+        }
+        Ok(SocketAddr::new(ip, self.port))
+    }
+}
+
+/// 외부에서 받은 제어 명령 (프론트엔드에 그대로 전달)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ControlCommand {
+    /// 페이지 이동 (페이지 ID 또는 0부터 시작하는 번호)
+    #[serde(rename_all = "camelCase")]
+    Goto { page_id: String },
+    Next,
+    Home,
+    /// 콘텐츠 다시 로드
+    Reload,
+    /// 핫 폴더로 설치한 콘텐츠를 이전 콘텐츠로 되돌림
+    Rollback,
+    Mute { muted: bool },
+}
+
+/// 현재 재생 상태 (GET /status)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ControlStatus {
+    /// 여러 프로젝트 빌드에서 선택한 프로젝트
+    pub project_id: Option<String>,
+    pub page_index: usize,
+    pub page_id: Option<String>,
+    pub muted: bool,
+}
+
+/// 구독자에게 보내는 이벤트
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ControlEvent {
+    #[serde(rename_all = "camelCase")]
+    PageChange {
+        project_id: Option<String>,
+        page_index: usize,
+        page_id: Option<String>,
+    },
+}
+
+/// 명령 실행 (프론트엔드에 이벤트 전달)
+pub type ControlDispatch = Arc<dyn Fn(ControlCommand) + Send + Sync>;
+
+/// 원격 제어 상태와 이벤트 구독자 (제어 서버들이 공유)
+#[derive(Default)]
+pub struct ControlHub {
+    status: Mutex<ControlStatus>,
+    subscribers: Mutex<Vec<Sender<ControlEvent>>>,
+    started: AtomicBool,
+}
+
+impl ControlHub {
+    pub fn status(&self) -> ControlStatus {
+        self.status.lock().map(|status| status.clone()).unwrap_or_default()
+    }
+
+    /// 현재 페이지 알림 (바뀌었으면 구독자에게 전송)
+    pub fn page_changed(
+        &self,
+        project_id: Option<String>,
+        page_index: usize,
+        page_id: Option<String>,
+    ) {
+        let Ok(mut status) = self.status.lock() else {
+            return;
+        };
+        if status.project_id == project_id
+            && status.page_index == page_index
+            && status.page_id == page_id
+        {
+            return;
+        }
+
+        status.project_id = project_id;
+        status.page_index = page_index;
+        status.page_id = page_id;
+        let event = page_change_event(&status);
+        drop(status);
+        self.broadcast(&event);
+    }
+
+    /// 명령 실행 (음소거 상태는 여기서 기록)
+    pub fn execute(&self, command: ControlCommand, dispatch: &ControlDispatch) {
+        if let ControlCommand::Mute { muted } = command {
+            if let Ok(mut status) = self.status.lock() {
+                status.muted = muted;
+            }
+        }
+        log::info!("Control command: {:?}", command);
+        dispatch(command);
+    }
+
+    /// 이벤트 구독
+    pub fn subscribe(&self) -> Receiver<ControlEvent> {
+        let (sender, receiver) = mpsc::channel();
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            subscribers.push(sender);
+        }
+        receiver
+    }
+
+    /// 모든 구독자에게 전송 (연결이 끊긴 구독자는 제거)
+    pub fn broadcast(&self, event: &ControlEvent) {
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            subscribers.retain(|subscriber| subscriber.send(event.clone()).is_ok());
+        }
+    }
+
+    /// HTTP/WebSocket 서버 시작 (이미 시작했으면 무시)
+    pub fn start(
+        self: &Arc<Self>,
+        settings: &ControlApiSettings,
+        dispatch: ControlDispatch,
+    ) -> Result<(), String> {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let listener = settings.address().and_then(|address| {
+            TcpListener::bind(address)
+                .map_err(|e| format!("Failed to bind control API on {}: {}", address, e))
+        });
+        let listener = match listener {
+            Ok(listener) => listener,
+            Err(e) => {
+                self.started.store(false, Ordering::SeqCst);
+                return Err(e);
+            }
+        };
+        log::info!("Control API listening on {}:{}", settings.bind, settings.port);
+
+        let hub = self.clone();
+        let token = settings.token().map(str::to_string);
+        let allow_query_token = listener
+            .local_addr()
+            .map(|address| address.ip().is_loopback())
+            .unwrap_or(false);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let stream = match stream {
+                    Ok(stream) => stream,
+                    Err(e) => {
+                        log::warn!("Control API connection failed: {}", e);
+                        continue;
+                    }
+                };
+
+                let hub = hub.clone();
+                let token = token.clone();
+                let dispatch = dispatch.clone();
+                std::thread::spawn(move || {
+                    let credentials = Credentials {
+                        token: token.as_deref(),
+                        allow_query: allow_query_token,
+                    };
+                    if let Err(e) = handle_connection(&hub, stream, credentials, &dispatch) {
+                        log::warn!("Control API request failed: {}", e);
+                    }
+                });
+            }
+        });
+        Ok(())
+    }
+}
+
+fn page_change_event(status: &ControlStatus) -> ControlEvent {
+    ControlEvent::PageChange {
+        project_id: status.project_id.clone(),
+        page_index: status.page_index,
+        page_id: status.page_id.clone(),
+    }
+}
+
+/// 받은 HTTP 요청
+struct Request {
+    method: String,
+    path: String,
+    query: HashMap<String, String>,
+    /// 헤더 이름은 소문자
+    headers: HashMap<String, String>,
+    body: Vec<u8>,
+}
+
+impl Request {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+
+    /// 토큰 확인 (비교 시간으로 토큰을 추측할 수 없도록 상수 시간 비교)
+    fn is_authorized(&self, credentials: Credentials) -> bool {
+        let Some(token) = credentials.token else {
+            return true;
+        };
+        let matches = |value: &str| bool::from(value.as_bytes().ct_eq(token.as_bytes()));
+
+        let bearer = self
+            .header("authorization")
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim);
+        let query = self
+            .query
+            .get("token")
+            .filter(|_| credentials.allow_query)
+            .map(String::as_str);
+        bearer.is_some_and(matches) || query.is_some_and(matches)
+    }
+
+    fn is_websocket_upgrade(&self) -> bool {
+        self.header("upgrade").is_some_and(|value| value.eq_ignore_ascii_case("websocket"))
+    }
+}
+
+fn read_request(stream: &TcpStream) -> Result<Request, String> {
+    let mut reader = BufReader::new(stream);
+    let mut lines = Vec::new();
+    let mut header_bytes = 0;
+    loop {
+        let mut line = String::new();
+        let read = reader
+            .read_line(&mut line)
+            .map_err(|e| format!("Failed to read request: {}", e))?;
+        if read == 0 {
+            return Err("Connection closed before request was complete".to_string());
+        }
+        header_bytes += read;
+        if header_bytes > MAX_HEADER_BYTES {
+            return Err("Request header too large".to_string());
+        }
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        lines.push(line.to_string());
+    }
+
+    let mut request_line = lines.first().ok_or("Empty request")?.split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_ascii_uppercase();
+    let target = request_line.next().ok_or("Missing request target")?;
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let query = query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+
+    let headers: HashMap<String, String> = lines[1..]
+        .iter()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect();
+
+    let content_length = headers
+        .get("content-length")
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    if content_length > MAX_BODY_BYTES {
+        return Err("Request body too large".to_string());
+    }
+    let mut body = vec![0u8; content_length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|e| format!("Failed to read request body: {}", e))?;
+
+    Ok(Request {
+        method,
+        path: path.to_string(),
+        query,
+        headers,
+        body,
+    })
+}
+
+fn write_response(mut stream: &TcpStream, status: u16, body: &str) -> Result<(), String> {
+    let reason = match status {
+        200 => "OK",
+        204 => "No Content",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        _ => "Error",
+    };
+    let response = format!(
+        "HTTP/1.1 {} {}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Access-Control-Allow-Origin: *\r\n\
+         Access-Control-Allow-Headers: Authorization, Content-Type\r\n\
+         Access-Control-Allow-Methods: GET, POST, OPTIONS\r\n\
+         Connection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    );
+    stream
+        .write_all(response.as_bytes())
+        .map_err(|e| format!("Failed to write response: {}", e))
+}
+
+fn error_body(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+/// 요청 인증 정보
+#[derive(Clone, Copy)]
+struct Credentials<'a> {
+    /// 접근 토큰 (없으면 인증하지 않음)
+    token: Option<&'a str>,
+    /// token 쿼리 허용 여부 (루프백에 열었을 때만)
+    allow_query: bool,
+}
+
+/// 연결 하나 처리 (HTTP 요청 또는 /events WebSocket)
+fn handle_connection(
+    hub: &ControlHub,
+    stream: TcpStream,
+    credentials: Credentials,
+    dispatch: &ControlDispatch,
+) -> Result<(), String> {
+    stream
+        .set_read_timeout(Some(REQUEST_TIMEOUT))
+        .map_err(|e| format!("Failed to set timeout: {}", e))?;
+    let request = read_request(&stream)?;
+
+    // 브라우저 제어 패널의 사전 요청은 인증 없이 허용
+    if request.method == "OPTIONS" {
+        return write_response(&stream, 204, "");
+    }
+    if !request.is_authorized(credentials) {
+        return write_response(&stream, 401, &error_body("Unauthorized"));
+    }
+    if request.path == "/events" && request.is_websocket_upgrade() {
+        return serve_events(hub, stream, &request, dispatch);
+    }
+
+    let (status, body) = route(hub, &request, dispatch);
+    write_response(&stream, status, &body)
+}
+
+/// HTTP 경로별 명령
+fn route(hub: &ControlHub, request: &Request, dispatch: &ControlDispatch) -> (u16, String) {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    let command = match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["status"]) => {
+            let status = serde_json::to_string(&hub.status()).unwrap_or_default();
+            return (200, status);
+        }
+        ("POST", ["goto", page_id]) => ControlCommand::Goto {
+            page_id: page_id.to_string(),
+        },
+        ("POST", ["next"]) => ControlCommand::Next,
+        ("POST", ["home"]) => ControlCommand::Home,
+        ("POST", ["reload"]) => ControlCommand::Reload,
+        ("POST", ["rollback"]) => ControlCommand::Rollback,
+        ("POST", ["mute"]) => ControlCommand::Mute { muted: true },
+        ("POST", ["unmute"]) => ControlCommand::Mute { muted: false },
+        ("POST", ["command"]) => match serde_json::from_slice(&request.body) {
+            Ok(command) => command,
+            Err(e) => return (400, error_body(&format!("Invalid command: {}", e))),
+        },
+        _ => return (404, error_body("Not found")),
+    };
+
+    hub.execute(command, dispatch);
+    (200, serde_json::json!({ "ok": true }).to_string())
+}
+
+/// 페이지 변경 이벤트 전송 (받은 텍스트 메시지는 명령 JSON으로 실행)
+fn serve_events(
+    hub: &ControlHub,
+    mut stream: TcpStream,
+    request: &Request,
+    dispatch: &ControlDispatch,
+) -> Result<(), String> {
+    let key = request.header("sec-websocket-key").ok_or("Missing WebSocket key")?;
+    let handshake = format!(
+        "HTTP/1.1 101 Switching Protocols\r\n\
+         Upgrade: websocket\r\n\
+         Connection: Upgrade\r\n\
+         Sec-WebSocket-Accept: {}\r\n\r\n",
+        derive_accept_key(key.as_bytes())
+    );
+    stream
+        .write_all(handshake.as_bytes())
+        .map_err(|e| format!("Failed to write handshake: {}", e))?;
+    stream
+        .set_read_timeout(Some(EVENT_POLL_INTERVAL))
+        .map_err(|e| format!("Failed to set timeout: {}", e))?;
+
+    let events = hub.subscribe();
+    let mut socket = WebSocket::from_raw_socket(stream, Role::Server, None);
+
+    // 연결 직후 현재 페이지 전송
+    send_event(&mut socket, &page_change_event(&hub.status()))?;
+
+    loop {
+        while let Ok(event) = events.try_recv() {
+            send_event(&mut socket, &event)?;
+        }
+
+        match socket.read() {
+            Ok(Message::Text(text)) => match serde_json::from_str::<ControlCommand>(&text) {
+                Ok(command) => hub.execute(command, dispatch),
+                Err(e) => log::warn!("Invalid control command over WebSocket: {}", e),
+            },
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(e))
+                if matches!(
+                    e.kind(),
+                    std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                ) => {}
+            Err(tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed) => {
+                return Ok(());
+            }
+            Err(e) => return Err(format!("WebSocket error: {}", e)),
+        }
+    }
+}
+
+fn send_event(socket: &mut WebSocket<TcpStream>, event: &ControlEvent) -> Result<(), String> {
+    let json =
+        serde_json::to_string(event).map_err(|e| format!("Failed to serialize event: {}", e))?;
+    socket
+        .send(Message::text(json))
+        .map_err(|e| format!("Failed to send event: {}", e))
+}
@@ -0,0 +1,231 @@
+use crate::license::SignatureStatus;
+use argon2::Argon2;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::{Read, Seek, SeekFrom};
+
+/// 지원하는 암호 알고리즘 식별자
+const CIPHER_NAME: &str = "chacha20poly1305";
+
+/// AEAD 인증 태그 크기
+const TAG_SIZE: u64 = 16;
+
+/// 암호화 청크 크기 (평문 기준, maker와 동일해야 함)
+/// 다른 값은 버퍼 크기와 청크 계산을 조작할 수 있으므로 거부
+const CHUNK_SIZE: u32 = 65536;
+
+/// 영역 앞에 기록된 nonce 접두사 크기 (maker와 동일해야 함)
+const NONCE_PREFIX_SIZE: u64 = 8;
+
+/// 내장 키 난독화용 상수 (maker와 동일해야 함)
+const KEY_OBFUSCATION_PEPPER: &[u8] = b"viswave-tutorial-content-key";
+
+/// 잠금 해제가 필요할 때 반환하는 에러 메시지 (프론트엔드에서 식별)
+pub const CONTENT_LOCKED_ERROR: &str = "Content is locked";
+
+/// 콘텐츠 키 출처
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeySource {
+    /// exe에 난독화되어 내장된 키
+    Embedded,
+    /// 실행 시 입력/전달되는 암호에서 유도한 키
+    Passphrase,
+}
+
+/// 암호화된 매니페스트 (VISTUT_E1/VISTUT_E2 트레일러)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SealedManifest {
+    pub cipher: String,
+    pub chunk_size: u32,
+    pub key_source: KeySource,
+    /// 키 유도/난독화용 솔트 (base64)
+    pub salt: String,
+    /// 난독화된 콘텐츠 키 (base64, 내장 키 모드에서만)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub obfuscated_key: Option<String>,
+    /// 매니페스트 암호화 nonce (base64)
+    pub nonce: String,
+    /// 암호화된 BuildManifest JSON (base64)
+    pub manifest: String,
+    /// 콘텐츠 버전 (키 없이 새 버전인지 판단할 수 있도록 평문으로 기록)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_version: Option<u64>,
+    /// 트레일러 서명 확인 결과 (봉인된 매니페스트 전체에 대한 서명)
+    #[serde(skip)]
+    pub signature: SignatureStatus,
+}
+
+impl SealedManifest {
+    /// 내장 키 복원 (암호 모드면 None)
+    pub fn embedded_key(&self) -> Result<Option<ContentKey>, String> {
+        if self.key_source != KeySource::Embedded {
+            return Ok(None);
+        }
+
+        let salt = decode_base64(&self.salt, "salt")?;
+        let obfuscated = self
+            .obfuscated_key
+            .as_deref()
+            .ok_or_else(|| "Embedded key is missing".to_string())
+            .and_then(|k| decode_base64(k, "embedded key"))?;
+
+        if obfuscated.len() != 32 {
+            return Err("Invalid embedded key length".to_string());
+        }
+
+        let mask = obfuscation_mask(&salt);
+        let mut key = [0u8; 32];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = obfuscated[i] ^ mask[i];
+        }
+
+        self.content_key(&key).map(Some)
+    }
+
+    /// 암호에서 콘텐츠 키 유도
+    pub fn derive_key(&self, passphrase: &str) -> Result<ContentKey, String> {
+        let salt = decode_base64(&self.salt, "salt")?;
+        let mut key = [0u8; 32];
+        Argon2::default()
+            .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+            .map_err(|e| format!("Failed to derive content key: {}", e))?;
+
+        self.content_key(&key)
+    }
+
+    /// 매니페스트 복호화 (키가 틀리면 인증 실패)
+    pub fn open(&self, key: &ContentKey) -> Result<Vec<u8>, String> {
+        let nonce = decode_base64(&self.nonce, "manifest nonce")?;
+        if nonce.len() != 12 {
+            return Err("Invalid manifest nonce length".to_string());
+        }
+        let sealed = decode_base64(&self.manifest, "manifest")?;
+
+        key.cipher
+            .decrypt(Nonce::from_slice(&nonce), sealed.as_slice())
+            .map_err(|_| "Invalid content key".to_string())
+    }
+
+    fn content_key(&self, key: &[u8; 32]) -> Result<ContentKey, String> {
+        if self.cipher != CIPHER_NAME {
+            return Err(format!("Unsupported cipher: {}", self.cipher));
+        }
+        if self.chunk_size != CHUNK_SIZE {
+            return Err(format!("Unsupported chunk size: {}", self.chunk_size));
+        }
+
+        Ok(ContentKey {
+            cipher: ChaCha20Poly1305::new(Key::from_slice(key)),
+            chunk_size: self.chunk_size as u64,
+        })
+    }
+}
+
+/// 잠금 해제된 콘텐츠 키
+#[derive(Clone)]
+pub struct ContentKey {
+    cipher: ChaCha20Poly1305,
+    chunk_size: u64,
+}
+
+impl ContentKey {
+    /// 암호화된 영역의 평문 크기
+    pub fn plain_size(&self, stored_size: u64) -> u64 {
+        let sealed_chunk = self.chunk_size + TAG_SIZE;
+        let chunks_size = stored_size.saturating_sub(NONCE_PREFIX_SIZE);
+        let full_chunks = chunks_size / sealed_chunk;
+        let remainder = chunks_size % sealed_chunk;
+        full_chunks * self.chunk_size + remainder.saturating_sub(TAG_SIZE)
+    }
+
+    /// 영역 전체 복호화
+    pub fn decrypt_region<R: Read + Seek>(
+        &self,
+        file: &mut R,
+        offset: u64,
+        stored_size: u64,
+    ) -> Result<Vec<u8>, String> {
+        let plain_size = self.plain_size(stored_size);
+        self.decrypt_range(file, offset, stored_size, 0, plain_size)
+    }
+
+    /// 영역의 평문 구간 [start, start + length)만 복호화
+    /// 해당 구간에 걸친 청크만 읽으므로 대용량 영상도 전체를 메모리에 올리지 않음
+    pub fn decrypt_range<R: Read + Seek>(
+        &self,
+        file: &mut R,
+        offset: u64,
+        stored_size: u64,
+        start: u64,
+        length: u64,
+    ) -> Result<Vec<u8>, String> {
+        let plain_size = self.plain_size(stored_size);
+        let end = start.saturating_add(length).min(plain_size);
+        if start >= end {
+            return Ok(Vec::new());
+        }
+
+        let mut prefix = [0u8; NONCE_PREFIX_SIZE as usize];
+        file.seek(SeekFrom::Start(offset))
+            .map_err(|e| format!("Failed to seek to encrypted region: {}", e))?;
+        file.read_exact(&mut prefix)
+            .map_err(|e| format!("Failed to read nonce prefix: {}", e))?;
+
+        let sealed_chunk = self.chunk_size + TAG_SIZE;
+        let first_chunk = start / self.chunk_size;
+        let last_chunk = (end - 1) / self.chunk_size;
+
+        let mut output = Vec::with_capacity((end - start) as usize);
+        let mut sealed = vec![0u8; sealed_chunk as usize];
+
+        for index in first_chunk..=last_chunk {
+            let chunk_start = NONCE_PREFIX_SIZE + index * sealed_chunk;
+            let chunk_len = sealed_chunk.min(stored_size - chunk_start) as usize;
+
+            file.seek(SeekFrom::Start(offset + chunk_start))
+                .map_err(|e| format!("Failed to seek to encrypted chunk: {}", e))?;
+            file.read_exact(&mut sealed[..chunk_len])
+                .map_err(|e| format!("Failed to read encrypted chunk: {}", e))?;
+
+            let plain = self
+                .cipher
+                .decrypt(&chunk_nonce(&prefix, index as u32), &sealed[..chunk_len])
+                .map_err(|_| "Encrypted content is corrupted or the key is invalid".to_string())?;
+
+            // 청크 내에서 요청 구간만 잘라내기
+            let plain_start = index * self.chunk_size;
+            let from = start.saturating_sub(plain_start) as usize;
+            let to = ((end - plain_start) as usize).min(plain.len());
+            output.extend_from_slice(&plain[from..to]);
+        }
+
+        Ok(output)
+    }
+}
+
+/// 청크 nonce 생성: 영역의 무작위 접두사(8바이트) + 청크 인덱스(4바이트)
+fn chunk_nonce(prefix: &[u8; NONCE_PREFIX_SIZE as usize], index: u32) -> Nonce {
+    let mut nonce = [0u8; 12];
+    nonce[..8].copy_from_slice(prefix);
+    nonce[8..].copy_from_slice(&index.to_be_bytes());
+    *Nonce::from_slice(&nonce)
+}
+
+/// 내장 키 난독화 마스크
+fn obfuscation_mask(salt: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(KEY_OBFUSCATION_PEPPER);
+    hasher.update(salt);
+    hasher.finalize().into()
+}
+
+fn decode_base64(value: &str, field: &str) -> Result<Vec<u8>, String> {
+    BASE64
+        .decode(value)
+        .map_err(|e| format!("Invalid {} in sealed manifest: {}", field, e))
+}
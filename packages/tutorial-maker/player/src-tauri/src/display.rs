@@ -0,0 +1,277 @@
+use serde::Deserialize;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, LogicalSize, Manager, Monitor, PhysicalPosition, WebviewWindow};
+
+/// 모니터 연결/해제 확인 주기
+const HOTPLUG_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// 플레이어 창을 띄울 모니터 선택
+/// CLI(--display)와 프로젝트 설정(settings.display)에서 같은 형식을 사용
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DisplaySelector {
+    /// 주 모니터
+    Primary,
+    /// 모니터 번호 (0부터, OS가 알려주는 순서)
+    Index(usize),
+    /// 모니터 이름 (대소문자 무시, 일부만 일치해도 됨)
+    Name(String),
+    /// 해상도가 가장 큰 모니터
+    Largest,
+    /// 터치 전용 모니터로 쓰는 가장 큰 보조 모니터 (보조 모니터가 없으면 가장 큰 모니터)
+    /// OS에서 모니터별 터치 지원 여부를 알 수 없어 전시장 구성(주 모니터 + 터치 보조 모니터) 기준으로 고름
+    LargestTouch,
+}
+
+impl DisplaySelector {
+    /// "primary", "largest", "largest-touch", 숫자(번호), 그 외는 모니터 이름
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+
+        let selector = match value.to_ascii_lowercase().as_str() {
+            "primary" => Self::Primary,
+            "largest" => Self::Largest,
+            "largest-touch" | "touch" => Self::LargestTouch,
+            lower => match lower.parse::<usize>() {
+                Ok(index) => Self::Index(index),
+                Err(_) => Self::Name(value.to_string()),
+            },
+        };
+        Some(selector)
+    }
+
+    /// 모니터 목록에서 선택 (찾지 못하면 None)
+    fn select<'a>(
+        &self,
+        monitors: &'a [Monitor],
+        primary: Option<&Monitor>,
+    ) -> Option<&'a Monitor> {
+        let is_primary = |monitor: &Monitor| match primary {
+            Some(primary) => same_monitor(monitor, primary),
+            None => false,
+        };
+        let largest = |candidates: Vec<&'a Monitor>| {
+            candidates
+                .into_iter()
+                .max_by_key(|monitor| monitor.size().width as u64 * monitor.size().height as u64)
+        };
+
+        match self {
+            Self::Primary => monitors.iter().find(|monitor| is_primary(monitor)),
+            Self::Index(index) => monitors.get(*index),
+            Self::Name(name) => {
+                let name = name.to_lowercase();
+                monitors.iter().find(|monitor| {
+                    monitor
+                        .name()
+                        .is_some_and(|monitor_name| monitor_name.to_lowercase().contains(&name))
+                })
+            }
+            Self::Largest => largest(monitors.iter().collect()),
+            Self::LargestTouch => {
+                largest(monitors.iter().filter(|monitor| !is_primary(monitor)).collect())
+                    .or_else(|| largest(monitors.iter().collect()))
+            }
+        }
+    }
+}
+
+/// 프로젝트 설정 중 창 배치에 필요한 부분
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowPlacement {
+    /// 모니터 선택 (DisplaySelector 형식, 없으면 주 모니터)
+    #[serde(default)]
+    pub display: Option<String>,
+    #[serde(default)]
+    pub fullscreen: bool,
+    #[serde(default = "default_window_width")]
+    pub window_width: u32,
+    #[serde(default = "default_window_height")]
+    pub window_height: u32,
+}
+
+fn default_window_width() -> u32 {
+    800
+}
+
+fn default_window_height() -> u32 {
+    600
+}
+
+impl Default for WindowPlacement {
+    fn default() -> Self {
+        Self {
+            display: None,
+            fullscreen: false,
+            window_width: default_window_width(),
+            window_height: default_window_height(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ProjectPlacement {
+    settings: WindowPlacement,
+}
+
+impl WindowPlacement {
+    pub fn from_project_json(project_json: &str) -> Result<Self, String> {
+        let project: ProjectPlacement = serde_json::from_str(project_json)
+            .map_err(|e| format!("Failed to parse window settings: {}", e))?;
+        Ok(project.settings)
+    }
+}
+
+/// 현재 창 배치 설정과 마지막으로 확인한 모니터 구성
+#[derive(Default)]
+pub struct DisplayState {
+    state: Mutex<PlacementState>,
+}
+
+#[derive(Default)]
+struct PlacementState {
+    /// 적용할 배치 (None이면 아직 배치하지 않음)
+    placement: Option<(Option<DisplaySelector>, WindowPlacement)>,
+    /// 모니터 구성 (이름, 위치, 크기)
+    layout: Vec<MonitorKey>,
+}
+
+type MonitorKey = (Option<String>, i32, i32, u32, u32);
+
+impl DisplayState {
+    /// 창을 선택한 모니터에 배치
+    /// CLI 선택이 프로젝트 설정보다 우선
+    pub fn place(
+        &self,
+        window: &WebviewWindow,
+        cli_selector: Option<DisplaySelector>,
+        placement: WindowPlacement,
+    ) -> Result<(), String> {
+        let selector = cli_selector
+            .or_else(|| placement.display.as_deref().and_then(DisplaySelector::parse));
+
+        place_window(window, selector.as_ref(), &placement)?;
+
+        if let Ok(mut state) = self.state.lock() {
+            state.layout = monitor_layout(window);
+            state.placement = Some((selector, placement));
+        }
+        Ok(())
+    }
+
+    /// 모니터 연결/해제를 감시하여 구성이 바뀌면 다시 배치
+    pub fn watch_hotplug(app: AppHandle) {
+        std::thread::spawn(move || loop {
+            std::thread::sleep(HOTPLUG_POLL_INTERVAL);
+
+            let Some(window) = app.get_webview_window("main") else {
+                continue;
+            };
+            let layout = monitor_layout(&window);
+            if layout.is_empty() {
+                continue;
+            }
+
+            // 창 조작은 메인 스레드에서 처리되므로 잠금을 풀고 배치
+            let placement = {
+                let display = app.state::<DisplayState>();
+                let Ok(mut state) = display.state.lock() else {
+                    continue;
+                };
+                if layout == state.layout {
+                    continue;
+                }
+                state.layout = layout;
+                state.placement.clone()
+            };
+
+            let Some((selector, placement)) = placement else {
+                continue;
+            };
+            log::info!("Display configuration changed, re-placing window");
+            if let Err(e) = place_window(&window, selector.as_ref(), &placement) {
+                log::warn!("Failed to re-place window: {}", e);
+            }
+        });
+    }
+}
+
+/// 창을 모니터에 배치 (전체화면이면 모니터 전체, 아니면 모니터 가운데)
+fn place_window(
+    window: &WebviewWindow,
+    selector: Option<&DisplaySelector>,
+    placement: &WindowPlacement,
+) -> Result<(), String> {
+    let monitors = window
+        .available_monitors()
+        .map_err(|e| format!("Failed to list monitors: {}", e))?;
+    let primary = window.primary_monitor().ok().flatten();
+    let selector = selector.unwrap_or(&DisplaySelector::Primary);
+
+    let monitor = match selector.select(&monitors, primary.as_ref()) {
+        Some(monitor) => monitor.clone(),
+        None => {
+            log::warn!("Display {:?} not found, using primary monitor", selector);
+            match primary.or_else(|| monitors.first().cloned()) {
+                Some(monitor) => monitor,
+                None => return Err("No monitor available".to_string()),
+            }
+        }
+    };
+
+    let origin = *monitor.position();
+    let area = *monitor.size();
+    let to_window_error = |e: tauri::Error| format!("Failed to place window: {}", e);
+
+    // 다른 모니터로 옮기려면 전체화면을 먼저 해제해야 함
+    window.set_fullscreen(false).map_err(to_window_error)?;
+
+    if placement.fullscreen {
+        window.set_position(origin).map_err(to_window_error)?;
+        window.set_fullscreen(true).map_err(to_window_error)?;
+    } else {
+        let size = LogicalSize::new(placement.window_width, placement.window_height)
+            .to_physical::<u32>(monitor.scale_factor());
+        let x = origin.x + (area.width.saturating_sub(size.width) / 2) as i32;
+        let y = origin.y + (area.height.saturating_sub(size.height) / 2) as i32;
+        window.set_size(size).map_err(to_window_error)?;
+        window
+            .set_position(PhysicalPosition::new(x, y))
+            .map_err(to_window_error)?;
+    }
+
+    log::info!(
+        "Window placed on display {} ({}x{} at {},{})",
+        monitor.name().map(|name| name.as_str()).unwrap_or("unknown"),
+        area.width,
+        area.height,
+        origin.x,
+        origin.y
+    );
+    Ok(())
+}
+
+fn monitor_layout(window: &WebviewWindow) -> Vec<MonitorKey> {
+    window
+        .available_monitors()
+        .map(|monitors| monitors.iter().map(monitor_key).collect())
+        .unwrap_or_default()
+}
+
+fn monitor_key(monitor: &Monitor) -> MonitorKey {
+    (
+        monitor.name().cloned(),
+        monitor.position().x,
+        monitor.position().y,
+        monitor.size().width,
+        monitor.size().height,
+    )
+}
+
+fn same_monitor(a: &Monitor, b: &Monitor) -> bool {
+    monitor_key(a) == monitor_key(b)
+}
@@ -1,13 +1,34 @@
+use crate::crypto::{ContentKey, SealedManifest, CONTENT_LOCKED_ERROR};
+use crate::license::{
+    check_license, current_machine_id, verify_trailer_signature, LicenseStatus, LicenseTerms,
+    SignatureStatus, SIGNATURE_SIZE,
+};
+use crate::schedule::ScheduleRule;
+use crate::update::installed_pack_path;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::fs::{self, File};
 use std::io::{Read, Seek, SeekFrom, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// 매직 바이트 - 임베딩된 데이터 식별용
 const MAGIC_BYTES: &[u8] = b"VISTUT_V1";
+/// 매직 바이트 - 암호화된 임베딩 데이터 식별용 (매니페스트가 SealedManifest)
+const MAGIC_BYTES_SEALED: &[u8] = b"VISTUT_E1";
+/// 매직 바이트 - 서명된 임베딩 데이터 (매니페스트 뒤에 Ed25519 서명)
+const MAGIC_BYTES_SIGNED: &[u8] = b"VISTUT_V2";
+/// 매직 바이트 - 서명된 암호화 임베딩 데이터
+const MAGIC_BYTES_SEALED_SIGNED: &[u8] = b"VISTUT_E2";
 const MAGIC_SIZE: usize = 9;
 const MANIFEST_SIZE_BYTES: usize = 8;
 
+/// 임베딩된 엔트리의 저장 인코딩 (없으면 원본 그대로 저장)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PayloadEncoding {
+    Zstd,
+}
+
 /// 미디어 파일 매니페스트 엔트리
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -16,19 +37,69 @@ pub struct MediaManifestEntry {
     pub name: String,
     pub mime_type: String,
     pub offset: u64,
+    /// 저장된 바이트 수 (압축된 경우 압축 후 크기)
+    pub size: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<PayloadEncoding>,
+    /// 압축 전 원본 크기 (압축된 경우에만 기록)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_size: Option<u64>,
+}
+
+/// 여러 튜토리얼을 함께 넣은 빌드의 프로젝트 엔트리
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectManifestEntry {
+    pub id: String,
+    pub name: String,
+    pub offset: u64,
     pub size: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<PayloadEncoding>,
+    /// 자동으로 재생할 시간 조건 (하나라도 맞으면 재생)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schedule: Vec<ScheduleRule>,
 }
 
 /// 빌드 매니페스트
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BuildManifest {
+    /// 기본 프로젝트 (여러 프로젝트면 첫 번째)
     pub project_json_offset: u64,
     pub project_json_size: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_json_encoding: Option<PayloadEncoding>,
+    /// 함께 넣은 모든 프로젝트 (2개 이상일 때만 기록, 미디어는 공유)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<ProjectManifestEntry>,
+    /// 함께 넣은 프로젝트가 제공하는 언어 (다국어 프로젝트가 없으면 비어 있음)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locales: Vec<String>,
     pub media: Vec<MediaManifestEntry>,
     pub buttons: Vec<MediaManifestEntry>,
     pub app_icon_offset: Option<u64>,
     pub app_icon_size: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_icon_encoding: Option<PayloadEncoding>,
+    /// 라이선스 조건 (사용 기간/허용 PC 제한, 트레일러 서명으로 보호)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<LicenseTerms>,
+    /// 콘텐츠 버전 (빌드/패치 시각, ms, 이전 버전 빌드는 None)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_version: Option<u64>,
+    /// 임베딩 데이터 시작 오프셋 (payload_sha256의 시작 위치)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_offset: Option<u64>,
+    /// 기본 프로젝트 ID (핫 폴더 업데이트가 같은 프로젝트의 콘텐츠인지 확인용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// payload_offset부터 매니페스트 앞까지의 SHA-256 (hex, 서명으로 보호됨)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_sha256: Option<String>,
+    /// 트레일러 서명 확인 결과 (매니페스트에 기록되지 않음)
+    #[serde(skip)]
+    pub signature: SignatureStatus,
 }
 
 /// 임베딩된 데이터 확인 결과
@@ -36,25 +107,218 @@ pub struct BuildManifest {
 #[serde(rename_all = "camelCase")]
 pub struct EmbeddedInfo {
     pub has_embedded_data: bool,
+    /// 암호화된 콘텐츠가 아직 잠금 해제되지 않음
+    #[serde(default)]
+    pub locked: bool,
+    /// 라이선스 확인 결과 (유효하지 않으면 manifest는 None)
+    pub license_status: LicenseStatus,
+    /// 현재 PC의 머신 ID (라이선스 문제 안내용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub machine_id: Option<String>,
     pub manifest: Option<BuildManifest>,
 }
 
+/// 트레일러에 기록된 매니페스트
+pub enum Trailer {
+    Plain(BuildManifest),
+    Sealed(SealedManifest),
+}
+
+impl Trailer {
+    /// 키 없이 읽을 수 있는 콘텐츠 버전
+    pub fn content_version(&self) -> Option<u64> {
+        match self {
+            Trailer::Plain(manifest) => manifest.content_version,
+            Trailer::Sealed(sealed) => sealed.content_version,
+        }
+    }
+}
+
+/// 열린 매니페스트와 복호화 키 (암호화되지 않았으면 None)
+pub struct OpenedManifest {
+    pub manifest: BuildManifest,
+    pub key: Option<ContentKey>,
+}
+
+/// 임베딩 데이터가 저장된 파일 (exe 자체, 또는 exe 옆의 데이터 팩 볼륨들)
+/// 여러 볼륨은 이어 붙인 하나의 데이터로 취급하며, 오프셋은 전체 기준
+#[derive(Debug, Clone)]
+pub struct DataSource {
+    volumes: Vec<PathBuf>,
+}
+
+impl DataSource {
+    /// 단일 파일 데이터
+    pub fn file(path: &Path) -> Self {
+        Self {
+            volumes: vec![path.to_path_buf()],
+        }
+    }
+
+    /// 데이터 팩 찾기 (<이름>.pak, 없으면 번호가 이어지는 <이름>.pak.001, .002, ...)
+    pub fn find_pack(pack_path: &Path) -> Option<Self> {
+        if pack_path.is_file() {
+            return Some(Self::file(pack_path));
+        }
+
+        let volumes: Vec<PathBuf> = (1..)
+            .map(|index| {
+                let mut name = pack_path.as_os_str().to_os_string();
+                name.push(format!(".{:03}", index));
+                PathBuf::from(name)
+            })
+            .take_while(|path| path.is_file())
+            .collect();
+
+        (!volumes.is_empty()).then_some(Self { volumes })
+    }
+
+    /// 데이터 파일 목록 (볼륨 순서)
+    pub fn volumes(&self) -> &[PathBuf] {
+        &self.volumes
+    }
+
+    /// 읽기용으로 열기
+    pub fn open(&self) -> Result<VolumeReader, String> {
+        let mut volumes = Vec::with_capacity(self.volumes.len());
+        let mut starts = Vec::with_capacity(self.volumes.len());
+        let mut total: u64 = 0;
+
+        for path in &self.volumes {
+            let file = File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+            let len = file
+                .metadata()
+                .map_err(|e| format!("Failed to get file metadata: {}", e))?
+                .len();
+            starts.push(total);
+            volumes.push((file, len));
+            total += len;
+        }
+
+        Ok(VolumeReader {
+            volumes,
+            starts,
+            total,
+            position: 0,
+        })
+    }
+}
+
+/// 여러 볼륨 파일을 하나로 이어 읽는 리더
+pub struct VolumeReader {
+    volumes: Vec<(File, u64)>,
+    starts: Vec<u64>,
+    total: u64,
+    position: u64,
+}
+
+impl Read for VolumeReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.position >= self.total || buf.is_empty() {
+            return Ok(0);
+        }
+
+        // 현재 위치를 포함하는 볼륨 찾기
+        let index = self.starts.partition_point(|&start| start <= self.position) - 1;
+        let local = self.position - self.starts[index];
+        let (file, len) = &mut self.volumes[index];
+
+        file.seek(SeekFrom::Start(local))?;
+        let max = (*len - local).min(buf.len() as u64) as usize;
+        let bytes_read = file.read(&mut buf[..max])?;
+
+        self.position += bytes_read as u64;
+        Ok(bytes_read)
+    }
+}
+
+impl Seek for VolumeReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(delta) => self.total.checked_add_signed(delta),
+            SeekFrom::Current(delta) => self.position.checked_add_signed(delta),
+        }
+        .ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, "Invalid seek position")
+        })?;
+
+        self.position = position;
+        Ok(position)
+    }
+}
+
 /// 현재 실행 파일 경로 가져오기
 pub fn get_current_exe_path() -> Result<std::path::PathBuf, String> {
     std::env::current_exe().map_err(|e| format!("Failed to get current exe path: {}", e))
 }
 
-/// 파일에서 매직 바이트 확인
-pub fn check_magic_bytes(path: &Path) -> Result<bool, String> {
-    let mut file = File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+/// 현재 exe의 임베딩 데이터 위치 찾기
+/// 핫 폴더에서 설치한 콘텐츠가 exe(또는 데이터 팩)보다 새 버전이면 설치한 콘텐츠를 사용
+pub fn locate_data_source() -> Result<Option<DataSource>, String> {
+    let built_in = locate_built_in_source()?;
+    let Some(installed) = installed_source() else {
+        return Ok(built_in);
+    };
+    let Some(built_in) = built_in else {
+        return Ok(Some(installed));
+    };
 
-    let file_size = file
-        .metadata()
-        .map_err(|e| format!("Failed to get file metadata: {}", e))?
-        .len();
+    let newer = match (read_trailer(&installed), read_trailer(&built_in)) {
+        (Ok(installed), Ok(built_in)) => installed.content_version() > built_in.content_version(),
+        (Err(e), _) | (_, Err(e)) => {
+            log::warn!("Installed content ignored: {}", e);
+            false
+        }
+    };
+    Ok(Some(if newer { installed } else { built_in }))
+}
+
+/// exe에 포함된 임베딩 데이터 위치
+/// exe 끝에 트레일러가 없으면 옆의 데이터 팩(<이름>.pak 또는 <이름>.pak.001, .002, ...)을 찾음
+fn locate_built_in_source() -> Result<Option<DataSource>, String> {
+    let exe_path = get_current_exe_path()?;
 
-    if file_size < (MAGIC_SIZE + MANIFEST_SIZE_BYTES) as u64 {
-        return Ok(false);
+    let exe = DataSource::file(&exe_path);
+    if check_magic_bytes(&exe)? {
+        return Ok(Some(exe));
+    }
+
+    match DataSource::find_pack(&exe_path.with_extension("pak")) {
+        Some(pack) => Ok(check_magic_bytes(&pack)?.then_some(pack)),
+        None => Ok(None),
+    }
+}
+
+/// 핫 폴더 업데이트로 설치한 콘텐츠 (없거나 트레일러가 없으면 None)
+fn installed_source() -> Option<DataSource> {
+    let path = installed_pack_path().ok().filter(|path| path.is_file())?;
+    let installed = DataSource::file(&path);
+    check_magic_bytes(&installed).unwrap_or(false).then_some(installed)
+}
+
+/// 매직 바이트별 트레일러 형식 (암호화 여부, 서명 여부)
+fn trailer_format(magic: &[u8]) -> Option<(bool, bool)> {
+    match magic {
+        MAGIC_BYTES => Some((false, false)),
+        MAGIC_BYTES_SEALED => Some((true, false)),
+        MAGIC_BYTES_SIGNED => Some((false, true)),
+        MAGIC_BYTES_SEALED_SIGNED => Some((true, true)),
+        _ => None,
+    }
+}
+
+/// 데이터에서 매직 바이트 확인 (일반/암호화/서명 모두)
+pub fn check_magic_bytes(source: &DataSource) -> Result<bool, String> {
+    Ok(read_magic_bytes(source)?.is_some())
+}
+
+/// 데이터 끝의 매직 바이트 읽기 (임베딩 데이터가 없으면 None)
+fn read_magic_bytes(source: &DataSource) -> Result<Option<&'static [u8]>, String> {
+    let mut file = source.open()?;
+
+    if file.total < (MAGIC_SIZE + MANIFEST_SIZE_BYTES) as u64 {
+        return Ok(None);
     }
 
     // 파일 끝에서 매직 바이트 위치로 이동
@@ -65,12 +329,60 @@ pub fn check_magic_bytes(path: &Path) -> Result<bool, String> {
     file.read_exact(&mut magic_buffer)
         .map_err(|e| format!("Failed to read magic bytes: {}", e))?;
 
-    Ok(&magic_buffer == MAGIC_BYTES)
+    Ok([
+        MAGIC_BYTES,
+        MAGIC_BYTES_SEALED,
+        MAGIC_BYTES_SIGNED,
+        MAGIC_BYTES_SEALED_SIGNED,
+    ]
+    .into_iter()
+    .find(|magic| &magic_buffer[..] == *magic))
+}
+
+/// 트레일러의 매니페스트 읽기
+/// 서명된 트레일러는 매니페스트와 크기 사이에 서명이 있음:
+/// [매니페스트][서명 64바이트][매니페스트 크기 8바이트][매직 바이트]
+pub fn read_trailer(source: &DataSource) -> Result<Trailer, String> {
+    let magic = read_magic_bytes(source)?.ok_or_else(|| "No embedded data found".to_string())?;
+    let (sealed, signed) =
+        trailer_format(magic).ok_or_else(|| "Unknown trailer format".to_string())?;
+
+    let mut file = source.open()?;
+    let (manifest_start, manifest_size) = locate_manifest(&mut file, signed)?;
+
+    // 매니페스트 JSON 읽기
+    file.seek(SeekFrom::Start(manifest_start))
+        .map_err(|e| format!("Failed to seek to manifest: {}", e))?;
+
+    let mut manifest_buffer = vec![0u8; manifest_size as usize];
+    file.read_exact(&mut manifest_buffer)
+        .map_err(|e| format!("Failed to read manifest: {}", e))?;
+
+    let signature = if signed {
+        let mut signature_buffer = [0u8; SIGNATURE_SIZE];
+        file.read_exact(&mut signature_buffer)
+            .map_err(|e| format!("Failed to read manifest signature: {}", e))?;
+        verify_trailer_signature(magic, &manifest_buffer, &signature_buffer)
+    } else {
+        SignatureStatus::Unsigned
+    };
+
+    let manifest_json =
+        String::from_utf8(manifest_buffer).map_err(|e| format!("Invalid UTF-8 in manifest: {}", e))?;
+
+    if sealed {
+        serde_json::from_str(&manifest_json)
+            .map(|sealed: SealedManifest| Trailer::Sealed(SealedManifest { signature, ..sealed }))
+            .map_err(|e| format!("Failed to parse sealed manifest JSON: {}", e))
+    } else {
+        parse_manifest(manifest_json.as_bytes())
+            .map(|manifest| Trailer::Plain(BuildManifest { signature, ..manifest }))
+    }
 }
 
-/// 매니페스트 읽기
-pub fn read_manifest(path: &Path) -> Result<BuildManifest, String> {
-    let mut file = File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+/// 매니페스트의 위치 (시작 오프셋, 크기)
+fn locate_manifest(file: &mut VolumeReader, signed: bool) -> Result<(u64, u64), String> {
+    let signature_size = if signed { SIGNATURE_SIZE } else { 0 };
 
     // 매니페스트 크기 읽기 (파일 끝에서 매직 바이트 + 8바이트 앞)
     file.seek(SeekFrom::End(-((MAGIC_SIZE + MANIFEST_SIZE_BYTES) as i64)))
@@ -82,39 +394,196 @@ pub fn read_manifest(path: &Path) -> Result<BuildManifest, String> {
 
     let manifest_size = u64::from_le_bytes(size_buffer);
 
-    // 매니페스트 JSON 읽기
-    file.seek(SeekFrom::End(
-        -((MAGIC_SIZE + MANIFEST_SIZE_BYTES) as i64 + manifest_size as i64),
-    ))
-    .map_err(|e| format!("Failed to seek to manifest: {}", e))?;
+    // 손상된 크기 값으로 큰 버퍼를 할당하거나 파일 앞을 벗어나 이동하지 않도록 먼저 확인
+    let trailer_size = (MAGIC_SIZE + MANIFEST_SIZE_BYTES + signature_size) as u64;
+    if file.total < trailer_size || manifest_size > file.total - trailer_size {
+        return Err(format!(
+            "Invalid manifest size: {} bytes exceeds file size {}",
+            manifest_size, file.total
+        ));
+    }
 
-    let mut manifest_buffer = vec![0u8; manifest_size as usize];
-    file.read_exact(&mut manifest_buffer)
-        .map_err(|e| format!("Failed to read manifest: {}", e))?;
+    Ok((file.total - trailer_size - manifest_size, manifest_size))
+}
 
-    let manifest_json =
-        String::from_utf8(manifest_buffer).map_err(|e| format!("Invalid UTF-8 in manifest: {}", e))?;
+/// payload_offset부터 매니페스트 앞까지의 SHA-256 (hex, 매니페스트의 payload_sha256과 비교)
+pub fn payload_digest(source: &DataSource, manifest: &BuildManifest) -> Result<String, String> {
+    let magic = read_magic_bytes(source)?.ok_or_else(|| "No embedded data found".to_string())?;
+    let (_, signed) = trailer_format(magic).ok_or_else(|| "Unknown trailer format".to_string())?;
+
+    let mut file = source.open()?;
+    let (manifest_start, _) = locate_manifest(&mut file, signed)?;
+    let payload_start = manifest
+        .payload_offset
+        .filter(|&offset| offset <= manifest_start)
+        .ok_or_else(|| "Invalid payload offset".to_string())?;
+
+    file.seek(SeekFrom::Start(payload_start))
+        .map_err(|e| format!("Failed to seek to payload: {}", e))?;
+    let mut hasher = Sha256::new();
+    let mut payload = file.take(manifest_start - payload_start);
+    std::io::copy(&mut payload, &mut hasher)
+        .map_err(|e| format!("Failed to read payload: {}", e))?;
+
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect())
+}
 
-    serde_json::from_str(&manifest_json).map_err(|e| format!("Failed to parse manifest JSON: {}", e))
+/// 매니페스트 열기
+/// 암호화된 경우 전달된 키, 없으면 내장 키로 복호화 (둘 다 없으면 잠금 에러)
+pub fn open_manifest(
+    source: &DataSource,
+    key: Option<&ContentKey>,
+) -> Result<OpenedManifest, String> {
+    match read_trailer(source)? {
+        Trailer::Plain(manifest) => Ok(OpenedManifest {
+            manifest,
+            key: None,
+        }),
+        Trailer::Sealed(sealed) => {
+            let key = match key {
+                Some(key) => key.clone(),
+                None => sealed
+                    .embedded_key()?
+                    .ok_or_else(|| CONTENT_LOCKED_ERROR.to_string())?,
+            };
+            let manifest = BuildManifest {
+                signature: sealed.signature,
+                ..parse_manifest(&sealed.open(&key)?)?
+            };
+
+            Ok(OpenedManifest {
+                manifest,
+                key: Some(key),
+            })
+        }
+    }
 }
 
-/// 임베딩된 프로젝트 JSON 읽기
-pub fn read_embedded_project(path: &Path, manifest: &BuildManifest) -> Result<String, String> {
-    let mut file = File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+fn parse_manifest(manifest_json: &[u8]) -> Result<BuildManifest, String> {
+    serde_json::from_slice(manifest_json)
+        .map_err(|e| format!("Failed to parse manifest JSON: {}", e))
+}
 
-    file.seek(SeekFrom::Start(manifest.project_json_offset))
-        .map_err(|e| format!("Failed to seek to project JSON: {}", e))?;
+/// 임베딩된 기본 프로젝트 JSON 읽기 (암호화/압축된 경우 자동 해제)
+pub fn read_embedded_project<R: Read + Seek>(
+    file: &mut R,
+    manifest: &BuildManifest,
+    key: Option<&ContentKey>,
+) -> Result<String, String> {
+    read_project_region(
+        file,
+        manifest.project_json_offset,
+        manifest.project_json_size,
+        manifest.project_json_encoding,
+        key,
+    )
+}
 
-    let mut buffer = vec![0u8; manifest.project_json_size as usize];
-    file.read_exact(&mut buffer)
+/// 여러 프로젝트 빌드에서 특정 프로젝트 JSON 읽기
+pub fn read_embedded_project_entry<R: Read + Seek>(
+    file: &mut R,
+    entry: &ProjectManifestEntry,
+    key: Option<&ContentKey>,
+) -> Result<String, String> {
+    read_project_region(file, entry.offset, entry.size, entry.encoding, key)
+}
+
+fn read_project_region<R: Read + Seek>(
+    file: &mut R,
+    offset: u64,
+    size: u64,
+    encoding: Option<PayloadEncoding>,
+    key: Option<&ContentKey>,
+) -> Result<String, String> {
+    let stored = read_embedded_region(file, offset, size, key)
         .map_err(|e| format!("Failed to read project JSON: {}", e))?;
+    let buffer = decode_payload(stored, encoding, None)?;
 
     String::from_utf8(buffer).map_err(|e| format!("Invalid UTF-8 in project JSON: {}", e))
 }
 
-/// 임베딩된 미디어 데이터 읽기
-pub fn read_embedded_media(path: &Path, offset: u64, size: u64) -> Result<Vec<u8>, String> {
-    let mut file = File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+/// 임베딩된 미디어 데이터 읽기 (암호화/압축된 경우 자동 해제)
+pub fn read_embedded_media<R: Read + Seek>(
+    file: &mut R,
+    entry: &MediaManifestEntry,
+    key: Option<&ContentKey>,
+) -> Result<Vec<u8>, String> {
+    let stored = read_embedded_region(file, entry.offset, entry.size, key)?;
+    decode_payload(stored, entry.encoding, entry.original_size)
+}
+
+/// 임베딩된 앱 아이콘 읽기 (없으면 None)
+pub fn read_embedded_app_icon<R: Read + Seek>(
+    file: &mut R,
+    manifest: &BuildManifest,
+    key: Option<&ContentKey>,
+) -> Result<Option<Vec<u8>>, String> {
+    let (Some(offset), Some(size)) = (manifest.app_icon_offset, manifest.app_icon_size) else {
+        return Ok(None);
+    };
+    let stored = read_embedded_region(file, offset, size, key)?;
+    decode_payload(stored, manifest.app_icon_encoding, None).map(Some)
+}
+
+/// 임베딩된 미디어의 원본 크기
+pub fn embedded_media_size(entry: &MediaManifestEntry, key: Option<&ContentKey>) -> u64 {
+    match (entry.original_size, key) {
+        (Some(original_size), _) => original_size,
+        (None, Some(key)) => key.plain_size(entry.size),
+        (None, None) => entry.size,
+    }
+}
+
+/// 임베딩된 미디어의 일부 구간 [start, start + length) 읽기
+/// 암호화된 영상도 해당 구간의 청크만 복호화하므로 전체를 메모리에 올리지 않음
+pub fn read_embedded_media_range<R: Read + Seek>(
+    file: &mut R,
+    entry: &MediaManifestEntry,
+    key: Option<&ContentKey>,
+    start: u64,
+    length: u64,
+) -> Result<Vec<u8>, String> {
+    // 압축된 엔트리는 작으므로 전체 복원 후 잘라내기
+    if entry.encoding.is_some() {
+        let data = read_embedded_media(file, entry, key)?;
+        let from = (start as usize).min(data.len());
+        let to = (start.saturating_add(length) as usize).min(data.len());
+        return Ok(data[from..to].to_vec());
+    }
+
+    if let Some(key) = key {
+        return key.decrypt_range(file, entry.offset, entry.size, start, length);
+    }
+
+    let end = start.saturating_add(length).min(entry.size);
+    if start >= end {
+        return Ok(Vec::new());
+    }
+
+    file.seek(SeekFrom::Start(entry.offset + start))
+        .map_err(|e| format!("Failed to seek to media: {}", e))?;
+
+    let mut buffer = vec![0u8; (end - start) as usize];
+    file.read_exact(&mut buffer)
+        .map_err(|e| format!("Failed to read media: {}", e))?;
+
+    Ok(buffer)
+}
+
+/// 지정된 오프셋의 영역 읽기 (암호화된 경우 복호화)
+pub fn read_embedded_region<R: Read + Seek>(
+    file: &mut R,
+    offset: u64,
+    size: u64,
+    key: Option<&ContentKey>,
+) -> Result<Vec<u8>, String> {
+    if let Some(key) = key {
+        return key.decrypt_region(file, offset, size);
+    }
 
     file.seek(SeekFrom::Start(offset))
         .map_err(|e| format!("Failed to seek to media: {}", e))?;
@@ -126,23 +595,58 @@ pub fn read_embedded_media(path: &Path, offset: u64, size: u64) -> Result<Vec<u8
     Ok(buffer)
 }
 
-/// 현재 exe에서 임베딩 정보 가져오기
-pub fn get_embedded_info() -> Result<EmbeddedInfo, String> {
-    let exe_path = get_current_exe_path()?;
+/// 저장 인코딩에 따라 데이터 복원
+fn decode_payload(
+    stored: Vec<u8>,
+    encoding: Option<PayloadEncoding>,
+    original_size: Option<u64>,
+) -> Result<Vec<u8>, String> {
+    match encoding {
+        None => Ok(stored),
+        Some(PayloadEncoding::Zstd) => match original_size {
+            Some(capacity) => zstd::bulk::decompress(&stored, capacity as usize),
+            None => zstd::decode_all(stored.as_slice()),
+        }
+        .map_err(|e| format!("Failed to decompress payload: {}", e)),
+    }
+}
 
-    if !check_magic_bytes(&exe_path)? {
+/// 현재 exe(또는 옆의 데이터 팩)에서 임베딩 정보 가져오기
+/// 암호화된 콘텐츠를 열 키가 없으면 locked 상태로 반환
+/// 라이선스가 유효하지 않으면 매니페스트 없이 라이선스 상태만 반환
+pub fn get_embedded_info(key: Option<&ContentKey>) -> Result<EmbeddedInfo, String> {
+    let Some(source) = locate_data_source()? else {
         return Ok(EmbeddedInfo {
             has_embedded_data: false,
+            locked: false,
+            license_status: LicenseStatus::Valid,
+            machine_id: None,
             manifest: None,
         });
-    }
-
-    let manifest = read_manifest(&exe_path)?;
+    };
 
-    Ok(EmbeddedInfo {
-        has_embedded_data: true,
-        manifest: Some(manifest),
-    })
+    match open_manifest(&source, key) {
+        Ok(opened) => {
+            let license_status = check_license(&opened.manifest);
+            let is_valid = license_status == LicenseStatus::Valid;
+
+            Ok(EmbeddedInfo {
+                has_embedded_data: true,
+                locked: false,
+                license_status,
+                machine_id: if is_valid { None } else { current_machine_id() },
+                manifest: if is_valid { Some(opened.manifest) } else { None },
+            })
+        }
+        Err(e) if e == CONTENT_LOCKED_ERROR => Ok(EmbeddedInfo {
+            has_embedded_data: true,
+            locked: true,
+            license_status: LicenseStatus::Valid,
+            machine_id: None,
+            manifest: None,
+        }),
+        Err(e) => Err(e),
+    }
 }
 
 /// 실행 파일 생성 (튜토리얼 임베딩)
@@ -181,6 +685,8 @@ pub fn create_embedded_executable(
             mime_type,
             offset: current_offset,
             size,
+            encoding: None,
+            original_size: None,
         });
 
         current_offset += size;
@@ -199,6 +705,8 @@ pub fn create_embedded_executable(
             mime_type,
             offset: current_offset,
             size,
+            encoding: None,
+            original_size: None,
         });
 
         current_offset += size;
@@ -227,10 +735,20 @@ pub fn create_embedded_executable(
     let manifest = BuildManifest {
         project_json_offset,
         project_json_size,
+        project_json_encoding: None,
+        projects: Vec::new(),
+        locales: Vec::new(),
         media: media_entries,
         buttons: button_entries,
         app_icon_offset,
         app_icon_size,
+        app_icon_encoding: None,
+        license: None,
+        content_version: None,
+        payload_offset: None,
+        project_id: None,
+        payload_sha256: None,
+        signature: SignatureStatus::Unsigned,
     };
 
     let manifest_json =
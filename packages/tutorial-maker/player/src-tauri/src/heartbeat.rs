@@ -0,0 +1,589 @@
+use crate::analytics::now_ms;
+use crate::content::EmbeddedContent;
+use crate::control::ControlHub;
+use crate::license::{current_machine_id, LicenseStatus};
+use crate::watchdog::{app_data_dir, WatchdogStatus};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// 상태 파일 이름 (앱 데이터 폴더)
+const STATUS_FILE: &str = "status.json";
+
+/// 상태 파일 경로를 지정하는 환경 변수 (모니터링 에이전트가 읽는 위치에 기록)
+pub const STATUS_FILE_ENV: &str = "VISTUT_STATUS_FILE";
+
+/// 너무 잦은 기록 방지
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 상태 전송 연결/응답 제한 시간
+const POST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 프로젝트 설정의 상태 보고 (settings.heartbeat)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeartbeatSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// 기록 주기 (초)
+    #[serde(default = "default_interval_seconds")]
+    pub interval_seconds: u64,
+    /// 상태를 POST할 주소 (http://호스트:포트/경로, 없으면 파일에만 기록)
+    #[serde(default)]
+    pub endpoint: Option<String>,
+    /// Authorization: Bearer 헤더로 보낼 토큰 (평문 HTTP라 루프백 주소로만 보냄)
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+fn default_interval_seconds() -> u64 {
+    60
+}
+
+#[derive(Deserialize)]
+struct ProjectHeartbeat {
+    settings: SettingsHeartbeat,
+}
+
+#[derive(Deserialize)]
+struct SettingsHeartbeat {
+    #[serde(default)]
+    heartbeat: Option<HeartbeatSettings>,
+}
+
+impl HeartbeatSettings {
+    /// 프로젝트 JSON에서 상태 보고 설정 읽기 (꺼져 있으면 None)
+    pub fn from_project_json(project_json: &str) -> Result<Option<Self>, String> {
+        let project: ProjectHeartbeat = serde_json::from_str(project_json)
+            .map_err(|e| format!("Failed to parse heartbeat settings: {}", e))?;
+        Ok(project.settings.heartbeat.filter(|heartbeat| heartbeat.enabled))
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_seconds).max(MIN_INTERVAL)
+    }
+
+    fn endpoint(&self) -> Option<&str> {
+        self.endpoint
+            .as_deref()
+            .map(str::trim)
+            .filter(|endpoint| !endpoint.is_empty())
+    }
+}
+
+/// 프론트엔드가 알려준 현재 화면
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PlayerScreen {
+    /// 시작 중 (콘텐츠 확인 전)
+    #[default]
+    Loading,
+    /// 튜토리얼 재생 중
+    Playing,
+    /// 여러 튜토리얼 중 선택 화면
+    Launcher,
+    /// 암호 입력 대기
+    Locked,
+    /// 라이선스 문제로 재생 불가
+    License,
+    /// 로드 실패 또는 자동 재시작 중단
+    Error,
+    /// 임베딩 콘텐츠 없이 파일을 여는 일반 모드
+    Viewer,
+}
+
+/// 무결성 확인 단계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VerificationState {
+    /// 아직 콘텐츠를 열지 않음
+    #[default]
+    Pending,
+    Running,
+    Passed,
+    Failed,
+}
+
+/// 열어 둔 콘텐츠의 무결성 확인 결과
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Verification {
+    pub state: VerificationState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 확인을 마친 시각 (ms)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checked_at_ms: Option<u64>,
+}
+
+/// 마지막 에러
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorRecord {
+    pub message: String,
+    pub at_ms: u64,
+}
+
+/// 주기적으로 기록하는 상태
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusRecord {
+    pub timestamp_ms: u64,
+    /// 기록 주기 (모니터링에서 이 시간보다 오래 갱신이 없으면 멈춤으로 판단)
+    pub interval_seconds: u64,
+    pub machine_id: Option<String>,
+    pub player_version: String,
+    /// 빌드 식별값 (매니페스트의 SHA-256, 콘텐츠를 열기 전이면 None)
+    pub build_hash: Option<String>,
+    pub content_version: Option<u64>,
+    pub screen: PlayerScreen,
+    pub project_id: Option<String>,
+    pub page_index: usize,
+    pub page_id: Option<String>,
+    pub uptime_seconds: u64,
+    /// 감시 프로세스가 재시작한 횟수
+    pub restart_count: u32,
+    pub license_status: Option<LicenseStatus>,
+    pub verification: Verification,
+    pub last_error: Option<ErrorRecord>,
+    /// 사용 가능한 메모리 (확인할 수 없으면 None)
+    pub free_memory_bytes: Option<u64>,
+}
+
+/// 상태 기록에 쓸 현재 콘텐츠 (프론트엔드가 다시 로드하면 바뀜)
+pub type ContentSource = Arc<dyn Fn() -> Option<Arc<EmbeddedContent>> + Send + Sync>;
+
+#[derive(Default)]
+struct ReporterState {
+    screen: PlayerScreen,
+    last_error: Option<ErrorRecord>,
+    verification: Verification,
+    /// 무결성 확인을 시작한 빌드 (바뀌면 다시 확인)
+    verified_build: Option<String>,
+}
+
+/// 플레이어 상태를 파일에 기록하고 설정된 주소로 전송 (한 번만 시작)
+pub struct StatusReporter {
+    started: AtomicBool,
+    launched_at: Instant,
+    state: Mutex<ReporterState>,
+}
+
+impl Default for StatusReporter {
+    fn default() -> Self {
+        Self {
+            started: AtomicBool::new(false),
+            launched_at: Instant::now(),
+            state: Mutex::new(ReporterState::default()),
+        }
+    }
+}
+
+impl StatusReporter {
+    /// 프론트엔드의 현재 화면 기록 (에러가 있으면 마지막 에러로 기록)
+    pub fn report_screen(&self, screen: PlayerScreen, error: Option<String>) {
+        if let Ok(mut state) = self.state.lock() {
+            state.screen = screen;
+        }
+        if let Some(message) = error {
+            self.record_error(message);
+        }
+    }
+
+    /// 마지막 에러 기록 (같은 에러가 반복되면 처음 시각 유지)
+    pub fn record_error(&self, message: String) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if state.last_error.as_ref().is_some_and(|last| last.message == message) {
+            return;
+        }
+        state.last_error = Some(ErrorRecord {
+            message,
+            at_ms: now_ms(),
+        });
+    }
+
+    /// 주기적인 상태 기록 시작
+    pub fn start(
+        self: &Arc<Self>,
+        settings: HeartbeatSettings,
+        hub: Arc<ControlHub>,
+        content: ContentSource,
+    ) {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let path = status_file_path();
+        log::info!("Writing player status to {:?}", path);
+
+        let reporter = Arc::clone(self);
+        std::thread::spawn(move || loop {
+            let current = content();
+            if let Some(current) = &current {
+                reporter.verify_if_needed(current);
+            }
+
+            let record = reporter.record(&settings, &hub, current.as_deref());
+            match serde_json::to_string_pretty(&record) {
+                Ok(json) => {
+                    if let Err(e) = write_status_file(&path, &json) {
+                        log::warn!("Failed to write player status: {}", e);
+                    }
+                    if let Some(endpoint) = settings.endpoint() {
+                        if let Err(e) = post_status(endpoint, settings.token.as_deref(), &json) {
+                            log::warn!("Failed to send player status: {}", e);
+                        }
+                    }
+                }
+                Err(e) => log::warn!("Failed to serialize player status: {}", e),
+            }
+
+            std::thread::sleep(settings.interval());
+        });
+    }
+
+    /// 현재 상태
+    pub fn record(
+        &self,
+        settings: &HeartbeatSettings,
+        hub: &ControlHub,
+        content: Option<&EmbeddedContent>,
+    ) -> StatusRecord {
+        let (screen, last_error, verification) = self
+            .state
+            .lock()
+            .map(|state| (state.screen, state.last_error.clone(), state.verification.clone()))
+            .unwrap_or_default();
+        let control = hub.status();
+
+        StatusRecord {
+            timestamp_ms: now_ms(),
+            interval_seconds: settings.interval().as_secs(),
+            machine_id: current_machine_id(),
+            player_version: env!("CARGO_PKG_VERSION").to_string(),
+            build_hash: content.map(|content| content.build_hash().to_string()),
+            content_version: content.and_then(EmbeddedContent::content_version),
+            screen,
+            project_id: control.project_id,
+            page_index: control.page_index,
+            page_id: control.page_id,
+            uptime_seconds: self.launched_at.elapsed().as_secs(),
+            restart_count: WatchdogStatus::from_env().restart_count,
+            license_status: content.map(EmbeddedContent::license_status),
+            verification,
+            last_error,
+            free_memory_bytes: free_memory_bytes(),
+        }
+    }
+
+    /// 처음 보는 빌드면 백그라운드에서 무결성 확인 (큰 콘텐츠는 오래 걸리므로 기록과 분리)
+    fn verify_if_needed(self: &Arc<Self>, content: &Arc<EmbeddedContent>) {
+        let build = content.build_hash().to_string();
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if state.verified_build.as_deref() == Some(build.as_str()) {
+            return;
+        }
+        state.verified_build = Some(build.clone());
+        state.verification = Verification {
+            state: VerificationState::Running,
+            ..Verification::default()
+        };
+        drop(state);
+
+        let content = Arc::clone(content);
+        let reporter = Arc::clone(self);
+        std::thread::spawn(move || {
+            let result = content.verify();
+            if let Err(e) = &result {
+                log::error!("Content verification failed: {}", e);
+            }
+            let Ok(mut state) = reporter.state.lock() else {
+                return;
+            };
+            // 확인 중에 콘텐츠가 바뀌었으면 새 빌드의 결과를 기다림
+            if state.verified_build.as_deref() != Some(build.as_str()) {
+                return;
+            }
+            state.verification = Verification {
+                state: if result.is_ok() {
+                    VerificationState::Passed
+                } else {
+                    VerificationState::Failed
+                },
+                error: result.err(),
+                checked_at_ms: Some(now_ms()),
+            };
+        });
+    }
+}
+
+/// 상태 파일 경로 (환경 변수가 없으면 앱 데이터 폴더의 status.json)
+pub fn status_file_path() -> PathBuf {
+    std::env::var_os(STATUS_FILE_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| app_data_dir().join(STATUS_FILE))
+}
+
+/// 임시 파일에 쓴 뒤 교체 (모니터링이 반쯤 쓴 파일을 읽지 않도록)
+fn write_status_file(path: &Path, json: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create {:?}: {}", dir, e))?;
+    }
+    let mut temp = path.as_os_str().to_os_string();
+    temp.push(".tmp");
+    let temp = PathBuf::from(temp);
+
+    fs::write(&temp, json).map_err(|e| format!("Failed to write {:?}: {}", temp, e))?;
+    fs::rename(&temp, path).map_err(|e| format!("Failed to replace {:?}: {}", path, e))
+}
+
+/// 상태 JSON을 POST (http://만 지원, 2xx 응답이 아니면 실패)
+///
+/// TLS가 없으므로 토큰은 같은 PC의 수집 에이전트(루프백 주소)로만 보내고,
+/// 다른 주소면 토큰이 노출되지 않도록 전송하지 않음
+fn post_status(endpoint: &str, token: Option<&str>, body: &str) -> Result<(), String> {
+    let rest = endpoint
+        .strip_prefix("http://")
+        .ok_or_else(|| format!("Unsupported status endpoint (http:// only): {}", endpoint))?;
+    let (host, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, "/"),
+    };
+    // 포트가 없으면 80 (IPv6 주소의 ':'는 포트로 보지 않음)
+    let has_port = host.rsplit(']').next().is_some_and(|tail| tail.contains(':'));
+    let address = if has_port {
+        host.to_string()
+    } else {
+        format!("{}:80", host)
+    };
+    let socket = address
+        .to_socket_addrs()
+        .map_err(|e| format!("Invalid status endpoint '{}': {}", endpoint, e))?
+        .next()
+        .ok_or_else(|| format!("Status endpoint not resolved: {}", endpoint))?;
+    let token = token.map(str::trim).filter(|token| !token.is_empty());
+    if token.is_some() && !socket.ip().is_loopback() {
+        return Err(format!(
+            "Refusing to send token over plain HTTP to non-loopback endpoint: {}",
+            endpoint
+        ));
+    }
+
+    let mut stream = TcpStream::connect_timeout(&socket, POST_TIMEOUT)
+        .map_err(|e| format!("Failed to connect to {}: {}", socket, e))?;
+    stream
+        .set_read_timeout(Some(POST_TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(POST_TIMEOUT)))
+        .map_err(|e| format!("Failed to configure connection: {}", e))?;
+
+    let mut request = format!(
+        "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\n\
+         Content-Length: {}\r\nConnection: close\r\n",
+        path,
+        host,
+        body.len()
+    );
+    if let Some(token) = token {
+        request.push_str(&format!("Authorization: Bearer {}\r\n", token));
+    }
+    request.push_str("\r\n");
+    request.push_str(body);
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| format!("Failed to send status: {}", e))?;
+
+    let mut status_line = String::new();
+    BufReader::new(stream)
+        .read_line(&mut status_line)
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+    let code = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| format!("Invalid response: {}", status_line.trim()))?;
+    if !(200..300).contains(&code) {
+        return Err(format!("Endpoint responded {}", status_line.trim()));
+    }
+    Ok(())
+}
+
+/// 사용 가능한 메모리 (Windows, KB 단위로 받음)
+#[cfg(target_os = "windows")]
+pub fn free_memory_bytes() -> Option<u64> {
+    use std::os::windows::process::CommandExt;
+    use std::process::Command;
+
+    /// 콘솔 창을 띄우지 않고 실행
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    let output = Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-NonInteractive",
+            "-Command",
+            "(Get-CimInstance Win32_OperatingSystem).FreePhysicalMemory",
+        ])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let kilobytes = String::from_utf8_lossy(&output.stdout).trim().parse::<u64>().ok()?;
+    Some(kilobytes * 1024)
+}
+
+/// 사용 가능한 메모리 (macOS, 빈 페이지와 비활성 페이지의 합)
+#[cfg(target_os = "macos")]
+pub fn free_memory_bytes() -> Option<u64> {
+    use std::process::Command;
+
+    let output = Command::new("vm_stat").output().ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+
+    // "Mach Virtual Memory Statistics: (page size of 16384 bytes)" 형식 파싱
+    let page_size = text
+        .lines()
+        .next()
+        .and_then(|line| line.split("page size of ").nth(1))
+        .and_then(|rest| rest.split_whitespace().next())
+        .and_then(|size| size.parse::<u64>().ok())?;
+    let pages = |name: &str| {
+        text.lines()
+            .find(|line| line.starts_with(name))
+            .and_then(|line| line.split(':').nth(1))
+            .and_then(|count| count.trim().trim_end_matches('.').parse::<u64>().ok())
+    };
+
+    Some((pages("Pages free")? + pages("Pages inactive").unwrap_or(0)) * page_size)
+}
+
+/// 사용 가능한 메모리 (Linux 등, /proc/meminfo의 MemAvailable)
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub fn free_memory_bytes() -> Option<u64> {
+    let meminfo = fs::read_to_string("/proc/meminfo").ok()?;
+    let kilobytes = meminfo
+        .lines()
+        .find(|line| line.starts_with("MemAvailable:"))?
+        .split_whitespace()
+        .nth(1)?
+        .parse::<u64>()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    /// 받은 요청 (요청 줄, 헤더, 본문)
+    struct Received {
+        request_line: String,
+        headers: Vec<(String, String)>,
+        body: String,
+    }
+
+    impl Received {
+        fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    /// 요청 하나를 받고 지정한 상태 줄로 응답하는 대역 서버
+    fn stand_in_server(status_line: &'static str) -> (String, JoinHandle<Received>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut headers = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                let (key, value) = line.split_once(':').unwrap();
+                headers.push((key.to_string(), value.trim().to_string()));
+            }
+            let length = headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+                .map(|(_, value)| value.parse::<usize>().unwrap())
+                .unwrap();
+            let mut body = vec![0u8; length];
+            reader.read_exact(&mut body).unwrap();
+
+            let mut stream = reader.into_inner();
+            write!(stream, "{}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n", status_line)
+                .unwrap();
+            Received {
+                request_line: request_line.trim_end().to_string(),
+                headers,
+                body: String::from_utf8(body).unwrap(),
+            }
+        });
+        (format!("http://{}/status/kiosk-1", address), handle)
+    }
+
+    #[test]
+    fn posts_json_to_stand_in_server() {
+        let (endpoint, server) = stand_in_server("HTTP/1.1 204 No Content");
+        let body = r#"{"screen":"playing","pageIndex":2,"note":"한글"}"#;
+        post_status(&endpoint, Some(" secret "), body).unwrap();
+
+        let received = server.join().unwrap();
+        assert_eq!(received.request_line, "POST /status/kiosk-1 HTTP/1.1");
+        assert_eq!(received.header("Content-Type"), Some("application/json"));
+        let length = body.len().to_string();
+        assert_eq!(received.header("Content-Length"), Some(length.as_str()));
+        assert_eq!(received.header("Authorization"), Some("Bearer secret"));
+        assert_eq!(received.body, body);
+        let json: serde_json::Value = serde_json::from_str(&received.body).unwrap();
+        assert_eq!(json["pageIndex"], 2);
+    }
+
+    #[test]
+    fn omits_empty_token() {
+        let (endpoint, server) = stand_in_server("HTTP/1.1 200 OK");
+        post_status(&endpoint, Some("  "), "{}").unwrap();
+        assert_eq!(server.join().unwrap().header("Authorization"), None);
+    }
+
+    #[test]
+    fn fails_on_non_success_status() {
+        let (endpoint, server) = stand_in_server("HTTP/1.1 503 Service Unavailable");
+        let error = post_status(&endpoint, None, "{}").unwrap_err();
+        assert!(error.contains("503"), "{}", error);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn refuses_token_for_remote_endpoint() {
+        // 연결하기 전에 거부하므로 응답하지 않는 문서용 주소(TEST-NET-1)를 사용
+        let error = post_status("http://192.0.2.1:8080/status", Some("secret"), "{}").unwrap_err();
+        assert!(error.contains("non-loopback"), "{}", error);
+        assert!(post_status("ftp://127.0.0.1/status", None, "{}").is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::analytics::{AnalyticsEvent, AnalyticsStore};
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+
+/// 대기 시간 확인 주기
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 대기 화면으로 돌아가라고 프론트엔드에 보내는 이벤트
+pub const IDLE_RESET_EVENT: &str = "idle-reset";
+
+/// 초기화 이벤트 내용
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdleReset {
+    /// 초기화 직전에 표시 중이던 페이지
+    pub page_index: usize,
+    pub page_id: Option<String>,
+    /// 마지막 입력 이후 지난 시간 (초)
+    pub idle_seconds: u64,
+}
+
+struct IdleState {
+    /// 대기 시간 (None이면 사용 안 함)
+    timeout: Option<Duration>,
+    last_activity: Instant,
+    /// 마지막 입력 이후 아직 초기화하지 않았는지
+    /// 초기화 후에는 다음 입력이 있을 때까지 반복하지 않음
+    armed: bool,
+    page_index: usize,
+    page_id: Option<String>,
+}
+
+/// 방문자 입력이 없는 시간을 추적하여 대기 화면으로 초기화
+pub struct IdleMonitor {
+    state: Mutex<IdleState>,
+}
+
+impl Default for IdleMonitor {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(IdleState {
+                timeout: None,
+                last_activity: Instant::now(),
+                armed: false,
+                page_index: 0,
+                page_id: None,
+            }),
+        }
+    }
+}
+
+impl IdleMonitor {
+    /// 대기 시간 설정 (0이면 사용 안 함)
+    pub fn configure(&self, timeout_seconds: u64) {
+        if let Ok(mut state) = self.state.lock() {
+            state.timeout = (timeout_seconds > 0).then_some(Duration::from_secs(timeout_seconds));
+            state.last_activity = Instant::now();
+        }
+    }
+
+    /// 방문자 입력 발생 (터치, 클릭, 키 입력)
+    pub fn touch(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.last_activity = Instant::now();
+            state.armed = true;
+        }
+    }
+
+    /// 현재 표시 중인 페이지 (영상 종료로 자동 이동한 경우도 포함, 입력으로 보지 않음)
+    pub fn set_page(&self, page_index: usize, page_id: Option<String>) {
+        if let Ok(mut state) = self.state.lock() {
+            state.page_index = page_index;
+            state.page_id = page_id;
+        }
+    }
+
+    /// 대기 시간이 지났으면 초기화 정보를 돌려주고 다음 입력까지 비활성화
+    fn take_expired(&self) -> Option<IdleReset> {
+        let mut state = self.state.lock().ok()?;
+        let timeout = state.timeout?;
+        let idle = state.last_activity.elapsed();
+        if !state.armed || idle < timeout {
+            return None;
+        }
+
+        state.armed = false;
+        Some(IdleReset {
+            page_index: state.page_index,
+            page_id: state.page_id.clone(),
+            idle_seconds: idle.as_secs(),
+        })
+    }
+
+    /// 대기 시간 확인 스레드 시작
+    /// 초기화 시 프론트엔드에 이벤트를 보내고 방문자 통계에 기록
+    pub fn spawn(app: AppHandle) {
+        std::thread::spawn(move || loop {
+            std::thread::sleep(CHECK_INTERVAL);
+
+            let Some(reset) = app.state::<IdleMonitor>().take_expired() else {
+                continue;
+            };
+
+            log::info!(
+                "Idle for {}s on page {}, returning to attract page",
+                reset.idle_seconds,
+                reset.page_index
+            );
+
+            if let Err(e) = app.emit(IDLE_RESET_EVENT, reset.clone()) {
+                log::warn!("Failed to emit idle reset: {}", e);
+            }
+
+            let event = AnalyticsEvent::IdleReset {
+                page_index: reset.page_index,
+                page_id: reset.page_id.unwrap_or_default(),
+            };
+            if let Err(e) = app.state::<AnalyticsStore>().record(event) {
+                log::warn!("Failed to record idle reset: {}", e);
+            }
+        });
+    }
+}
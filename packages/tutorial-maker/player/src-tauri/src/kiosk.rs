@@ -0,0 +1,230 @@
+use argon2::password_hash::{PasswordHash, PasswordVerifier};
+use argon2::Argon2;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{WebviewWindow, Window, WindowEvent};
+
+/// 관리자 PIN이 틀렸을 때 반환하는 오류
+pub const INVALID_ADMIN_PIN_ERROR: &str = "Invalid admin PIN";
+
+/// PIN을 여러 번 틀려 잠시 입력을 막았을 때 반환하는 오류
+pub const ADMIN_PIN_THROTTLED_ERROR: &str = "Too many invalid admin PIN attempts";
+
+/// 첫 실패 후 대기 시간 (실패할 때마다 두 배)
+const PIN_RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// 최대 대기 시간
+const MAX_PIN_RETRY_DELAY: Duration = Duration::from_secs(300);
+
+/// 프로젝트 설정의 키오스크 모드 (settings.kiosk)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KioskSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// 관리자 PIN의 Argon2 해시 (PHC 문자열, 없으면 종료 키만으로 종료)
+    #[serde(default)]
+    pub admin_pin_hash: Option<String>,
+    /// 입력이 없으면 커서를 숨길 시간 (초)
+    #[serde(default)]
+    pub hide_cursor_seconds: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct ProjectKiosk {
+    settings: SettingsKiosk,
+}
+
+#[derive(Deserialize)]
+struct SettingsKiosk {
+    #[serde(default)]
+    kiosk: Option<KioskSettings>,
+}
+
+impl KioskSettings {
+    /// 프로젝트 JSON에서 키오스크 설정 읽기 (꺼져 있으면 None)
+    pub fn from_project_json(project_json: &str) -> Result<Option<Self>, String> {
+        let project: ProjectKiosk = serde_json::from_str(project_json)
+            .map_err(|e| format!("Failed to parse kiosk settings: {}", e))?;
+        Ok(project.settings.kiosk.filter(|kiosk| kiosk.enabled))
+    }
+
+    fn has_admin_pin(&self) -> bool {
+        matches!(&self.admin_pin_hash, Some(hash) if !hash.is_empty())
+    }
+
+    /// PIN 확인 (Argon2 PHC 해시만 허용, 다른 형식이면 어떤 PIN도 맞지 않음)
+    fn verify_pin(&self, pin: &str) -> bool {
+        let Some(expected) = &self.admin_pin_hash else {
+            return false;
+        };
+
+        let Ok(hash) = PasswordHash::new(expected) else {
+            log::warn!("Admin PIN hash is not an Argon2 PHC string; set the PIN again");
+            return false;
+        };
+        Argon2::default()
+            .verify_password(pin.as_bytes(), &hash)
+            .is_ok()
+    }
+}
+
+/// 관리자 PIN 연속 실패 기록 (실패할 때마다 다음 시도까지 대기 시간이 늘어남)
+#[derive(Default)]
+struct PinThrottle {
+    failures: u32,
+    retry_after: Option<Instant>,
+}
+
+impl PinThrottle {
+    fn is_blocked(&self, now: Instant) -> bool {
+        self.retry_after.is_some_and(|retry_after| now < retry_after)
+    }
+
+    fn record_failure(&mut self, now: Instant) {
+        self.failures = self.failures.saturating_add(1);
+        let exponent = self.failures.saturating_sub(1).min(16);
+        let delay = PIN_RETRY_BASE_DELAY
+            .saturating_mul(1 << exponent)
+            .min(MAX_PIN_RETRY_DELAY);
+        self.retry_after = Some(now + delay);
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// 프론트엔드에 알려주는 키오스크 상태
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KioskStatus {
+    pub enabled: bool,
+    /// 종료 시 관리자 PIN 입력 필요 여부
+    pub requires_pin: bool,
+    pub hide_cursor_seconds: Option<u64>,
+}
+
+/// 키오스크 잠금 상태
+/// 창 닫기 차단, 항상 위 전체화면 유지
+#[derive(Default)]
+pub struct KioskState {
+    settings: Mutex<Option<KioskSettings>>,
+    /// 종료 키 또는 관리자 PIN으로 종료가 허용됨
+    exit_allowed: AtomicBool,
+    pin_throttle: Mutex<PinThrottle>,
+}
+
+impl KioskState {
+    pub fn status(&self) -> KioskStatus {
+        match self.settings.lock().ok().and_then(|settings| settings.clone()) {
+            Some(settings) => KioskStatus {
+                enabled: true,
+                requires_pin: settings.has_admin_pin(),
+                hide_cursor_seconds: settings.hide_cursor_seconds,
+            },
+            None => KioskStatus::default(),
+        }
+    }
+
+    /// 창 닫기를 막아야 하는지
+    pub fn is_locked(&self) -> bool {
+        let enabled = self
+            .settings
+            .lock()
+            .map(|settings| settings.is_some())
+            .unwrap_or(false);
+        enabled && !self.exit_allowed.load(Ordering::SeqCst)
+    }
+
+    /// 키오스크 설정 적용 (꺼져 있으면 아무것도 하지 않음)
+    pub fn apply(
+        &self,
+        window: &WebviewWindow,
+        settings: Option<KioskSettings>,
+    ) -> Result<(), String> {
+        let enabled = settings.is_some();
+        if let Ok(mut current) = self.settings.lock() {
+            *current = settings;
+        }
+        if !enabled {
+            return Ok(());
+        }
+
+        window
+            .set_resizable(false)
+            .and_then(|_| window.set_minimizable(false))
+            .and_then(|_| window.set_fullscreen(true))
+            .and_then(|_| window.set_always_on_top(true))
+            .and_then(|_| window.set_zoom(1.0))
+            .and_then(|_| window.set_focus())
+            .map_err(|e| format!("Failed to lock window: {}", e))?;
+
+        // 개발 빌드에서만 열 수 있는 개발자 도구도 닫아둠
+        #[cfg(debug_assertions)]
+        window.close_devtools();
+
+        log::info!("Kiosk lockdown enabled");
+        Ok(())
+    }
+
+    /// 잠금 해제 (라이선스 만료 등으로 재생을 멈춘 경우 창을 닫을 수 있게)
+    pub fn release(&self) {
+        self.exit_allowed.store(true, Ordering::SeqCst);
+    }
+
+    /// 종료 허용 (관리자 PIN이 설정되어 있으면 PIN 확인)
+    /// PIN을 틀리면 대기 시간이 지나기 전까지는 확인하지 않고 거부
+    pub fn allow_exit(&self, pin: Option<&str>) -> Result<(), String> {
+        let settings = self.settings.lock().ok().and_then(|settings| settings.clone());
+        if let Some(settings) = settings.filter(|settings| settings.has_admin_pin()) {
+            // 확인하는 동안 잠가 두어 동시에 들어온 요청이 대기 시간을 건너뛰지 못하게 함
+            let mut throttle = self
+                .pin_throttle
+                .lock()
+                .map_err(|_| INVALID_ADMIN_PIN_ERROR.to_string())?;
+            if throttle.is_blocked(Instant::now()) {
+                log::warn!("Kiosk exit rejected: admin PIN retry delay not elapsed");
+                return Err(ADMIN_PIN_THROTTLED_ERROR.to_string());
+            }
+            if !pin.is_some_and(|pin| settings.verify_pin(pin)) {
+                throttle.record_failure(Instant::now());
+                log::warn!(
+                    "Kiosk exit rejected: invalid admin PIN ({} consecutive failures)",
+                    throttle.failures
+                );
+                return Err(INVALID_ADMIN_PIN_ERROR.to_string());
+            }
+            throttle.reset();
+        }
+
+        self.exit_allowed.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// 창 이벤트 처리 (닫기 차단, 포커스를 잃으면 다시 전체화면/항상 위로)
+    pub fn handle_window_event(&self, window: &Window, event: &WindowEvent) {
+        if !self.is_locked() {
+            return;
+        }
+
+        match event {
+            WindowEvent::CloseRequested { api, .. } => {
+                log::info!("Window close blocked by kiosk lockdown");
+                api.prevent_close();
+            }
+            WindowEvent::Focused(false) => {
+                let result = window
+                    .set_always_on_top(true)
+                    .and_then(|_| window.set_fullscreen(true))
+                    .and_then(|_| window.set_focus());
+                if let Err(e) = result {
+                    log::warn!("Failed to restore kiosk window: {}", e);
+                }
+            }
+            _ => {}
+        }
+    }
+}
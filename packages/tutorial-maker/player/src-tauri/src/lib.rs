@@ -1,11 +1,117 @@
+mod analytics;
+mod content;
+mod control;
+mod crypto;
+mod diagnostics;
+mod display;
 mod embedded;
+mod heartbeat;
+mod idle;
+mod kiosk;
+mod license;
+mod osc;
+mod prefetch;
+mod protocol;
+mod schedule;
+mod update;
+mod watchdog;
 
+use analytics::{now_ms, AnalyticsEvent, AnalyticsExportRequest, AnalyticsStore};
+use content::EmbeddedContent;
+use control::{
+    ControlApiSettings, ControlCommand, ControlDispatch, ControlHub, CONTROL_COMMAND_EVENT,
+};
+use crypto::ContentKey;
+use diagnostics::{
+    app_log_dir, parse_log_level, project_log_level, DEFAULT_LOG_LEVEL, KEEP_LOG_FILES,
+    LOG_FILE_NAME, LOG_LEVEL_ENV, MAX_LOG_FILE_BYTES,
+};
+use display::{DisplaySelector, DisplayState, WindowPlacement};
 use embedded::{
-    check_magic_bytes, create_embedded_executable, get_current_exe_path, get_embedded_info,
-    read_embedded_media, read_embedded_project, read_manifest, EmbeddedInfo,
+    create_embedded_executable, get_embedded_info, locate_data_source, open_manifest,
+    read_trailer, DataSource, EmbeddedInfo, Trailer,
 };
-use serde::Deserialize;
-use std::path::PathBuf;
+use heartbeat::{ContentSource, HeartbeatSettings, PlayerScreen, StatusReporter};
+use idle::IdleMonitor;
+use kiosk::{KioskSettings, KioskState, KioskStatus};
+use license::{check_license, current_machine_id, LicenseStatus, LICENSE_INVALID_ERROR};
+use osc::{OscBridge, OscSettings};
+use prefetch::DEFAULT_PREFETCH_BUDGET_BYTES;
+use protocol::{error_response, media_response, MEDIA_PROTOCOL};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tauri::http::StatusCode;
+use tauri::{Emitter, Manager};
+use tauri_plugin_cli::CliExt;
+use tauri_plugin_log::{RotationStrategy, Target, TargetKind, TimezoneStrategy};
+use update::{
+    resolve_key, ContentUpdateStatus, ContentUpdater, HotFolderSettings, UpdateNotify,
+    CONTENT_UPDATE_EVENT,
+};
+use watchdog::{WatchdogSettings, WatchdogStatus};
+
+/// 잠금 해제 키를 전달하는 환경 변수
+const CONTENT_KEY_ENV: &str = "VISTUT_CONTENT_KEY";
+
+/// 프리페치 메모리 예산(MB)을 지정하는 환경 변수 (0이면 캐시하지 않음)
+const PREFETCH_BUDGET_ENV: &str = "VISTUT_PREFETCH_MB";
+
+/// 여러 프로젝트 빌드에서 재생할 프로젝트를 지정하는 환경 변수 (ID, 번호 또는 이름)
+const PROJECT_ENV: &str = "VISTUT_PROJECT";
+const LOCALE_ENV: &str = "VISTUT_LANG";
+
+/// 잠금 해제된 콘텐츠 키 (암호 모드로 암호화된 exe에서 사용)
+#[derive(Default)]
+struct ContentKeyState(Mutex<Option<ContentKey>>);
+
+impl ContentKeyState {
+    fn get(&self) -> Option<ContentKey> {
+        self.0.lock().ok().and_then(|key| key.clone())
+    }
+
+    fn set(&self, key: Option<ContentKey>) {
+        if let Ok(mut state) = self.0.lock() {
+            *state = key;
+        }
+    }
+}
+
+/// 열어 둔 임베딩 콘텐츠 (시작 시 또는 잠금 해제 시 로드)
+#[derive(Default)]
+struct ContentState(Mutex<Option<Arc<EmbeddedContent>>>);
+
+impl ContentState {
+    fn get(&self) -> Option<Arc<EmbeddedContent>> {
+        self.0.lock().ok().and_then(|content| content.clone())
+    }
+}
+
+/// 미디어 구간 읽기 결과
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EmbeddedMediaChunk {
+    data: Vec<u8>,
+    /// 미디어 전체 크기 (원본 기준)
+    total_size: u64,
+}
+
+/// 선택 화면에 표시할 프로젝트
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EmbeddedProjectSummary {
+    id: String,
+    name: String,
+}
+
+/// 함께 넣은 프로젝트 목록과 선택된 프로젝트
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EmbeddedProjects {
+    /// 단일 프로젝트 빌드면 비어 있음
+    projects: Vec<EmbeddedProjectSummary>,
+    selected: Option<String>,
+}
 
 /// Export 요청 데이터
 #[derive(Debug, Deserialize)]
@@ -28,69 +134,565 @@ struct ExportRequest {
     app_icon: Option<Vec<u8>>,
 }
 
+/// 현재 exe(또는 데이터 팩)의 임베딩 데이터 위치
+fn current_data_source() -> Result<DataSource, String> {
+    locate_data_source()?.ok_or_else(|| "No embedded data found".to_string())
+}
+
+/// 현재 exe의 임베딩 콘텐츠를 열어 상태에 저장 (잠금 해제된 키 사용)
+fn load_content(
+    keys: &ContentKeyState,
+    content: &ContentState,
+) -> Result<Arc<EmbeddedContent>, String> {
+    let loaded = Arc::new(EmbeddedContent::load(
+        current_data_source()?,
+        keys.get().as_ref(),
+        prefetch_budget(),
+    )?);
+    select_launch_project(&loaded);
+    apply_log_level(&loaded);
+
+    if let Ok(mut state) = content.0.lock() {
+        *state = Some(loaded.clone());
+    }
+    Ok(loaded)
+}
+
+/// 프로젝트 설정의 로그 수준 적용 (실행 시 --log-level 인자나 환경 변수가 있으면 그대로 유지)
+fn apply_log_level(content: &EmbeddedContent) {
+    if matches!(launch_log_level(), Ok(Some(_))) {
+        return;
+    }
+    let level = content
+        .read_project()
+        .and_then(|json| project_log_level(&json));
+    match level {
+        Ok(level) => log::set_max_level(level.unwrap_or(DEFAULT_LOG_LEVEL)),
+        Err(e) => log::warn!("Project log level not applied: {}", e),
+    }
+}
+
+/// 실행 시 지정된 프로젝트 선택 (여러 프로젝트 빌드에서만)
+fn select_launch_project(content: &EmbeddedContent) {
+    if content.projects().len() < 2 {
+        return;
+    }
+    let Some(selector) = launch_arg_before_start("project", PROJECT_ENV) else {
+        return;
+    };
+    if let Err(e) = content.select_project(&selector) {
+        log::warn!("Launch project not selected: {}", e);
+    }
+}
+
+/// 임베딩 프로젝트의 창 설정 적용 (모니터 배치, 키오스크 잠금)
+/// 라이선스가 유효하지 않거나 자동 재시작을 포기했으면 안내 화면에 갇히지 않도록 잠그지 않음
+/// 여러 프로젝트 빌드에서 선택 전이면 기본 프로젝트의 설정으로 선택 화면을 띄움
+fn apply_window_settings(app: &tauri::AppHandle, content: &EmbeddedContent) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    let project_json = match content.read_project() {
+        Ok(json) => json,
+        Err(e) => {
+            log::warn!("Window settings not applied: {}", e);
+            return;
+        }
+    };
+
+    let gave_up = WatchdogStatus::from_env().failure.is_some();
+    let kiosk = if content.license_status() == LicenseStatus::Valid && !gave_up {
+        KioskSettings::from_project_json(&project_json).unwrap_or_else(|e| {
+            log::warn!("Kiosk settings not applied: {}", e);
+            None
+        })
+    } else {
+        None
+    };
+
+    let mut placement = WindowPlacement::from_project_json(&project_json).unwrap_or_else(|e| {
+        log::warn!("Window placement settings not applied: {}", e);
+        WindowPlacement::default()
+    });
+    // 키오스크 모드는 항상 전체화면
+    placement.fullscreen |= kiosk.is_some();
+
+    let display = app.state::<DisplayState>();
+    if let Err(e) = display.place(&window, launch_display(app), placement) {
+        log::warn!("Failed to place window: {}", e);
+    }
+    if let Err(e) = app.state::<KioskState>().apply(&window, kiosk) {
+        log::warn!("Failed to apply kiosk lockdown: {}", e);
+    }
+}
+
+/// 프로젝트 설정의 원격 제어 서버와 OSC 연동 시작 (처음 설정을 읽은 프로젝트 기준, 한 번만 시작)
+/// 받은 명령은 프론트엔드에 이벤트로 전달하여 화면 조작과 같은 이동 로직으로 처리
+fn start_remote_control(app: &tauri::AppHandle, content: &EmbeddedContent) {
+    if content.license_status() != LicenseStatus::Valid {
+        return;
+    }
+    let project_json = match content.read_project() {
+        Ok(json) => json,
+        Err(e) => {
+            log::warn!("Remote control not started: {}", e);
+            return;
+        }
+    };
+
+    let handle = app.clone();
+    let dispatch: ControlDispatch = Arc::new(move |command: ControlCommand| {
+        if let Err(e) = handle.emit(CONTROL_COMMAND_EVENT, command) {
+            log::warn!("Failed to emit control command: {}", e);
+        }
+    });
+    let hub = app.state::<Arc<ControlHub>>();
+
+    match ControlApiSettings::from_project_json(&project_json) {
+        Ok(Some(settings)) => {
+            if let Err(e) = hub.start(&settings, dispatch.clone()) {
+                log::warn!("Control API not started: {}", e);
+            }
+        }
+        Ok(None) => {}
+        Err(e) => log::warn!("Control API not started: {}", e),
+    }
+
+    match OscSettings::from_project_json(&project_json) {
+        Ok(Some(settings)) => {
+            if let Err(e) = app.state::<OscBridge>().start(&hub, &settings, dispatch) {
+                log::warn!("OSC not started: {}", e);
+            }
+        }
+        Ok(None) => {}
+        Err(e) => log::warn!("OSC not started: {}", e),
+    }
+}
+
+/// 프로젝트 설정의 핫 폴더 감시 시작 (처음 설정을 읽은 프로젝트 기준, 한 번만 시작)
+/// 새 콘텐츠를 받아 두면 프론트엔드에 알려 다음 대기 화면 복귀 때 적용
+fn start_content_updates(app: &tauri::AppHandle, content: &EmbeddedContent) {
+    if content.license_status() != LicenseStatus::Valid {
+        return;
+    }
+    let settings = content
+        .read_project()
+        .and_then(|json| HotFolderSettings::from_project_json(&json));
+    let settings = match settings {
+        Ok(Some(settings)) => settings,
+        Ok(None) => return,
+        Err(e) => {
+            log::warn!("Hot folder updates not started: {}", e);
+            return;
+        }
+    };
+
+    let handle = app.clone();
+    let notify: UpdateNotify = Arc::new(move |version: u64| {
+        if let Err(e) = handle.emit(CONTENT_UPDATE_EVENT, version) {
+            log::warn!("Failed to emit content update: {}", e);
+        }
+    });
+    let updater = app.state::<Arc<ContentUpdater>>();
+    updater.start(settings, launch_passphrase(app), notify);
+}
+
+/// 프로젝트 설정의 상태 기록 시작 (처음 설정을 읽은 프로젝트 기준, 한 번만 시작)
+/// 라이선스 문제도 모니터링에서 알 수 있도록 라이선스와 관계없이 시작
+fn start_status_reports(app: &tauri::AppHandle, content: &EmbeddedContent) {
+    let settings = content
+        .read_project()
+        .and_then(|json| HeartbeatSettings::from_project_json(&json));
+    let settings = match settings {
+        Ok(Some(settings)) => settings,
+        Ok(None) => return,
+        Err(e) => {
+            log::warn!("Status reports not started: {}", e);
+            return;
+        }
+    };
+
+    let handle = app.clone();
+    let source: ContentSource = Arc::new(move || handle.state::<ContentState>().get());
+    let hub = app.state::<Arc<ControlHub>>().inner().clone();
+    let reporter = app.state::<Arc<StatusReporter>>();
+    reporter.start(settings, hub, source);
+}
+
+/// 프리페치 메모리 예산 (환경 변수가 없으면 기본값)
+fn prefetch_budget() -> u64 {
+    std::env::var(PREFETCH_BUDGET_ENV)
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(|mb| mb * 1024 * 1024)
+        .unwrap_or(DEFAULT_PREFETCH_BUDGET_BYTES)
+}
+
+/// 열어 둔 임베딩 콘텐츠 가져오기 (아직 열지 않았으면 로드)
+/// 라이선스가 유효하지 않으면 콘텐츠를 제공하지 않음
+fn current_content(
+    keys: &ContentKeyState,
+    content: &ContentState,
+) -> Result<Arc<EmbeddedContent>, String> {
+    let loaded = match content.get() {
+        Some(loaded) => loaded,
+        None => load_content(keys, content)?,
+    };
+
+    if loaded.license_status() != LicenseStatus::Valid {
+        return Err(LICENSE_INVALID_ERROR.to_string());
+    }
+
+    Ok(loaded)
+}
+
+/// 암호로 콘텐츠 잠금 해제 후 콘텐츠 로드
+fn unlock_with_passphrase(
+    keys: &ContentKeyState,
+    content: &ContentState,
+    passphrase: &str,
+) -> Result<(), String> {
+    let source = current_data_source()?;
+
+    let sealed = match read_trailer(&source)? {
+        Trailer::Sealed(sealed) => sealed,
+        Trailer::Plain(_) => return Ok(()),
+    };
+
+    let key = sealed.derive_key(passphrase)?;
+    // 매니페스트 복호화로 키 검증
+    sealed.open(&key)?;
+
+    keys.set(Some(key));
+    load_content(keys, content)?;
+    Ok(())
+}
+
 /// 임베딩 정보 확인
 #[tauri::command]
-fn get_embedded_data_info() -> Result<EmbeddedInfo, String> {
-    get_embedded_info()
+fn get_embedded_data_info(keys: tauri::State<ContentKeyState>) -> Result<EmbeddedInfo, String> {
+    get_embedded_info(keys.get().as_ref())
 }
 
-/// 임베딩된 프로젝트 JSON 가져오기
+/// 실행 중 라이선스 재확인 (장시간 실행 중 만료 감지용)
 #[tauri::command]
-fn get_embedded_project_json() -> Result<String, String> {
-    let exe_path = get_current_exe_path()?;
-
-    if !check_magic_bytes(&exe_path)? {
-        return Err("No embedded data found".to_string());
+fn check_embedded_license(
+    keys: tauri::State<ContentKeyState>,
+    content: tauri::State<ContentState>,
+    kiosk: tauri::State<KioskState>,
+) -> Result<LicenseStatus, String> {
+    if let Some(loaded) = content.get() {
+        let status = loaded.refresh_license();
+        if status != LicenseStatus::Valid {
+            kiosk.release();
+        }
+        return Ok(status);
     }
 
-    let manifest = read_manifest(&exe_path)?;
-    read_embedded_project(&exe_path, &manifest)
+    let source = current_data_source()?;
+    let opened = open_manifest(&source, keys.get().as_ref())?;
+    Ok(check_license(&opened.manifest))
 }
 
-/// 임베딩된 미디어 데이터 가져오기
+/// 현재 PC의 머신 ID (라이선스 허용 목록 등록용)
 #[tauri::command]
-fn get_embedded_media_data(id: String) -> Result<Vec<u8>, String> {
-    let exe_path = get_current_exe_path()?;
+fn get_machine_id() -> Option<String> {
+    current_machine_id()
+}
 
-    if !check_magic_bytes(&exe_path)? {
-        return Err("No embedded data found".to_string());
+/// 암호화된 콘텐츠 잠금 해제
+#[tauri::command]
+fn unlock_embedded_content(
+    app: tauri::AppHandle,
+    keys: tauri::State<ContentKeyState>,
+    content: tauri::State<ContentState>,
+    passphrase: String,
+) -> Result<(), String> {
+    unlock_with_passphrase(&keys, &content, &passphrase)?;
+    if let Some(loaded) = content.get() {
+        apply_window_settings(&app, &loaded);
+        start_remote_control(&app, &loaded);
+        start_content_updates(&app, &loaded);
+        start_status_reports(&app, &loaded);
     }
+    Ok(())
+}
+
+/// 임베딩된 프로젝트 JSON 가져오기
+#[tauri::command]
+fn get_embedded_project_json(
+    keys: tauri::State<ContentKeyState>,
+    content: tauri::State<ContentState>,
+) -> Result<String, String> {
+    current_content(&keys, &content)?.read_project()
+}
+
+/// 함께 넣은 프로젝트 목록 (여러 프로젝트 빌드의 선택 화면용)
+#[tauri::command]
+fn get_embedded_projects(
+    keys: tauri::State<ContentKeyState>,
+    content: tauri::State<ContentState>,
+) -> Result<EmbeddedProjects, String> {
+    let loaded = current_content(&keys, &content)?;
+    let projects = loaded
+        .projects()
+        .iter()
+        .map(|project| EmbeddedProjectSummary {
+            id: project.id.clone(),
+            name: project.name.clone(),
+        })
+        .collect();
 
-    let manifest = read_manifest(&exe_path)?;
+    Ok(EmbeddedProjects {
+        projects,
+        selected: loaded.selected_project().map(|project| project.id),
+    })
+}
 
-    // 미디어에서 찾기
-    if let Some(entry) = manifest.media.iter().find(|e| e.id == id) {
-        return read_embedded_media(&exe_path, entry.offset, entry.size);
+/// 재생 일정에 맞는 프로젝트 ID
+/// 일정이 없거나 맞는 것이 없으면 None, 실행 인자로 프로젝트를 지정했으면 일정을 따르지 않음
+#[tauri::command]
+fn get_scheduled_project(
+    keys: tauri::State<ContentKeyState>,
+    content: tauri::State<ContentState>,
+    utc_offset_minutes: i32,
+) -> Result<Option<String>, String> {
+    if launch_arg_before_start("project", PROJECT_ENV).is_some() {
+        return Ok(None);
     }
 
-    // 버튼에서 찾기
-    if let Some(entry) = manifest.buttons.iter().find(|e| e.id == id) {
-        return read_embedded_media(&exe_path, entry.offset, entry.size);
+    let loaded = current_content(&keys, &content)?;
+    let scheduled =
+        schedule::scheduled_project(loaded.projects(), now_ms() as i64, utc_offset_minutes);
+    Ok(scheduled.map(|project| project.id.clone()))
+}
+
+/// 실행 인자(--lang) 또는 환경 변수로 지정한 시작 언어
+/// 콘텐츠가 제공하지 않는 언어면 None (프로젝트의 기본 언어로 시작)
+#[tauri::command]
+fn get_launch_locale(
+    keys: tauri::State<ContentKeyState>,
+    content: tauri::State<ContentState>,
+) -> Result<Option<String>, String> {
+    let Some(locale) = launch_arg_before_start("lang", LOCALE_ENV) else {
+        return Ok(None);
+    };
+
+    let loaded = current_content(&keys, &content)?;
+    let locale = locale.trim();
+    let available =
+        loaded.locales().iter().find(|available| available.eq_ignore_ascii_case(locale)).cloned();
+    if available.is_none() {
+        log::warn!("Launch locale not available: {}", locale);
     }
+    Ok(available)
+}
+
+/// 재생할 프로젝트 선택 (선택한 프로젝트의 창 설정을 다시 적용)
+#[tauri::command]
+fn select_embedded_project(
+    app: tauri::AppHandle,
+    keys: tauri::State<ContentKeyState>,
+    content: tauri::State<ContentState>,
+    id: String,
+) -> Result<(), String> {
+    let loaded = current_content(&keys, &content)?;
+    let project = loaded.select_project(&id)?;
+    log::info!("Project selected: {} ({})", project.name, project.id);
+    apply_log_level(&loaded);
+    apply_window_settings(&app, &loaded);
+    start_remote_control(&app, &loaded);
+    start_content_updates(&app, &loaded);
+    start_status_reports(&app, &loaded);
+    Ok(())
+}
+
+/// 임베딩된 미디어 데이터 가져오기 (미디어, 버튼 이미지 순서로 ID 검색)
+#[tauri::command]
+fn get_embedded_media_data(
+    keys: tauri::State<ContentKeyState>,
+    content: tauri::State<ContentState>,
+    id: String,
+) -> Result<Vec<u8>, String> {
+    current_content(&keys, &content)?.read_media(&id)
+}
 
-    Err(format!("Media not found: {}", id))
+/// 임베딩된 미디어의 일부 구간 가져오기 (대용량 영상 스트리밍용)
+#[tauri::command]
+fn get_embedded_media_range(
+    keys: tauri::State<ContentKeyState>,
+    content: tauri::State<ContentState>,
+    id: String,
+    start: u64,
+    length: u64,
+) -> Result<EmbeddedMediaChunk, String> {
+    let (data, total_size) =
+        current_content(&keys, &content)?.read_media_range(&id, start, length)?;
+    Ok(EmbeddedMediaChunk { data, total_size })
 }
 
 /// 임베딩된 앱 아이콘 가져오기
 #[tauri::command]
-fn get_embedded_app_icon() -> Result<Option<Vec<u8>>, String> {
-    let exe_path = get_current_exe_path()?;
+fn get_embedded_app_icon(
+    keys: tauri::State<ContentKeyState>,
+    content: tauri::State<ContentState>,
+) -> Result<Option<Vec<u8>>, String> {
+    current_content(&keys, &content)?.read_app_icon()
+}
 
-    if !check_magic_bytes(&exe_path)? {
-        return Err("No embedded data found".to_string());
-    }
+/// 현재 표시 중인 페이지 알림 (이동 가능한 다음 페이지의 미디어 프리페치, 원격 제어 구독자에게 전송)
+#[tauri::command]
+fn set_current_page(
+    keys: tauri::State<ContentKeyState>,
+    content: tauri::State<ContentState>,
+    idle: tauri::State<IdleMonitor>,
+    control: tauri::State<Arc<ControlHub>>,
+    page_index: usize,
+    page_id: Option<String>,
+) -> Result<(), String> {
+    idle.set_page(page_index, page_id.clone());
+    let loaded = current_content(&keys, &content)?;
+    let project_id = loaded.selected_project().map(|project| project.id);
+    control.page_changed(project_id, page_index, page_id);
+    loaded.prefetch_from(page_index);
+    Ok(())
+}
+
+/// 대기 화면 복귀 시간 설정 (프로젝트 설정의 idleTimeoutSeconds, 0이면 사용 안 함)
+#[tauri::command]
+fn configure_idle_reset(idle: tauri::State<IdleMonitor>, timeout_seconds: u64) {
+    idle.configure(timeout_seconds);
+}
+
+/// 방문자 입력 알림 (대기 시간 초기화)
+#[tauri::command]
+fn report_activity(idle: tauri::State<IdleMonitor>) {
+    idle.touch();
+}
 
-    let manifest = read_manifest(&exe_path)?;
+/// 키오스크 모드 상태
+#[tauri::command]
+fn get_kiosk_status(kiosk: tauri::State<KioskState>) -> KioskStatus {
+    kiosk.status()
+}
+
+/// 키오스크 모드 종료 (종료 키 확인 후 호출, 관리자 PIN이 있으면 PIN 필요)
+#[tauri::command]
+fn exit_kiosk(
+    app: tauri::AppHandle,
+    kiosk: tauri::State<KioskState>,
+    pin: Option<String>,
+) -> Result<(), String> {
+    kiosk.allow_exit(pin.as_deref())?;
+    app.exit(0);
+    Ok(())
+}
+
+/// 프론트엔드 하트비트 (감시 프로세스가 화면 멈춤 감지에 사용)
+#[tauri::command]
+fn watchdog_heartbeat() -> Result<(), String> {
+    watchdog::beat()
+}
+
+/// 자동 재시작 상태 (재시작 횟수, 포기한 사유)
+#[tauri::command]
+fn get_watchdog_status() -> WatchdogStatus {
+    WatchdogStatus::from_env()
+}
+
+/// 현재 화면 알림 (상태 기록용, 에러 화면이면 에러 내용 포함)
+#[tauri::command]
+fn report_player_state(
+    reporter: tauri::State<Arc<StatusReporter>>,
+    screen: PlayerScreen,
+    error: Option<String>,
+) {
+    reporter.report_screen(screen, error);
+}
+
+/// 프론트엔드에서 처리하지 못한 에러 기록 (상태 기록의 마지막 에러)
+#[tauri::command]
+fn report_player_error(reporter: tauri::State<Arc<StatusReporter>>, message: String) {
+    reporter.record_error(message);
+}
+
+/// 핫 폴더 업데이트 상태 (현재/대기 중/이전 콘텐츠 버전, 마지막 확인 결과)
+#[tauri::command]
+fn get_content_update_status(updater: tauri::State<Arc<ContentUpdater>>) -> ContentUpdateStatus {
+    updater.status()
+}
 
-    match (manifest.app_icon_offset, manifest.app_icon_size) {
-        (Some(offset), Some(size)) => {
-            let data = read_embedded_media(&exe_path, offset, size)?;
-            Ok(Some(data))
+/// 받아 둔 새 콘텐츠로 교체 (프론트엔드가 대기 화면 복귀 시 호출, 적용한 버전 반환)
+/// 새 콘텐츠를 열지 못하면 이전 콘텐츠로 자동으로 되돌림
+#[tauri::command]
+fn apply_content_update(
+    app: tauri::AppHandle,
+    keys: tauri::State<ContentKeyState>,
+    content: tauri::State<ContentState>,
+    updater: tauri::State<Arc<ContentUpdater>>,
+    reporter: tauri::State<Arc<StatusReporter>>,
+) -> Result<Option<u64>, String> {
+    let previous_key = keys.get();
+    let installed = updater.install_pending(launch_passphrase(&app).as_deref())?;
+    keys.set(installed.key);
+
+    match load_content(&keys, &content) {
+        Ok(loaded) => {
+            apply_window_settings(&app, &loaded);
+            Ok(installed.version)
+        }
+        Err(e) => {
+            log::error!("Content update failed, rolling back: {}", e);
+            reporter.record_error(format!("Content update failed: {}", e));
+            updater.rollback()?;
+            keys.set(previous_key);
+            load_content(&keys, &content)?;
+            Err(format!("Content update failed: {}", e))
         }
-        _ => Ok(None),
     }
 }
 
+/// 설치한 콘텐츠를 이전 콘텐츠로 되돌림 (되돌린 버전은 다시 설치하지 않음)
+#[tauri::command]
+fn rollback_content_update(
+    app: tauri::AppHandle,
+    keys: tauri::State<ContentKeyState>,
+    content: tauri::State<ContentState>,
+    updater: tauri::State<Arc<ContentUpdater>>,
+) -> Result<Option<u64>, String> {
+    let version = updater.rollback()?;
+
+    // 되돌린 콘텐츠를 여는 키 (암호 모드인데 실행 시 암호가 없으면 기존 키 유지)
+    let passphrase = launch_passphrase(&app);
+    let key = read_trailer(&current_data_source()?)
+        .and_then(|trailer| resolve_key(&trailer, passphrase.as_deref()));
+    if let Ok(key) = key {
+        keys.set(key);
+    }
+
+    let loaded = load_content(&keys, &content)?;
+    apply_window_settings(&app, &loaded);
+    Ok(version)
+}
+
+/// 방문자 상호작용 기록
+#[tauri::command]
+fn record_analytics_event(
+    analytics: tauri::State<AnalyticsStore>,
+    event: AnalyticsEvent,
+) -> Result<(), String> {
+    analytics.record(event)
+}
+
+/// 방문자 기록을 날짜별로 요약하여 CSV/JSON으로 내보내기 (요약된 날짜 수 반환)
+#[tauri::command]
+fn export_analytics(
+    analytics: tauri::State<AnalyticsStore>,
+    request: AnalyticsExportRequest,
+) -> Result<usize, String> {
+    analytics.export(&request)
+}
+
 /// 실행 파일로 내보내기
 #[tauri::command]
 fn export_as_executable(request: ExportRequest) -> Result<(), String> {
@@ -117,27 +719,264 @@ fn export_as_executable(request: ExportRequest) -> Result<(), String> {
     )
 }
 
+/// 실행 시 전달된 잠금 해제 암호
+fn launch_passphrase(app: &tauri::AppHandle) -> Option<String> {
+    let from_cli = app.cli().matches().ok().and_then(|matches| {
+        matches
+            .args
+            .get("key")
+            .and_then(|arg| arg.value.as_str().map(|s| s.to_string()))
+    });
+
+    from_cli
+        .or_else(|| std::env::var(CONTENT_KEY_ENV).ok())
+        .filter(|passphrase| !passphrase.is_empty())
+}
+
+/// 실행 시 전달된 모니터 선택 (--display 인자, 프로젝트 설정보다 우선)
+fn launch_display(app: &tauri::AppHandle) -> Option<DisplaySelector> {
+    app.cli().matches().ok().and_then(|matches| {
+        matches
+            .args
+            .get("display")
+            .and_then(|arg| arg.value.as_str().and_then(DisplaySelector::parse))
+    })
+}
+
+/// 실행 인자 또는 환경 변수 값 (Tauri 시작 전에도 쓸 수 있도록 CLI 인자를 직접 확인)
+fn launch_arg_before_start(name: &str, env: &str) -> Option<String> {
+    cli_arg_before_start(name)
+        .or_else(|| std::env::var(env).ok())
+        .filter(|value| !value.is_empty())
+}
+
+/// 실행 인자 값 (--name 값 또는 --name=값)
+fn cli_arg_before_start(name: &str) -> Option<String> {
+    let flag = format!("--{}", name);
+    let prefix = format!("--{}=", name);
+    let mut args = std::env::args().skip(1);
+    let mut from_cli = None;
+    while let Some(arg) = args.next() {
+        if arg == flag {
+            from_cli = args.next();
+        } else if let Some(value) = arg.strip_prefix(&prefix) {
+            from_cli = Some(value.to_string());
+        }
+    }
+    from_cli
+}
+
+/// 실행 시 지정한 로그 수준 (--log-level 인자 또는 환경 변수, 프로젝트 설정보다 우선)
+/// 로거 초기화 전에도 호출되므로 잘못된 값은 에러로 돌려주고 호출하는 쪽에서 기록
+fn launch_log_level() -> Result<Option<log::LevelFilter>, String> {
+    match launch_arg_before_start("log-level", LOG_LEVEL_ENV) {
+        Some(value) => parse_log_level(&value).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// 로그 플러그인 (릴리스 빌드도 앱 로그 폴더에 기록하고 최대 크기를 넘으면 새 파일로 교체)
+/// 모든 수준을 받아 두고 실제 수준은 log::set_max_level로 조절 (프로젝트 설정을 읽은 뒤 변경)
+fn log_plugin<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
+    let mut targets = vec![Target::new(TargetKind::Folder {
+        path: app_log_dir(),
+        file_name: Some(LOG_FILE_NAME.to_string()),
+    })];
+    if cfg!(debug_assertions) {
+        targets.push(Target::new(TargetKind::Stdout));
+    }
+
+    tauri_plugin_log::Builder::default()
+        .targets(targets)
+        .level(log::LevelFilter::Trace)
+        .max_file_size(MAX_LOG_FILE_BYTES)
+        .rotation_strategy(RotationStrategy::KeepSome(KEEP_LOG_FILES))
+        .timezone_strategy(TimezoneStrategy::UseLocal)
+        .build()
+}
+
+/// 임베딩 프로젝트의 자동 재시작 설정 (Tauri 시작 전에 읽음)
+/// 암호 모드는 실행 시 암호가 전달된 경우에만 읽을 수 있음
+fn launch_watchdog_settings() -> Result<Option<WatchdogSettings>, String> {
+    let Some(source) = locate_data_source()? else {
+        return Ok(None);
+    };
+
+    let key = match read_trailer(&source)? {
+        Trailer::Sealed(sealed) if sealed.embedded_key()?.is_none() => {
+            match launch_arg_before_start("key", CONTENT_KEY_ENV) {
+                Some(passphrase) => Some(sealed.derive_key(&passphrase)?),
+                None => return Ok(None),
+            }
+        }
+        _ => None,
+    };
+
+    let content = EmbeddedContent::load(source, key.as_ref(), 0)?;
+    select_launch_project(&content);
+    WatchdogSettings::from_project_json(&content.read_project()?)
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // 진단 번들만 만들고 종료하는 실행 (--export-diagnostics <zip 경로>, 현장 점검용)
+    let diagnostics_output = cli_arg_before_start("export-diagnostics");
+
+    // 로거는 Tauri 시작 후에 초기화되므로 그 전의 경고는 모아 두었다가 기록
+    let mut startup_warnings = Vec::new();
+
+    // 자동 재시작이 켜져 있으면 이 프로세스는 감시만 하고 UI는 자식 프로세스로 실행
+    if diagnostics_output.is_none() && !watchdog::is_supervised_child() {
+        match launch_watchdog_settings() {
+            Ok(Some(settings)) => std::process::exit(watchdog::supervise(&settings)),
+            Ok(None) => {}
+            Err(e) => startup_warnings.push(format!("Watchdog disabled: {}", e)),
+        }
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_cli::init())
+        .plugin(log_plugin())
+        .manage(ContentKeyState::default())
+        .manage(ContentState::default())
+        .manage(IdleMonitor::default())
+        .manage(KioskState::default())
+        .manage(DisplayState::default())
+        .manage(Arc::new(ControlHub::default()))
+        .manage(OscBridge::default())
+        .manage(Arc::new(ContentUpdater::default()))
+        .manage(Arc::new(StatusReporter::default()))
+        .on_window_event(|window, event| {
+            window.state::<KioskState>().handle_window_event(window, event);
+        })
+        // 미디어는 IPC 대신 URI 스킴으로 제공 (웹뷰가 필요한 구간만 요청)
+        .register_asynchronous_uri_scheme_protocol(MEDIA_PROTOCOL, |ctx, request, responder| {
+            let app = ctx.app_handle().clone();
+            // 파일 읽기/복호화가 웹뷰 스레드를 막지 않도록 별도 스레드에서 처리
+            tauri::async_runtime::spawn_blocking(move || {
+                let keys = app.state::<ContentKeyState>();
+                let content = app.state::<ContentState>();
+                let response = match current_content(&keys, &content) {
+                    Ok(loaded) => media_response(&loaded, &request),
+                    Err(e) => error_response(StatusCode::FORBIDDEN, &e),
+                };
+                responder.respond(response);
+            });
+        })
         .invoke_handler(tauri::generate_handler![
             get_embedded_data_info,
+            check_embedded_license,
+            get_machine_id,
+            unlock_embedded_content,
             get_embedded_project_json,
+            get_embedded_projects,
+            select_embedded_project,
+            get_scheduled_project,
+            get_launch_locale,
             get_embedded_media_data,
+            get_embedded_media_range,
             get_embedded_app_icon,
+            set_current_page,
+            configure_idle_reset,
+            report_activity,
+            get_kiosk_status,
+            exit_kiosk,
+            watchdog_heartbeat,
+            get_watchdog_status,
+            report_player_state,
+            report_player_error,
+            get_content_update_status,
+            apply_content_update,
+            rollback_content_update,
+            record_analytics_event,
+            export_analytics,
             export_as_executable,
         ])
-        .setup(|app| {
-            if cfg!(debug_assertions) {
-                app.handle().plugin(
-                    tauri_plugin_log::Builder::default()
-                        .level(log::LevelFilter::Info)
-                        .build(),
-                )?;
+        .setup(move |app| {
+            let launch_level = launch_log_level().unwrap_or_else(|e| {
+                startup_warnings.push(format!("Launch log level ignored: {}", e));
+                None
+            });
+            log::set_max_level(launch_level.unwrap_or(DEFAULT_LOG_LEVEL));
+            log::info!("Tutorial Viewer {} started", env!("CARGO_PKG_VERSION"));
+            for warning in &startup_warnings {
+                log::warn!("{}", warning);
+            }
+
+            // 진단 번들만 만들고 종료 (결과는 앱 로그에 남기고 창은 표시하지 않음)
+            if let Some(output_path) = diagnostics_output {
+                let passphrase = launch_arg_before_start("key", CONTENT_KEY_ENV);
+                let output = Path::new(&output_path);
+                let code = match diagnostics::export_bundle(output, passphrase.as_deref()) {
+                    Ok(count) => {
+                        log::info!(
+                            "Diagnostics bundle written: {} ({} files)",
+                            output_path,
+                            count
+                        );
+                        0
+                    }
+                    Err(e) => {
+                        log::error!("Failed to export diagnostics: {}", e);
+                        1
+                    }
+                };
+                app.handle().exit(code);
+                return Ok(());
+            }
+
+            // 방문자 기록은 앱 데이터 폴더에 저장
+            let analytics_dir = app
+                .path()
+                .app_data_dir()
+                .unwrap_or_else(|_| std::env::temp_dir().join("tutorial-viewer"))
+                .join("analytics");
+            app.manage(AnalyticsStore::new(analytics_dir));
+            IdleMonitor::spawn(app.handle().clone());
+
+            let keys = app.state::<ContentKeyState>();
+            let content = app.state::<ContentState>();
+
+            // 실행 시 전달된 키로 잠금 해제 (--key 인자 또는 환경 변수)
+            if let Some(passphrase) = launch_passphrase(app.handle()) {
+                if let Err(e) = unlock_with_passphrase(&keys, &content, &passphrase) {
+                    log::warn!("Failed to unlock content with launch key: {}", e);
+                }
+            }
+
+            // 매니페스트를 미리 열어 첫 페이지 로딩 지연을 줄임
+            // (임베딩 데이터가 없거나 잠겨 있으면 이후 요청 시 다시 시도)
+            if content.get().is_none() {
+                if let Err(e) = load_content(&keys, &content) {
+                    log::info!("Embedded content not loaded at startup: {}", e);
+                }
+            }
+
+            // 창은 숨긴 채로 시작하여 모니터 배치 후 표시
+            match content.get() {
+                Some(loaded) => {
+                    apply_window_settings(app.handle(), &loaded);
+                    start_remote_control(app.handle(), &loaded);
+                    start_content_updates(app.handle(), &loaded);
+                    start_status_reports(app.handle(), &loaded);
+                }
+                None => {
+                    let display = app.state::<DisplayState>();
+                    if let Some(window) = app.get_webview_window("main") {
+                        let selector = launch_display(app.handle());
+                        let placement = WindowPlacement::default();
+                        if let Err(e) = display.place(&window, selector, placement) {
+                            log::warn!("Failed to place window: {}", e);
+                        }
+                    }
+                }
+            }
+            if let Some(window) = app.get_webview_window("main") {
+                window.show()?;
             }
+            DisplayState::watch_hotplug(app.handle().clone());
             Ok(())
         })
         .run(tauri::generate_context!())
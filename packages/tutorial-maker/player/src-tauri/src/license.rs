@@ -0,0 +1,360 @@
+use crate::embedded::BuildManifest;
+use crate::watchdog::app_data_dir;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use ed25519_dalek::{Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// 트레일러 서명 확인용 공개 키 (Ed25519, base64)
+/// 빌드 시 환경 변수로 넣으며, 설정되면 서명된 라이선스가 없는 콘텐츠는 실행하지 않음
+/// (maker는 이 문자열이 viewer.exe에 포함되어 있는지로 서명 키가 맞는지 확인)
+const LICENSE_PUBLIC_KEY: Option<&str> = option_env!("VISTUT_LICENSE_PUBLIC_KEY");
+
+/// Ed25519 서명 크기 (maker와 동일해야 함)
+pub const SIGNATURE_SIZE: usize = 64;
+
+/// 마지막으로 확인한 시각을 기록하는 파일 (시계를 되돌려 만료를 피하지 못하도록)
+const LICENSE_CLOCK_FILE: &str = "license-clock";
+
+/// 라이선스가 유효하지 않을 때 반환하는 에러 메시지 (프론트엔드에서 식별)
+pub const LICENSE_INVALID_ERROR: &str = "License is not valid";
+
+/// 라이선스 조건
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LicenseTerms {
+    /// 사용 시작 시각 (Unix ms)
+    #[serde(default)]
+    pub not_before: Option<u64>,
+    /// 만료 시각 (Unix ms)
+    #[serde(default)]
+    pub not_after: Option<u64>,
+    /// 실행을 허용할 머신 ID 목록 (비어 있으면 제한 없음)
+    #[serde(default)]
+    pub machine_ids: Vec<String>,
+}
+
+impl LicenseTerms {
+    /// 제한 조건이 하나라도 있는지 확인
+    pub fn has_restrictions(&self) -> bool {
+        self.not_before.is_some() || self.not_after.is_some() || !self.machine_ids.is_empty()
+    }
+}
+
+/// 트레일러 서명 확인 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SignatureStatus {
+    /// 서명이 없거나 확인할 공개 키가 없음
+    #[default]
+    Unsigned,
+    /// 공개 키로 확인됨
+    Verified,
+    /// 서명 불일치 (변조됨)
+    Invalid,
+}
+
+/// 라이선스 확인 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LicenseStatus {
+    /// 유효 (또는 라이선스 제한 없음)
+    Valid,
+    /// 사용 시작 전
+    NotYetValid,
+    /// 만료됨
+    Expired,
+    /// 허용되지 않은 PC
+    MachineNotAllowed,
+    /// 서명 불일치 (변조됨) 또는 서명된 라이선스가 없음
+    Invalid,
+}
+
+/// 서명된 트레일러인지 확인 (공개 키 없이 빌드된 player면 Unsigned)
+pub fn verify_trailer_signature(
+    magic: &[u8],
+    manifest: &[u8],
+    signature: &[u8],
+) -> SignatureStatus {
+    match LICENSE_PUBLIC_KEY {
+        Some(public_key) => verify_signature(public_key, magic, manifest, signature),
+        None => SignatureStatus::Unsigned,
+    }
+}
+
+/// 공개 키로 매직 바이트 + 매니페스트 바이트의 서명 확인
+fn verify_signature(
+    public_key: &str,
+    magic: &[u8],
+    manifest: &[u8],
+    signature: &[u8],
+) -> SignatureStatus {
+    let key = BASE64
+        .decode(public_key.trim())
+        .ok()
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .and_then(|bytes| VerifyingKey::from_bytes(&bytes).ok());
+    let (Some(key), Ok(signature)) = (key, Signature::from_slice(signature)) else {
+        return SignatureStatus::Invalid;
+    };
+
+    let mut message = Vec::with_capacity(magic.len() + manifest.len());
+    message.extend_from_slice(magic);
+    message.extend_from_slice(manifest);
+
+    if key.verify_strict(&message, &signature).is_ok() {
+        SignatureStatus::Verified
+    } else {
+        SignatureStatus::Invalid
+    }
+}
+
+/// 매니페스트의 라이선스 확인
+pub fn check_license(manifest: &BuildManifest) -> LicenseStatus {
+    match signed_terms(manifest, LICENSE_PUBLIC_KEY.is_some()) {
+        Ok(terms) if terms.has_restrictions() => evaluate_terms(
+            terms,
+            trusted_now(manifest.content_version),
+            current_machine_id().as_deref(),
+        ),
+        Ok(_) => LicenseStatus::Valid,
+        Err(status) => status,
+    }
+}
+
+/// 평가할 라이선스 조건 (조건을 보지 않고 결과가 정해지면 Err로 그 결과를 반환)
+/// 공개 키가 있으면 서명이 확인되고 라이선스가 기록된 빌드만 허용
+/// 공개 키가 없으면 제한 조건을 믿을 수 없으므로 제한이 있는 빌드는 거부
+fn signed_terms(manifest: &BuildManifest, licensing: bool) -> Result<&LicenseTerms, LicenseStatus> {
+    match (licensing, manifest.license.as_ref()) {
+        (true, Some(terms)) if manifest.signature == SignatureStatus::Verified => Ok(terms),
+        (true, _) => Err(LicenseStatus::Invalid),
+        (false, Some(terms)) if terms.has_restrictions() => Err(LicenseStatus::Invalid),
+        (false, _) => Err(LicenseStatus::Valid),
+    }
+}
+
+/// 라이선스 조건 평가
+fn evaluate_terms(terms: &LicenseTerms, now: u64, machine_id: Option<&str>) -> LicenseStatus {
+    if terms.not_before.is_some_and(|not_before| now < not_before) {
+        return LicenseStatus::NotYetValid;
+    }
+
+    if terms.not_after.is_some_and(|not_after| now >= not_after) {
+        return LicenseStatus::Expired;
+    }
+
+    if !terms.machine_ids.is_empty() {
+        let allowed = machine_id.is_some_and(|id| terms.machine_ids.iter().any(|m| m == id));
+        if !allowed {
+            return LicenseStatus::MachineNotAllowed;
+        }
+    }
+
+    LicenseStatus::Valid
+}
+
+/// 라이선스 확인에 쓸 현재 시각 (Unix ms)
+/// 시스템 시계, 빌드 시각, 마지막으로 확인한 시각 중 가장 늦은 값을 사용해
+/// PC 시계를 되돌려도 만료된 빌드가 다시 실행되지 않도록 함
+fn trusted_now(build_time: Option<u64>) -> u64 {
+    let path = app_data_dir().join(LICENSE_CLOCK_FILE);
+    let last_seen = fs::read_to_string(&path)
+        .ok()
+        .and_then(|text| text.trim().parse::<u64>().ok());
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    let trusted = [Some(now), build_time, last_seen]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or(now);
+
+    if last_seen != Some(trusted) {
+        if let Err(e) = fs::create_dir_all(app_data_dir())
+            .and_then(|_| fs::write(&path, trusted.to_string()))
+        {
+            log::warn!("Failed to record license clock: {}", e);
+        }
+    }
+
+    trusted
+}
+
+/// 현재 PC의 머신 ID (maker의 허용 목록과 같은 형식: 대문자)
+pub fn current_machine_id() -> Option<String> {
+    read_machine_id()
+        .map(|id| id.trim().to_uppercase())
+        .filter(|id| !id.is_empty())
+}
+
+/// 머신 ID 읽기 (Windows)
+#[cfg(target_os = "windows")]
+fn read_machine_id() -> Option<String> {
+    use std::os::windows::process::CommandExt;
+    use std::process::Command;
+
+    /// 콘솔 창을 띄우지 않고 실행
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    let output = Command::new("reg")
+        .args([
+            "query",
+            r"HKLM\SOFTWARE\Microsoft\Cryptography",
+            "/v",
+            "MachineGuid",
+        ])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    // "    MachineGuid    REG_SZ    xxxxxxxx-xxxx-..." 형식 파싱
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find(|line| line.contains("MachineGuid"))
+        .and_then(|line| line.split_whitespace().last())
+        .map(|id| id.to_string())
+}
+
+/// 머신 ID 읽기 (macOS)
+#[cfg(target_os = "macos")]
+fn read_machine_id() -> Option<String> {
+    use std::process::Command;
+
+    let output = Command::new("ioreg")
+        .args(["-rd1", "-c", "IOPlatformExpertDevice"])
+        .output()
+        .ok()?;
+
+    // "IOPlatformUUID" = "xxxxxxxx-xxxx-..." 형식 파싱
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find(|line| line.contains("IOPlatformUUID"))
+        .and_then(|line| line.split('"').nth(3))
+        .map(|id| id.to_string())
+}
+
+/// 머신 ID 읽기 (Linux 등)
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn read_machine_id() -> Option<String> {
+    ["/etc/machine-id", "/var/lib/dbus/machine-id"]
+        .iter()
+        .find_map(|path| std::fs::read_to_string(path).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    const MAGIC: &[u8] = b"VISTUT_V2";
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7; 32])
+    }
+
+    fn public_key() -> String {
+        BASE64.encode(signing_key().verifying_key().as_bytes())
+    }
+
+    fn sign(magic: &[u8], manifest: &[u8]) -> Vec<u8> {
+        let message = [magic, manifest].concat();
+        signing_key().sign(&message).to_bytes().to_vec()
+    }
+
+    fn manifest(license: Option<LicenseTerms>, signature: SignatureStatus) -> BuildManifest {
+        let json = r#"{"projectJsonOffset":0,"projectJsonSize":0,"media":[],"buttons":[]}"#;
+        BuildManifest {
+            license,
+            signature,
+            ..serde_json::from_str(json).unwrap()
+        }
+    }
+
+    fn expiring(not_after: u64) -> LicenseTerms {
+        LicenseTerms {
+            not_after: Some(not_after),
+            ..LicenseTerms::default()
+        }
+    }
+
+    #[test]
+    fn signature_covers_magic_and_manifest() {
+        let manifest = br#"{"license":{"notAfter":100}}"#;
+        let signature = sign(MAGIC, manifest);
+
+        assert_eq!(
+            verify_signature(&public_key(), MAGIC, manifest, &signature),
+            SignatureStatus::Verified
+        );
+        assert_eq!(
+            verify_signature(&public_key(), MAGIC, br#"{"license":{"notAfter":999}}"#, &signature),
+            SignatureStatus::Invalid
+        );
+        assert_eq!(
+            verify_signature(&public_key(), b"VISTUT_E2", manifest, &signature),
+            SignatureStatus::Invalid
+        );
+    }
+
+    #[test]
+    fn signature_from_other_key_is_invalid() {
+        let manifest = br#"{}"#;
+        let other = SigningKey::from_bytes(&[9; 32]);
+        let signature = other.sign(&[MAGIC, manifest].concat()).to_bytes();
+
+        assert_eq!(
+            verify_signature(&public_key(), MAGIC, manifest, &signature),
+            SignatureStatus::Invalid
+        );
+        assert_eq!(
+            verify_signature("not a key", MAGIC, manifest, &sign(MAGIC, manifest)),
+            SignatureStatus::Invalid
+        );
+    }
+
+    #[test]
+    fn licensing_requires_signed_license() {
+        let unsigned = manifest(Some(LicenseTerms::default()), SignatureStatus::Unsigned);
+        assert_eq!(signed_terms(&unsigned, true).unwrap_err(), LicenseStatus::Invalid);
+
+        let tampered = manifest(Some(LicenseTerms::default()), SignatureStatus::Invalid);
+        assert_eq!(signed_terms(&tampered, true).unwrap_err(), LicenseStatus::Invalid);
+
+        let missing = manifest(None, SignatureStatus::Verified);
+        assert_eq!(signed_terms(&missing, true).unwrap_err(), LicenseStatus::Invalid);
+
+        let signed = manifest(Some(expiring(100)), SignatureStatus::Verified);
+        assert_eq!(signed_terms(&signed, true).unwrap().not_after, Some(100));
+    }
+
+    #[test]
+    fn restrictions_without_public_key_are_rejected() {
+        let plain = manifest(None, SignatureStatus::Unsigned);
+        assert_eq!(signed_terms(&plain, false).unwrap_err(), LicenseStatus::Valid);
+
+        let restricted = manifest(Some(expiring(100)), SignatureStatus::Verified);
+        assert_eq!(signed_terms(&restricted, false).unwrap_err(), LicenseStatus::Invalid);
+    }
+
+    #[test]
+    fn terms_are_evaluated_against_given_time_and_machine() {
+        let terms = LicenseTerms {
+            not_before: Some(100),
+            not_after: Some(200),
+            machine_ids: vec!["PC-1".to_string()],
+        };
+
+        assert_eq!(evaluate_terms(&terms, 50, Some("PC-1")), LicenseStatus::NotYetValid);
+        assert_eq!(evaluate_terms(&terms, 200, Some("PC-1")), LicenseStatus::Expired);
+        assert_eq!(evaluate_terms(&terms, 150, Some("PC-2")), LicenseStatus::MachineNotAllowed);
+        assert_eq!(evaluate_terms(&terms, 150, None), LicenseStatus::MachineNotAllowed);
+        assert_eq!(evaluate_terms(&terms, 150, Some("PC-1")), LicenseStatus::Valid);
+    }
+}
@@ -0,0 +1,499 @@
+use crate::control::{ControlCommand, ControlDispatch, ControlEvent, ControlHub};
+use serde::Deserialize;
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// 받을 수 있는 OSC 패킷 최대 크기
+const MAX_PACKET_BYTES: usize = 8 * 1024;
+
+/// 프로젝트 설정의 OSC 연동 (settings.osc)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OscSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// 수신할 인터페이스 주소 (기본 127.0.0.1, 다른 PC의 쇼 컨트롤러는 0.0.0.0 등으로 지정)
+    #[serde(default = "default_bind")]
+    pub bind: String,
+    #[serde(default = "default_port")]
+    pub port: u16,
+    /// 주소 접두사 (기본 /tutorial, 예: /tutorial/goto)
+    #[serde(default = "default_prefix")]
+    pub prefix: String,
+    /// 페이지가 바뀌면 메시지를 보낼 대상 ("호스트:포트", 없으면 보내지 않음)
+    #[serde(default)]
+    pub send_to: Option<String>,
+    /// 메시지를 받을 송신 IP 목록 (비어 있으면 모든 주소에서 받음)
+    #[serde(default)]
+    pub allow_from: Vec<String>,
+}
+
+fn default_bind() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_port() -> u16 {
+    9000
+}
+
+fn default_prefix() -> String {
+    "/tutorial".to_string()
+}
+
+#[derive(Deserialize)]
+struct ProjectOsc {
+    settings: SettingsOsc,
+}
+
+#[derive(Deserialize)]
+struct SettingsOsc {
+    #[serde(default)]
+    osc: Option<OscSettings>,
+}
+
+impl OscSettings {
+    /// 프로젝트 JSON에서 OSC 설정 읽기 (꺼져 있으면 None)
+    pub fn from_project_json(project_json: &str) -> Result<Option<Self>, String> {
+        let project: ProjectOsc = serde_json::from_str(project_json)
+            .map_err(|e| format!("Failed to parse OSC settings: {}", e))?;
+        Ok(project.settings.osc.filter(|osc| osc.enabled))
+    }
+
+    fn address(&self) -> Result<SocketAddr, String> {
+        let ip: IpAddr = self
+            .bind
+            .trim()
+            .parse()
+            .map_err(|e| format!("Invalid OSC address '{}': {}", self.bind, e))?;
+        Ok(SocketAddr::new(ip, self.port))
+    }
+
+    fn target(&self) -> Result<Option<SocketAddr>, String> {
+        let target = self.send_to.as_deref().map(str::trim);
+        let Some(target) = target.filter(|target| !target.is_empty()) else {
+            return Ok(None);
+        };
+        target
+            .to_socket_addrs()
+            .map_err(|e| format!("Invalid OSC target '{}': {}", target, e))?
+            .next()
+            .map(Some)
+            .ok_or_else(|| format!("OSC target not resolved: {}", target))
+    }
+
+    fn prefix(&self) -> &str {
+        self.prefix.trim_end_matches('/')
+    }
+
+    fn allowed_senders(&self) -> Result<Vec<IpAddr>, String> {
+        self.allow_from
+            .iter()
+            .map(|sender| {
+                sender
+                    .trim()
+                    .parse()
+                    .map_err(|e| format!("Invalid OSC sender '{}': {}", sender, e))
+            })
+            .collect()
+    }
+}
+
+/// OSC 인자
+#[derive(Debug, Clone, PartialEq)]
+pub enum OscArg {
+    Int(i32),
+    Float(f32),
+    String(String),
+    Bool(bool),
+}
+
+/// OSC 메시지 (번들은 메시지 목록으로 풀어서 처리)
+#[derive(Debug, Clone, PartialEq)]
+pub struct OscMessage {
+    pub address: String,
+    pub args: Vec<OscArg>,
+}
+
+impl OscMessage {
+    /// OSC 1.0 바이너리로 인코딩
+    pub fn encode(&self) -> Vec<u8> {
+        let mut tags = String::from(",");
+        let mut args = Vec::new();
+        for arg in &self.args {
+            match arg {
+                OscArg::Int(value) => {
+                    tags.push('i');
+                    args.extend_from_slice(&value.to_be_bytes());
+                }
+                OscArg::Float(value) => {
+                    tags.push('f');
+                    args.extend_from_slice(&value.to_be_bytes());
+                }
+                OscArg::String(value) => {
+                    tags.push('s');
+                    write_padded_string(&mut args, value);
+                }
+                OscArg::Bool(value) => tags.push(if *value { 'T' } else { 'F' }),
+            }
+        }
+
+        let mut packet = Vec::new();
+        write_padded_string(&mut packet, &self.address);
+        write_padded_string(&mut packet, &tags);
+        packet.extend_from_slice(&args);
+        packet
+    }
+}
+
+/// OSC 문자열 (null 종료, 4바이트 단위로 채움)
+fn write_padded_string(buffer: &mut Vec<u8>, value: &str) {
+    buffer.extend_from_slice(value.as_bytes());
+    let padding = 4 - value.len() % 4;
+    buffer.resize(buffer.len() + padding, 0);
+}
+
+/// 패킷 읽기 위치
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let end = self.position.checked_add(length).filter(|end| *end <= self.data.len());
+        let end = end.ok_or("OSC packet truncated")?;
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn read_string(&mut self) -> Result<String, String> {
+        let rest = &self.data[self.position..];
+        let length =
+            rest.iter().position(|byte| *byte == 0).ok_or("OSC string not terminated")?;
+        let value = std::str::from_utf8(&rest[..length])
+            .map_err(|_| "OSC string is not UTF-8".to_string())?
+            .to_string();
+        self.take(length + 4 - length % 4)?;
+        Ok(value)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, String> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+/// OSC 패킷 해석 (메시지 또는 번들)
+pub fn decode_packet(data: &[u8]) -> Result<Vec<OscMessage>, String> {
+    if data.starts_with(b"#bundle\0") {
+        // "#bundle" 뒤의 8바이트 타임태그는 무시하고 바로 실행
+        if data.len() < 16 {
+            return Err("OSC bundle truncated".to_string());
+        }
+        let mut reader = Reader { data, position: 16 };
+        let mut messages = Vec::new();
+        while reader.position < data.len() {
+            let size = reader.read_u32()? as usize;
+            messages.extend(decode_packet(reader.take(size)?)?);
+        }
+        return Ok(messages);
+    }
+
+    let mut reader = Reader { data, position: 0 };
+    let address = reader.read_string()?;
+    if !address.starts_with('/') {
+        return Err(format!("Invalid OSC address: {}", address));
+    }
+    // 타입 태그가 없는 오래된 형식은 인자 없음으로 처리
+    if reader.position >= data.len() {
+        return Ok(vec![OscMessage { address, args: Vec::new() }]);
+    }
+
+    let tags = reader.read_string()?;
+    let mut args = Vec::new();
+    for tag in tags.chars().skip_while(|tag| *tag == ',') {
+        let arg = match tag {
+            'i' => OscArg::Int(reader.read_u32()? as i32),
+            'f' => OscArg::Float(f32::from_bits(reader.read_u32()?)),
+            's' | 'S' => OscArg::String(reader.read_string()?),
+            'T' => OscArg::Bool(true),
+            'F' => OscArg::Bool(false),
+            'N' | 'I' => continue,
+            other => return Err(format!("Unsupported OSC argument type: {}", other)),
+        };
+        args.push(arg);
+    }
+    Ok(vec![OscMessage { address, args }])
+}
+
+/// 받은 메시지를 제어 명령으로 변환 (접두사가 다르거나 모르는 주소면 None)
+///
+/// OSC는 인증이 없으므로 페이지 이동만 받고, 콘텐츠 다시 로드/되돌리기는 토큰이 있는
+/// 원격 제어 API로만 받음
+pub fn command_from_message(prefix: &str, message: &OscMessage) -> Option<ControlCommand> {
+    let action = message.address.strip_prefix(prefix)?.strip_prefix('/')?;
+    match action {
+        "goto" => {
+            let page_id = match message.args.first()? {
+                OscArg::String(value) => value.clone(),
+                OscArg::Int(value) => value.to_string(),
+                OscArg::Float(value) => (*value as i32).to_string(),
+                OscArg::Bool(_) => return None,
+            };
+            Some(ControlCommand::Goto { page_id })
+        }
+        "next" => Some(ControlCommand::Next),
+        "home" => Some(ControlCommand::Home),
+        _ => None,
+    }
+}
+
+/// 페이지 변경을 보낼 메시지 ({prefix}/page 번호 페이지ID)
+fn message_from_event(prefix: &str, event: &ControlEvent) -> OscMessage {
+    match event {
+        ControlEvent::PageChange {
+            page_index,
+            page_id,
+            ..
+        } => OscMessage {
+            address: format!("{}/page", prefix),
+            args: vec![
+                OscArg::Int(*page_index as i32),
+                OscArg::String(page_id.clone().unwrap_or_default()),
+            ],
+        },
+    }
+}
+
+/// OSC 수신/송신 (한 번만 시작)
+#[derive(Default)]
+pub struct OscBridge {
+    started: AtomicBool,
+}
+
+impl OscBridge {
+    /// UDP 수신을 시작하고, 대상이 있으면 페이지 변경을 전송
+    pub fn start(
+        &self,
+        hub: &Arc<ControlHub>,
+        settings: &OscSettings,
+        dispatch: ControlDispatch,
+    ) -> Result<(), String> {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let sockets = settings.address().and_then(|address| {
+            let socket = UdpSocket::bind(address)
+                .map_err(|e| format!("Failed to bind OSC on {}: {}", address, e))?;
+            let sender =
+                socket.try_clone().map_err(|e| format!("Failed to clone OSC socket: {}", e))?;
+            Ok((socket, sender, settings.target()?, settings.allowed_senders()?))
+        });
+        let (socket, sender, target, allowed) = match sockets {
+            Ok(sockets) => sockets,
+            Err(e) => {
+                self.started.store(false, Ordering::SeqCst);
+                return Err(e);
+            }
+        };
+        log::info!("OSC listening on {}:{}", settings.bind, settings.port);
+
+        let prefix = settings.prefix().to_string();
+        if let Some(target) = target {
+            let events = hub.subscribe();
+            let prefix = prefix.clone();
+            std::thread::spawn(move || {
+                for event in events {
+                    let packet = message_from_event(&prefix, &event).encode();
+                    if let Err(e) = sender.send_to(&packet, target) {
+                        log::warn!("Failed to send OSC message to {}: {}", target, e);
+                    }
+                }
+            });
+        }
+
+        let hub = hub.clone();
+        std::thread::spawn(move || {
+            let mut buffer = [0u8; MAX_PACKET_BYTES];
+            loop {
+                let (size, from) = match socket.recv_from(&mut buffer) {
+                    Ok(received) => received,
+                    Err(e) => {
+                        log::warn!("OSC receive failed: {}", e);
+                        continue;
+                    }
+                };
+                if !allowed.is_empty() && !allowed.contains(&from.ip()) {
+                    log::debug!("Ignored OSC packet from {}", from);
+                    continue;
+                }
+
+                let messages = match decode_packet(&buffer[..size]) {
+                    Ok(messages) => messages,
+                    Err(e) => {
+                        log::warn!("Invalid OSC packet from {}: {}", from, e);
+                        continue;
+                    }
+                };
+                for message in messages {
+                    match command_from_message(&prefix, &message) {
+                        Some(command) => hub.execute(command, &dispatch),
+                        None => log::debug!("Ignored OSC message: {}", message.address),
+                    }
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn message(address: &str, args: Vec<OscArg>) -> OscMessage {
+        OscMessage {
+            address: address.to_string(),
+            args,
+        }
+    }
+
+    fn bundle(messages: &[OscMessage]) -> Vec<u8> {
+        let mut packet = b"#bundle\0".to_vec();
+        packet.extend_from_slice(&1u64.to_be_bytes());
+        for message in messages {
+            let encoded = message.encode();
+            packet.extend_from_slice(&(encoded.len() as u32).to_be_bytes());
+            packet.extend_from_slice(&encoded);
+        }
+        packet
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let original = message(
+            "/tutorial/page",
+            vec![
+                OscArg::Int(-3),
+                OscArg::Float(1.5),
+                OscArg::String("intro".to_string()),
+                OscArg::String("abcd".to_string()),
+                OscArg::Bool(true),
+                OscArg::Bool(false),
+            ],
+        );
+        let packet = original.encode();
+        assert_eq!(packet.len() % 4, 0);
+        assert_eq!(decode_packet(&packet).unwrap(), vec![original]);
+    }
+
+    #[test]
+    fn decodes_bundles_in_order() {
+        let first = message("/tutorial/next", Vec::new());
+        let second = message("/tutorial/goto", vec![OscArg::Int(2)]);
+        let packet = bundle(&[first.clone(), second.clone()]);
+        assert_eq!(decode_packet(&packet).unwrap(), vec![first, second]);
+
+        // 번들 안의 번들도 풀어서 처리
+        let nested = bundle(&[message("/tutorial/home", Vec::new())]);
+        let mut outer = b"#bundle\0".to_vec();
+        outer.extend_from_slice(&0u64.to_be_bytes());
+        outer.extend_from_slice(&(nested.len() as u32).to_be_bytes());
+        outer.extend_from_slice(&nested);
+        assert_eq!(
+            decode_packet(&outer).unwrap(),
+            vec![message("/tutorial/home", Vec::new())]
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_packets() {
+        let packet = message(
+            "/tutorial/goto",
+            vec![OscArg::String("intro".to_string()), OscArg::Int(1)],
+        )
+        .encode();
+        for length in [1, 8, packet.len() - 4, packet.len() - 1] {
+            assert!(decode_packet(&packet[..length]).is_err(), "length {}", length);
+        }
+
+        let bundled = bundle(&[message("/tutorial/next", Vec::new())]);
+        assert!(decode_packet(&bundled[..12]).is_err());
+        assert!(decode_packet(&bundled[..bundled.len() - 2]).is_err());
+        assert!(decode_packet(b"tutorial\0\0\0\0").is_err());
+    }
+
+    #[test]
+    fn maps_navigation_only() {
+        let command = |address: &str, args: Vec<OscArg>| {
+            command_from_message("/tutorial", &message(address, args))
+        };
+        assert_eq!(
+            command("/tutorial/goto", vec![OscArg::String("intro".to_string())]),
+            Some(ControlCommand::Goto {
+                page_id: "intro".to_string()
+            })
+        );
+        assert_eq!(
+            command("/tutorial/goto", vec![OscArg::Float(2.0)]),
+            Some(ControlCommand::Goto {
+                page_id: "2".to_string()
+            })
+        );
+        assert_eq!(command("/tutorial/goto", Vec::new()), None);
+        for address in ["/tutorial/reload", "/tutorial/rollback", "/tutorial/mute", "/other/next"] {
+            assert_eq!(command(address, Vec::new()), None, "{}", address);
+        }
+    }
+
+    #[test]
+    fn loopback_sender_drives_commands() {
+        let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+        receiver.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let target = receiver.local_addr().unwrap();
+
+        let sent = [
+            message("/tutorial/goto", vec![OscArg::String("intro".to_string())]),
+            message("/tutorial/next", Vec::new()),
+            message("/tutorial/home", Vec::new()),
+        ];
+        for message in &sent {
+            sender.send_to(&message.encode(), target).unwrap();
+        }
+
+        let mut buffer = [0u8; MAX_PACKET_BYTES];
+        let mut commands = Vec::new();
+        for _ in 0..sent.len() {
+            let (size, from) = receiver.recv_from(&mut buffer).unwrap();
+            assert_eq!(from, sender.local_addr().unwrap());
+            for message in decode_packet(&buffer[..size]).unwrap() {
+                commands.extend(command_from_message("/tutorial", &message));
+            }
+        }
+        assert_eq!(
+            commands,
+            vec![
+                ControlCommand::Goto {
+                    page_id: "intro".to_string()
+                },
+                ControlCommand::Next,
+                ControlCommand::Home,
+            ]
+        );
+    }
+
+    #[test]
+    fn defaults_to_loopback() {
+        let settings =
+            OscSettings::from_project_json(r#"{"settings":{"osc":{"enabled":true}}}"#)
+                .unwrap()
+                .unwrap();
+        assert!(settings.address().unwrap().ip().is_loopback());
+        assert!(settings.allowed_senders().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,224 @@
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+
+/// 기본 프리페치 메모리 예산 (64MB)
+/// 키오스크 PC는 4GB RAM에서 웹뷰의 영상 디코딩과 메모리를 나눠 쓰므로 작게 잡음
+/// 예산을 넘는 영상은 캐시 대신 OS 파일 캐시만 데우므로 이미지/버튼 몇 페이지 분량이면 충분
+/// (메모리가 넉넉한 장비는 VISTUT_PREFETCH_MB로 늘릴 수 있음)
+pub const DEFAULT_PREFETCH_BUDGET_BYTES: u64 = 64 * 1024 * 1024;
+
+/// 프로젝트 JSON 중 페이지 이동 경로 계산에 필요한 부분
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NavigationGraph {
+    pages: Vec<PageNavigation>,
+    #[serde(default)]
+    settings: NavigationSettings,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NavigationSettings {
+    #[serde(default)]
+    loop_at_end: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageNavigation {
+    media_id: String,
+    #[serde(default)]
+    buttons: Vec<NavigationTrigger>,
+    #[serde(default)]
+    touch_areas: Vec<NavigationTrigger>,
+}
+
+/// 버튼/터치 영역
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NavigationTrigger {
+    /// 버튼 이미지 ID (터치 영역은 없음)
+    #[serde(default)]
+    image_id: Option<String>,
+    action: NavigationAction,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NavigationAction {
+    #[serde(rename = "type")]
+    kind: String,
+    /// 이동할 페이지 인덱스 (문자열)
+    #[serde(default)]
+    target_page_id: Option<String>,
+}
+
+impl NavigationGraph {
+    pub fn parse(project_json: &str) -> Result<Self, String> {
+        serde_json::from_str(project_json)
+            .map_err(|e| format!("Failed to parse project navigation: {}", e))
+    }
+
+    /// 현재 페이지에서 바로 이동할 수 있는 페이지 인덱스
+    /// 프론트엔드 usePageNavigation의 connectedPages와 같은 규칙 (다음 페이지 + goto 대상)
+    pub fn reachable_pages(&self, page_index: usize) -> Vec<usize> {
+        let mut reachable = Vec::new();
+        let Some(page) = self.pages.get(page_index) else {
+            return reachable;
+        };
+
+        let next_index = page_index + 1;
+        if next_index < self.pages.len() {
+            reachable.push(next_index);
+        } else if self.settings.loop_at_end {
+            reachable.push(0);
+        }
+
+        for trigger in page.buttons.iter().chain(page.touch_areas.iter()) {
+            let target = match (trigger.action.kind.as_str(), &trigger.action.target_page_id) {
+                ("goto", Some(target)) => target.parse::<usize>().ok(),
+                _ => None,
+            };
+            if let Some(target) = target {
+                if target < self.pages.len() && !reachable.contains(&target) {
+                    reachable.push(target);
+                }
+            }
+        }
+
+        reachable.retain(|&index| index != page_index);
+        reachable
+    }
+
+    /// 페이지를 표시하는 데 필요한 미디어/버튼 이미지 ID (영상이 먼저)
+    pub fn page_media_ids(&self, page_index: usize) -> Vec<String> {
+        let Some(page) = self.pages.get(page_index) else {
+            return Vec::new();
+        };
+
+        std::iter::once(page.media_id.clone())
+            .chain(page.buttons.iter().filter_map(|button| button.image_id.clone()))
+            .collect()
+    }
+}
+
+/// 프리페치한 미디어를 보관하는 LRU 캐시
+/// 예산을 넘으면 가장 오래 사용하지 않은 항목부터 제거
+pub struct PrefetchCache {
+    budget: u64,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    /// 오래된 순서 (앞쪽이 가장 오래 사용하지 않은 항목)
+    entries: VecDeque<(String, Arc<Vec<u8>>)>,
+    used: u64,
+}
+
+impl PrefetchCache {
+    pub fn new(budget: u64) -> Self {
+        Self {
+            budget,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// 예산 안에 들어가는 크기인지 확인
+    pub fn fits(&self, size: u64) -> bool {
+        size <= self.budget
+    }
+
+    /// 캐시된 데이터 가져오기 (가장 최근 사용으로 이동)
+    pub fn get(&self, id: &str) -> Option<Arc<Vec<u8>>> {
+        let mut state = self.state.lock().ok()?;
+        let position = state.entries.iter().position(|(key, _)| key == id)?;
+        let entry = state.entries.remove(position)?;
+        let data = entry.1.clone();
+        state.entries.push_back(entry);
+        Some(data)
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.state
+            .lock()
+            .map(|state| state.entries.iter().any(|(key, _)| key == id))
+            .unwrap_or(false)
+    }
+
+    /// 데이터 추가 (예산을 넘으면 오래된 항목 제거)
+    pub fn insert(&self, id: &str, data: Vec<u8>) {
+        let size = data.len() as u64;
+        if !self.fits(size) {
+            return;
+        }
+
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+
+        if let Some(position) = state.entries.iter().position(|(key, _)| key == id) {
+            if let Some((_, old)) = state.entries.remove(position) {
+                state.used -= old.len() as u64;
+            }
+        }
+
+        while state.used + size > self.budget {
+            match state.entries.pop_front() {
+                Some((_, evicted)) => state.used -= evicted.len() as u64,
+                None => break,
+            }
+        }
+
+        state.used += size;
+        state.entries.push_back((id.to_string(), Arc::new(data)));
+    }
+}
+
+/// 프리페치 작업 스레드에 전달하는 요청
+/// 처리 전에 새 요청이 오면 이전 요청은 버리고 가장 최근 페이지만 처리
+#[derive(Default)]
+pub struct PrefetchQueue {
+    state: Mutex<QueueState>,
+    wake: Condvar,
+}
+
+#[derive(Default)]
+struct QueueState {
+    /// (페이지 인덱스, 세대)
+    pending: Option<(usize, u64)>,
+    closed: bool,
+}
+
+impl PrefetchQueue {
+    /// 요청 등록 (처리 전 요청은 덮어씀)
+    pub fn push(&self, page_index: usize, generation: u64) {
+        if let Ok(mut state) = self.state.lock() {
+            state.pending = Some((page_index, generation));
+            self.wake.notify_one();
+        }
+    }
+
+    /// 다음 요청을 기다림 (닫히면 None)
+    pub fn next(&self) -> Option<(usize, u64)> {
+        let mut state = self.state.lock().ok()?;
+        loop {
+            if state.closed {
+                return None;
+            }
+            if let Some(request) = state.pending.take() {
+                return Some(request);
+            }
+            state = self.wake.wait(state).ok()?;
+        }
+    }
+
+    /// 작업 스레드 종료 요청
+    pub fn close(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.closed = true;
+            self.wake.notify_all();
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::content::EmbeddedContent;
+use tauri::http::{header, Request, Response, StatusCode};
+
+/// 임베딩 미디어를 제공하는 URI 스킴
+/// 프론트엔드는 convertFileSrc(id, 'embedded')로 URL을 만들고, 웹뷰가 표시할 때 필요한 구간만 요청함
+pub const MEDIA_PROTOCOL: &str = "embedded";
+
+/// 구간 요청 한 번에 보낼 최대 크기 (열린 구간 "bytes=0-"은 잘라서 보내고 웹뷰가 이어서 요청)
+const MAX_RANGE_BYTES: u64 = 4 * 1024 * 1024;
+
+/// Range 헤더 없이 한 번에 보낼 최대 크기 (이미지용, 더 크면 첫 구간만 보냄)
+const MAX_FULL_RESPONSE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// 미디어 요청 처리 (경로는 미디어/버튼 이미지 ID, Range 헤더가 있으면 해당 구간만 복호화)
+pub fn media_response(content: &EmbeddedContent, request: &Request<Vec<u8>>) -> Response<Vec<u8>> {
+    let id = percent_decode(request.uri().path().trim_start_matches('/'));
+    let (mime_type, total) = match content.entry(&id) {
+        Ok(entry) => (
+            entry.mime_type.clone(),
+            content.media_size(&id).unwrap_or_default(),
+        ),
+        Err(e) => return error_response(StatusCode::NOT_FOUND, &e),
+    };
+
+    let range = request
+        .headers()
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok());
+    let (start, end, partial) = match range {
+        Some(range) => match parse_range(range, total) {
+            Some((start, end)) => (start, end.min(start + MAX_RANGE_BYTES - 1), true),
+            None => {
+                return Response::builder()
+                    .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                    .header(header::CONTENT_RANGE, format!("bytes */{}", total))
+                    .body(Vec::new())
+                    .unwrap_or_default();
+            }
+        },
+        None if total <= MAX_FULL_RESPONSE_BYTES => (0, total.saturating_sub(1), false),
+        None => (0, MAX_RANGE_BYTES - 1, true),
+    };
+
+    let data = if total == 0 {
+        Vec::new()
+    } else {
+        match content.read_media_range(&id, start, end - start + 1) {
+            Ok((data, _)) if data.len() as u64 == end - start + 1 => data,
+            Ok(_) => {
+                log::warn!("Media {} is truncated at {}", id, start);
+                return error_response(StatusCode::INTERNAL_SERVER_ERROR, "Media is truncated");
+            }
+            Err(e) => {
+                log::warn!("Failed to serve media {}: {}", id, e);
+                return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e);
+            }
+        }
+    };
+
+    let mut response = Response::builder()
+        .header(header::CONTENT_TYPE, mime_type)
+        .header(header::ACCEPT_RANGES, "bytes")
+        .header(header::CONTENT_LENGTH, data.len())
+        // 콘텐츠 교체 후 이전 데이터가 남지 않도록 웹뷰 캐시 대신 프리페치 캐시만 사용
+        .header(header::CACHE_CONTROL, "no-store");
+    if partial {
+        response = response.status(StatusCode::PARTIAL_CONTENT).header(
+            header::CONTENT_RANGE,
+            format!("bytes {}-{}/{}", start, end, total),
+        );
+    }
+    response.body(data).unwrap_or_default()
+}
+
+pub fn error_response(status: StatusCode, message: &str) -> Response<Vec<u8>> {
+    Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, "text/plain; charset=utf-8")
+        .body(message.as_bytes().to_vec())
+        .unwrap_or_default()
+}
+
+/// "bytes=시작-끝" 형식의 첫 구간을 [시작, 끝]으로 변환 (끝 생략, 뒤에서부터 지정 지원)
+fn parse_range(value: &str, total: u64) -> Option<(u64, u64)> {
+    let spec = value
+        .trim()
+        .strip_prefix("bytes=")?
+        .split(',')
+        .next()?
+        .trim();
+    let (start, end) = spec.split_once('-')?;
+    let (start, end) = match (start.trim(), end.trim()) {
+        ("", suffix) => {
+            let suffix = suffix.parse::<u64>().ok()?.min(total);
+            (total - suffix, total.checked_sub(1)?)
+        }
+        (start, "") => (start.parse().ok()?, total.checked_sub(1)?),
+        (start, end) => (
+            start.parse().ok()?,
+            end.parse::<u64>().ok()?.min(total.checked_sub(1)?),
+        ),
+    };
+    (start <= end && start < total).then_some((start, end))
+}
+
+/// URL 경로의 퍼센트 인코딩 해제 (잘못된 인코딩은 그대로 둠)
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let hex = bytes
+            .get(index + 1..index + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[index], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
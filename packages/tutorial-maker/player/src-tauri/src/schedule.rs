@@ -0,0 +1,122 @@
+use crate::analytics::parse_date;
+use crate::embedded::ProjectManifestEntry;
+use serde::{Deserialize, Serialize};
+
+const MS_PER_MINUTE: i64 = 60 * 1000;
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+/// 프로젝트를 자동으로 재생할 시간 조건 (플레이어 PC의 현지 시각 기준)
+/// 지정하지 않은 항목은 제한하지 않음
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleRule {
+    /// 요일 (0 = 일요일 ~ 6 = 토요일)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub weekdays: Vec<u8>,
+    /// 시작/종료 시각 ("HH:MM", 종료가 시작보다 이르면 자정을 넘김)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<String>,
+    /// 시작/종료 날짜 ("YYYY-MM-DD", 종료일 포함)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+}
+
+/// 현지 시각 (1970-01-01부터의 일 수, 요일, 자정 이후 분)
+struct LocalTime {
+    day: i64,
+    weekday: u8,
+    minute: i64,
+}
+
+impl LocalTime {
+    fn new(timestamp_ms: i64, utc_offset_minutes: i32) -> Self {
+        let local_minutes =
+            timestamp_ms.div_euclid(MS_PER_MINUTE) + i64::from(utc_offset_minutes);
+        let day = local_minutes.div_euclid(MINUTES_PER_DAY);
+        Self {
+            day,
+            // 1970-01-01은 목요일
+            weekday: (day + 4).rem_euclid(7) as u8,
+            minute: local_minutes.rem_euclid(MINUTES_PER_DAY),
+        }
+    }
+}
+
+impl ScheduleRule {
+    /// 날짜 범위가 있는 조건 (휴일 등 특정 기간용, 요일/시간 조건보다 우선)
+    fn has_date_range(&self) -> bool {
+        self.start_date.is_some() || self.end_date.is_some()
+    }
+
+    /// 조건이 맞는지 (형식이 잘못된 조건은 맞지 않는 것으로 처리)
+    fn matches(&self, now: &LocalTime) -> bool {
+        if !self.weekdays.is_empty() && !self.weekdays.contains(&now.weekday) {
+            return false;
+        }
+
+        let after_start = within_date(&self.start_date, |start| now.day >= start);
+        let before_end = within_date(&self.end_date, |end| now.day <= end);
+        if !(after_start && before_end) {
+            return false;
+        }
+
+        let start = self.start_time.as_deref().map(parse_time);
+        let end = self.end_time.as_deref().map(parse_time);
+        match (start, end) {
+            (Some(None), _) | (_, Some(None)) => false,
+            (None, None) => true,
+            (Some(Some(start)), None) => now.minute >= start,
+            (None, Some(Some(end))) => now.minute < end,
+            (Some(Some(start)), Some(Some(end))) if start <= end => {
+                now.minute >= start && now.minute < end
+            }
+            // 자정을 넘기는 시간대 (예: 22:00 ~ 02:00)
+            (Some(Some(start)), Some(Some(end))) => now.minute >= start || now.minute < end,
+        }
+    }
+}
+
+/// 날짜 조건 확인 (없으면 통과, 형식이 잘못되면 실패)
+fn within_date(date: &Option<String>, check: impl FnOnce(i64) -> bool) -> bool {
+    match date {
+        Some(date) => parse_date(date).is_some_and(check),
+        None => true,
+    }
+}
+
+/// "HH:MM"을 자정 이후 분으로 변환
+fn parse_time(time: &str) -> Option<i64> {
+    let (hour, minute) = time.trim().split_once(':')?;
+    let hour: i64 = hour.parse().ok()?;
+    let minute: i64 = minute.parse().ok()?;
+    ((0..24).contains(&hour) && (0..60).contains(&minute)).then_some(hour * 60 + minute)
+}
+
+/// 지금 재생할 프로젝트 (맞는 일정이 없으면 None)
+/// 날짜 범위가 있는 조건이 먼저, 같으면 매니페스트 순서가 앞선 프로젝트가 우선
+pub fn scheduled_project(
+    projects: &[ProjectManifestEntry],
+    timestamp_ms: i64,
+    utc_offset_minutes: i32,
+) -> Option<&ProjectManifestEntry> {
+    let now = LocalTime::new(timestamp_ms, utc_offset_minutes);
+
+    let mut best: Option<(&ProjectManifestEntry, bool)> = None;
+    for project in projects {
+        for rule in project.schedule.iter().filter(|rule| rule.matches(&now)) {
+            let dated = rule.has_date_range();
+            let better = match best {
+                Some((_, best_dated)) => dated && !best_dated,
+                None => true,
+            };
+            if better {
+                best = Some((project, dated));
+            }
+        }
+    }
+    best.map(|(project, _)| project)
+}
@@ -0,0 +1,528 @@
+use crate::analytics::now_ms;
+use crate::crypto::ContentKey;
+use crate::embedded::{
+    get_current_exe_path, locate_data_source, open_manifest, payload_digest, read_trailer,
+    BuildManifest, DataSource, Trailer,
+};
+use crate::license::{check_license, LicenseStatus, SignatureStatus};
+use crate::watchdog::app_data_dir;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, UNIX_EPOCH};
+
+/// 새 콘텐츠를 받아 적용할 준비가 되면 프론트엔드에 보내는 이벤트 (콘텐츠 버전 전달)
+pub const CONTENT_UPDATE_EVENT: &str = "content-update-ready";
+
+/// 감시할 폴더를 지정하는 환경 변수 (프로젝트 설정보다 우선)
+const UPDATE_DIR_ENV: &str = "VISTUT_UPDATE_DIR";
+
+/// 확인 주기 최솟값 (네트워크 공유 폴더 부하 방지)
+const MIN_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// 설치한 콘텐츠 파일 이름 (<앱 데이터>/content/<exe 이름>/)
+const CURRENT_FILE: &str = "current.pak";
+const PREVIOUS_FILE: &str = "previous.pak";
+const INCOMING_FILE: &str = "incoming.pak";
+const PARTIAL_FILE: &str = "incoming.partial";
+const STATE_FILE: &str = "update.json";
+
+/// 복사 버퍼 크기
+const COPY_BUFFER_SIZE: usize = 1024 * 1024;
+
+/// 프로젝트 설정의 핫 폴더 업데이트 (settings.hotFolder)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HotFolderSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// 감시할 폴더 (로컬 또는 연결된 네트워크 공유 폴더)
+    #[serde(default)]
+    pub path: String,
+    #[serde(default = "default_check_interval")]
+    pub check_interval_seconds: u64,
+}
+
+fn default_check_interval() -> u64 {
+    60
+}
+
+#[derive(Deserialize)]
+struct ProjectHotFolder {
+    settings: SettingsHotFolder,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SettingsHotFolder {
+    #[serde(default)]
+    hot_folder: Option<HotFolderSettings>,
+}
+
+impl HotFolderSettings {
+    /// 프로젝트 JSON에서 핫 폴더 설정 읽기 (꺼져 있거나 폴더가 없으면 None)
+    /// 환경 변수로 폴더를 지정하면 프로젝트 설정과 관계없이 사용
+    pub fn from_project_json(project_json: &str) -> Result<Option<Self>, String> {
+        let project: ProjectHotFolder = serde_json::from_str(project_json)
+            .map_err(|e| format!("Failed to parse hot folder settings: {}", e))?;
+        let settings = project.settings.hot_folder;
+
+        if let Some(path) = std::env::var(UPDATE_DIR_ENV).ok().filter(|path| !path.is_empty()) {
+            return Ok(Some(Self {
+                enabled: true,
+                path,
+                check_interval_seconds: settings
+                    .map(|settings| settings.check_interval_seconds)
+                    .unwrap_or_else(default_check_interval),
+            }));
+        }
+        Ok(settings.filter(|settings| settings.enabled && !settings.path.trim().is_empty()))
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(self.check_interval_seconds).max(MIN_CHECK_INTERVAL)
+    }
+}
+
+/// 롤백 후 다시 설치하지 않을 버전 (update.json)
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateState {
+    #[serde(default)]
+    skipped_version: Option<u64>,
+}
+
+/// 프론트엔드에 알려주는 업데이트 상태
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentUpdateStatus {
+    pub enabled: bool,
+    /// 재생 중인 콘텐츠 버전
+    pub current_version: Option<u64>,
+    /// 받아 두고 다음 대기 화면 복귀 때 적용할 버전
+    pub pending_version: Option<u64>,
+    /// 롤백하면 되돌아갈 버전 (설치한 이전 콘텐츠가 없으면 None)
+    pub previous_version: Option<u64>,
+    pub last_check_ms: Option<u64>,
+    pub last_error: Option<String>,
+}
+
+/// 업데이트 적용 후 새 콘텐츠를 여는 데 필요한 키
+pub struct InstalledUpdate {
+    pub version: Option<u64>,
+    pub key: Option<ContentKey>,
+}
+
+#[derive(Default)]
+struct UpdaterState {
+    pending_version: Option<u64>,
+    last_check_ms: Option<u64>,
+    last_error: Option<String>,
+    /// 검증에 실패한 후보 (같은 파일을 반복해서 복사하지 않도록 기억)
+    rejected: Option<String>,
+}
+
+/// 새 버전을 알리는 함수 (프론트엔드 이벤트 전송)
+pub type UpdateNotify = Arc<dyn Fn(u64) + Send + Sync>;
+
+/// 핫 폴더 감시와 설치한 콘텐츠 교체 (감시는 한 번만 시작)
+#[derive(Default)]
+pub struct ContentUpdater {
+    started: AtomicBool,
+    state: Mutex<UpdaterState>,
+}
+
+impl ContentUpdater {
+    /// 폴더 감시 시작
+    /// 지난 실행에서 받아 두고 적용하지 못한 콘텐츠가 있으면 바로 알림
+    /// 암호 모드 빌드는 실행 시 전달된 암호로만 검증할 수 있음
+    pub fn start(
+        self: &Arc<Self>,
+        settings: HotFolderSettings,
+        passphrase: Option<String>,
+        notify: UpdateNotify,
+    ) {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        log::info!("Watching hot folder for content updates: {}", settings.path);
+
+        let updater = self.clone();
+        std::thread::spawn(move || {
+            if let Some(version) = updater.restore_pending() {
+                notify(version);
+            }
+
+            let folder = PathBuf::from(settings.path.trim());
+            loop {
+                match updater.check(&folder, passphrase.as_deref()) {
+                    Ok(Some(version)) => {
+                        log::info!("Content update {} ready", version);
+                        notify(version);
+                    }
+                    Ok(None) => {}
+                    Err(e) => log::warn!("Content update check failed: {}", e),
+                }
+                std::thread::sleep(settings.interval());
+            }
+        });
+    }
+
+    /// 현재 상태
+    pub fn status(&self) -> ContentUpdateStatus {
+        let previous = install_dir().ok().map(|dir| dir.join(PREVIOUS_FILE));
+        let state = self.state.lock();
+        let state = state.as_deref().ok();
+        ContentUpdateStatus {
+            enabled: self.started.load(Ordering::SeqCst),
+            current_version: current_version().ok().flatten(),
+            pending_version: state.and_then(|state| state.pending_version),
+            previous_version: previous.and_then(|path| file_version(&path)),
+            last_check_ms: state.and_then(|state| state.last_check_ms),
+            last_error: state.and_then(|state| state.last_error.clone()),
+        }
+    }
+
+    /// 받아 둔 콘텐츠를 현재 콘텐츠로 교체 (현재 콘텐츠는 이전 콘텐츠로 보관)
+    /// rename만 하므로 중간에 실패해도 어느 한쪽의 온전한 파일이 남음
+    pub fn install_pending(&self, passphrase: Option<&str>) -> Result<InstalledUpdate, String> {
+        let dir = install_dir()?;
+        let incoming = dir.join(INCOMING_FILE);
+        if !incoming.is_file() {
+            return Err("No content update pending".to_string());
+        }
+
+        let trailer = read_trailer(&DataSource::file(&incoming))?;
+        let key = resolve_key(&trailer, passphrase)?;
+
+        let current = dir.join(CURRENT_FILE);
+        let previous = dir.join(PREVIOUS_FILE);
+        if current.is_file() {
+            let _ = fs::remove_file(&previous);
+            fs::rename(&current, &previous)
+                .map_err(|e| format!("Failed to keep previous content: {}", e))?;
+        }
+        fs::rename(&incoming, &current)
+            .map_err(|e| format!("Failed to install content update: {}", e))?;
+
+        if let Ok(mut state) = self.state.lock() {
+            state.pending_version = None;
+        }
+        let version = trailer.content_version();
+        log::info!("Content update installed: {:?}", version);
+        Ok(InstalledUpdate { version, key })
+    }
+
+    /// 설치한 현재 콘텐츠를 버리고 이전 콘텐츠로 되돌림
+    /// 이전에 설치한 콘텐츠가 없으면 exe에 포함된 콘텐츠로 돌아감
+    /// 되돌린 버전은 핫 폴더에 남아 있어도 다시 설치하지 않음
+    pub fn rollback(&self) -> Result<Option<u64>, String> {
+        let dir = install_dir()?;
+        let current = dir.join(CURRENT_FILE);
+        let previous = dir.join(PREVIOUS_FILE);
+        if !current.is_file() {
+            return Err("No installed content to roll back".to_string());
+        }
+
+        if let Some(version) = file_version(&current) {
+            write_state(&dir, &UpdateState { skipped_version: Some(version) })?;
+        }
+        fs::remove_file(&current)
+            .map_err(|e| format!("Failed to remove installed content: {}", e))?;
+        if previous.is_file() {
+            fs::rename(&previous, &current)
+                .map_err(|e| format!("Failed to restore previous content: {}", e))?;
+        }
+
+        let restored = current_version().ok().flatten();
+        log::info!("Content rolled back to {:?}", restored);
+        Ok(restored)
+    }
+
+    /// 지난 실행에서 받아 둔 콘텐츠 (현재보다 새 버전일 때만)
+    fn restore_pending(&self) -> Option<u64> {
+        let incoming = install_dir().ok()?.join(INCOMING_FILE);
+        let version = file_version(&incoming)?;
+        if current_version().ok().flatten().is_some_and(|current| version <= current) {
+            let _ = fs::remove_file(&incoming);
+            return None;
+        }
+        if let Ok(mut state) = self.state.lock() {
+            state.pending_version = Some(version);
+        }
+        Some(version)
+    }
+
+    /// 폴더에서 새 버전을 찾아 받아 둠 (받았으면 버전 반환)
+    fn check(&self, folder: &Path, passphrase: Option<&str>) -> Result<Option<u64>, String> {
+        let result = self.fetch(folder, passphrase);
+        if let Ok(mut state) = self.state.lock() {
+            state.last_check_ms = Some(now_ms());
+            match &result {
+                Ok(Some(version)) => {
+                    state.pending_version = Some(*version);
+                    state.last_error = None;
+                }
+                Ok(None) => state.last_error = None,
+                Err(e) => state.last_error = Some(e.clone()),
+            }
+        }
+        result
+    }
+
+    fn fetch(&self, folder: &Path, passphrase: Option<&str>) -> Result<Option<u64>, String> {
+        let pack_path = folder.join(format!("{}.pak", exe_stem()?));
+        let Some(source) = DataSource::find_pack(&pack_path) else {
+            return Ok(None);
+        };
+
+        // 트레일러의 버전으로 먼저 거름 (복사하지 않고 확인)
+        let version = read_trailer(&source)?
+            .content_version()
+            .ok_or_else(|| "Update has no content version".to_string())?;
+        let pending = self.state.lock().ok().and_then(|state| state.pending_version);
+        let dir = install_dir()?;
+        let skipped = read_state(&dir).skipped_version;
+        let newest = [current_version()?, pending, skipped].into_iter().flatten().max();
+        if newest.is_some_and(|newest| version <= newest) {
+            return Ok(None);
+        }
+
+        let checksum_path = checksum_path(&pack_path);
+        let checksums = fs::read_to_string(&checksum_path)
+            .map_err(|e| format!("Failed to read {:?}: {}", checksum_path, e))?;
+        let fingerprint = fingerprint(&source, &checksums);
+        let rejected = self.state.lock().ok().and_then(|state| state.rejected.clone());
+        if rejected.as_ref() == Some(&fingerprint) {
+            return Ok(None);
+        }
+
+        log::info!("Copying content update {} from {:?}", version, pack_path);
+        fs::create_dir_all(&dir).map_err(|e| format!("Failed to create content folder: {}", e))?;
+        let partial = dir.join(PARTIAL_FILE);
+
+        // 읽기 실패는 다음 확인 때 다시 시도하고, 내용이 잘못된 후보는 파일이 바뀔 때까지 건너뜀
+        let verified = copy_verified(&source, &parse_checksums(&checksums), &partial)
+            .inspect_err(|_| {
+                let _ = fs::remove_file(&partial);
+            })?;
+        let valid = if verified {
+            validate(&partial, version, passphrase)
+        } else {
+            Err("Checksum mismatch".to_string())
+        };
+        if let Err(e) = valid {
+            let _ = fs::remove_file(&partial);
+            if let Ok(mut state) = self.state.lock() {
+                state.rejected = Some(fingerprint);
+            }
+            return Err(format!("Content update {} rejected: {}", version, e));
+        }
+
+        fs::rename(&partial, dir.join(INCOMING_FILE))
+            .map_err(|e| format!("Failed to store content update: {}", e))?;
+        Ok(Some(version))
+    }
+}
+
+/// 설치한 콘텐츠 폴더 (<앱 데이터>/content/<exe 이름>)
+fn install_dir() -> Result<PathBuf, String> {
+    Ok(app_data_dir().join("content").join(exe_stem()?))
+}
+
+/// 핫 폴더 업데이트로 설치한 현재 콘텐츠 경로
+pub fn installed_pack_path() -> Result<PathBuf, String> {
+    Ok(install_dir()?.join(CURRENT_FILE))
+}
+
+fn exe_stem() -> Result<String, String> {
+    let exe_path = get_current_exe_path()?;
+    exe_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .ok_or_else(|| "Invalid executable name".to_string())
+}
+
+/// 재생 중인 콘텐츠 버전 (이전 버전 빌드는 None)
+fn current_version() -> Result<Option<u64>, String> {
+    match locate_data_source()? {
+        Some(source) => Ok(read_trailer(&source)?.content_version()),
+        None => Ok(None),
+    }
+}
+
+/// 파일의 콘텐츠 버전 (없거나 읽을 수 없으면 None)
+fn file_version(path: &Path) -> Option<u64> {
+    if !path.is_file() {
+        return None;
+    }
+    read_trailer(&DataSource::file(path)).ok()?.content_version()
+}
+
+fn read_state(dir: &Path) -> UpdateState {
+    fs::read(dir.join(STATE_FILE))
+        .ok()
+        .and_then(|data| serde_json::from_slice(&data).ok())
+        .unwrap_or_default()
+}
+
+fn write_state(dir: &Path, state: &UpdateState) -> Result<(), String> {
+    let data = serde_json::to_vec(state)
+        .map_err(|e| format!("Failed to serialize update state: {}", e))?;
+    fs::write(dir.join(STATE_FILE), data)
+        .map_err(|e| format!("Failed to write update state: {}", e))
+}
+
+/// 데이터 팩 체크섬 파일 경로 (<이름>.pak.sha256, maker가 함께 기록)
+fn checksum_path(pack_path: &Path) -> PathBuf {
+    let mut name = pack_path.as_os_str().to_os_string();
+    name.push(".sha256");
+    PathBuf::from(name)
+}
+
+/// sha256sum 형식의 체크섬 목록 (파일 이름 → 소문자 hex)
+fn parse_checksums(text: &str) -> HashMap<String, String> {
+    text.lines()
+        .filter_map(|line| {
+            let (hash, name) = line.trim().split_once(char::is_whitespace)?;
+            let name = name.trim_start().trim_start_matches('*');
+            Some((name.to_string(), hash.to_ascii_lowercase()))
+        })
+        .collect()
+}
+
+/// 후보 식별값 (체크섬 목록과 파일 크기/수정 시각, 어느 하나라도 바뀌면 다시 시도)
+fn fingerprint(source: &DataSource, checksums: &str) -> String {
+    let mut fingerprint = checksums.to_string();
+    for path in source.volumes() {
+        let metadata = fs::metadata(path).ok();
+        let modified = metadata
+            .as_ref()
+            .and_then(|metadata| metadata.modified().ok())
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map(|elapsed| elapsed.as_millis())
+            .unwrap_or_default();
+        let len = metadata.map(|metadata| metadata.len()).unwrap_or_default();
+        fingerprint.push_str(&format!("|{}:{}", len, modified));
+    }
+    fingerprint
+}
+
+/// 볼륨을 순서대로 하나의 파일에 이어 복사하면서 각 볼륨의 SHA-256 확인
+/// 볼륨은 전체 기준 오프셋을 쓰므로 이어 붙인 파일도 같은 데이터로 읽힘
+fn copy_verified(
+    source: &DataSource,
+    checksums: &HashMap<String, String>,
+    output_path: &Path,
+) -> Result<bool, String> {
+    let mut expected = Vec::with_capacity(source.volumes().len());
+    for path in source.volumes() {
+        let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let hash = checksums
+            .get(&name)
+            .ok_or_else(|| format!("Checksum missing for {}", name))?;
+        expected.push((path, name, hash));
+    }
+
+    let mut output = File::create(output_path)
+        .map_err(|e| format!("Failed to create {:?}: {}", output_path, e))?;
+    let mut buffer = vec![0u8; COPY_BUFFER_SIZE];
+
+    for (path, name, expected) in expected {
+        let mut input = File::open(path).map_err(|e| format!("Failed to open {}: {}", name, e))?;
+        let mut hasher = Sha256::new();
+        loop {
+            let bytes_read = input
+                .read(&mut buffer)
+                .map_err(|e| format!("Failed to read {}: {}", name, e))?;
+            if bytes_read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..bytes_read]);
+            output
+                .write_all(&buffer[..bytes_read])
+                .map_err(|e| format!("Failed to copy {}: {}", name, e))?;
+        }
+
+        let actual: String = hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        if actual != *expected {
+            log::warn!("Checksum mismatch for {}", name);
+            return Ok(false);
+        }
+    }
+
+    output
+        .sync_all()
+        .map_err(|e| format!("Failed to flush {:?}: {}", output_path, e))?;
+    Ok(true)
+}
+
+/// 복사한 콘텐츠 확인 (버전, 서명, 재생 중인 콘텐츠와 같은 프로젝트인지,
+/// 데이터 해시, 매니페스트 복호화, 이 PC에서의 라이선스)
+/// 체크섬 파일은 누구나 다시 만들 수 있으므로 빌드 서명이 확인된 콘텐츠만 받음
+fn validate(path: &Path, version: u64, passphrase: Option<&str>) -> Result<(), String> {
+    let source = DataSource::file(path);
+    let trailer = read_trailer(&source)?;
+    if trailer.content_version() != Some(version) {
+        return Err("Content version changed while copying".to_string());
+    }
+
+    let key = resolve_key(&trailer, passphrase)?;
+    let incoming = open_manifest(&source, key.as_ref())?.manifest;
+    if incoming.signature != SignatureStatus::Verified {
+        return Err("Update is not signed with this player's build key".to_string());
+    }
+
+    let running = running_manifest(passphrase)?;
+    if incoming.project_id.is_none() || incoming.project_id != running.project_id {
+        return Err(format!(
+            "Update is for a different project ({:?}, playing {:?})",
+            incoming.project_id, running.project_id
+        ));
+    }
+
+    let expected = incoming
+        .payload_sha256
+        .as_deref()
+        .ok_or_else(|| "Update has no payload digest".to_string())?;
+    if payload_digest(&source, &incoming)? != expected {
+        return Err("Payload digest mismatch".to_string());
+    }
+
+    if check_license(&incoming) != LicenseStatus::Valid {
+        return Err("License is not valid on this PC".to_string());
+    }
+    Ok(())
+}
+
+/// 재생 중인 콘텐츠의 매니페스트 (업데이트와 프로젝트 비교용)
+fn running_manifest(passphrase: Option<&str>) -> Result<BuildManifest, String> {
+    let source = locate_data_source()?.ok_or_else(|| "No content is playing".to_string())?;
+    let key = resolve_key(&read_trailer(&source)?, passphrase)?;
+    Ok(open_manifest(&source, key.as_ref())?.manifest)
+}
+
+/// 콘텐츠를 열 키 (암호화되지 않았거나 내장 키면 None, 암호 모드면 실행 시 암호에서 유도)
+pub fn resolve_key(
+    trailer: &Trailer,
+    passphrase: Option<&str>,
+) -> Result<Option<ContentKey>, String> {
+    let Trailer::Sealed(sealed) = trailer else {
+        return Ok(None);
+    };
+    if sealed.embedded_key()?.is_some() {
+        return Ok(None);
+    }
+    let passphrase = passphrase
+        .ok_or_else(|| "Passphrase-protected update requires the launch key".to_string())?;
+    sealed.derive_key(passphrase).map(Some)
+}
@@ -0,0 +1,287 @@
+use crate::analytics::{format_timestamp, now_ms};
+use serde::{Deserialize, Serialize};
+use std::ffi::OsString;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus};
+use std::time::{Duration, Instant};
+
+/// 감시 프로세스가 UI 프로세스에 넘기는 하트비트 파일 경로
+/// 설정되어 있으면 현재 프로세스는 감시 대상인 UI 프로세스
+pub const HEARTBEAT_FILE_ENV: &str = "VISTUT_WATCHDOG_HEARTBEAT";
+
+/// 지금까지 재시작한 횟수
+const RESTART_COUNT_ENV: &str = "VISTUT_WATCHDOG_RESTARTS";
+
+/// 마지막 재시작 사유
+const RESTART_REASON_ENV: &str = "VISTUT_WATCHDOG_REASON";
+
+/// 재시작을 포기한 사유 (설정되면 UI는 에러 화면만 표시)
+pub const FAILURE_ENV: &str = "VISTUT_WATCHDOG_FAILURE";
+
+/// UI 프로세스 상태 확인 주기
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// 재시작 전 대기 시간 (드라이버/파일 잠금 정리)
+const RESTART_DELAY: Duration = Duration::from_secs(2);
+
+/// 첫 하트비트까지 기다리는 시간 (앱 시작, 콘텐츠 로드 포함)
+const STARTUP_GRACE: Duration = Duration::from_secs(60);
+
+/// 하트비트 대기 시간 최솟값 (프론트엔드는 5초마다 전송)
+const MIN_HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// 감시 로그 파일 이름 (앱 데이터 폴더)
+const LOG_FILE_NAME: &str = "watchdog.log";
+
+/// 앱 데이터 폴더 이름 (tauri.conf.json의 identifier)
+pub const APP_IDENTIFIER: &str = "com.viswave.tutorialviewer";
+
+/// 프로젝트 설정의 자동 재시작 (settings.watchdog)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchdogSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// 프론트엔드 하트비트가 이 시간 동안 없으면 멈춘 것으로 판단 (초)
+    #[serde(default = "default_heartbeat_timeout")]
+    pub heartbeat_timeout_seconds: u64,
+    /// `restart_window_seconds` 안에서 허용하는 최대 재시작 횟수
+    #[serde(default = "default_max_restarts")]
+    pub max_restarts: u32,
+    #[serde(default = "default_restart_window")]
+    pub restart_window_seconds: u64,
+}
+
+fn default_heartbeat_timeout() -> u64 {
+    30
+}
+
+fn default_max_restarts() -> u32 {
+    5
+}
+
+fn default_restart_window() -> u64 {
+    600
+}
+
+#[derive(Deserialize)]
+struct ProjectWatchdog {
+    settings: SettingsWatchdog,
+}
+
+#[derive(Deserialize)]
+struct SettingsWatchdog {
+    #[serde(default)]
+    watchdog: Option<WatchdogSettings>,
+}
+
+impl WatchdogSettings {
+    /// 프로젝트 JSON에서 자동 재시작 설정 읽기 (꺼져 있으면 None)
+    pub fn from_project_json(project_json: &str) -> Result<Option<Self>, String> {
+        let project: ProjectWatchdog = serde_json::from_str(project_json)
+            .map_err(|e| format!("Failed to parse watchdog settings: {}", e))?;
+        Ok(project.settings.watchdog.filter(|watchdog| watchdog.enabled))
+    }
+
+    fn heartbeat_timeout(&self) -> Duration {
+        Duration::from_secs(self.heartbeat_timeout_seconds).max(MIN_HEARTBEAT_TIMEOUT)
+    }
+}
+
+/// UI 프로세스의 감시 상태 (프론트엔드에 알려줌)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchdogStatus {
+    pub supervised: bool,
+    pub restart_count: u32,
+    pub last_restart_reason: Option<String>,
+    /// 재시작을 포기한 사유 (있으면 에러 화면 표시)
+    pub failure: Option<String>,
+}
+
+impl WatchdogStatus {
+    /// 감시 프로세스가 넘겨준 환경 변수에서 상태 읽기
+    pub fn from_env() -> Self {
+        Self {
+            supervised: std::env::var_os(HEARTBEAT_FILE_ENV).is_some(),
+            restart_count: std::env::var(RESTART_COUNT_ENV)
+                .ok()
+                .and_then(|count| count.parse().ok())
+                .unwrap_or(0),
+            last_restart_reason: std::env::var(RESTART_REASON_ENV).ok(),
+            failure: std::env::var(FAILURE_ENV).ok(),
+        }
+    }
+}
+
+/// 현재 프로세스가 감시 프로세스가 실행한 UI 프로세스인지
+pub fn is_supervised_child() -> bool {
+    std::env::var_os(HEARTBEAT_FILE_ENV).is_some() || std::env::var_os(FAILURE_ENV).is_some()
+}
+
+/// 하트비트 기록 (감시 중이 아니면 아무것도 하지 않음)
+pub fn beat() -> Result<(), String> {
+    let Some(path) = std::env::var_os(HEARTBEAT_FILE_ENV) else {
+        return Ok(());
+    };
+    fs::write(&path, now_ms().to_string())
+        .map_err(|e| format!("Failed to write heartbeat: {}", e))
+}
+
+/// 감시 프로세스 실행
+/// 같은 exe를 UI 프로세스로 실행하고, 비정상 종료하거나 하트비트가 끊기면 다시 실행
+/// 재시작이 너무 잦으면 포기하고 에러 화면만 띄움 (반환값은 종료 코드)
+pub fn supervise(settings: &WatchdogSettings) -> i32 {
+    let log_path = log_path();
+    let exe = match std::env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => {
+            write_log(&log_path, &format!("Failed to get executable path: {}", e));
+            return 1;
+        }
+    };
+    let args: Vec<OsString> = std::env::args_os().skip(1).collect();
+    let heartbeat = std::env::temp_dir().join(format!("vistut-heartbeat-{}", std::process::id()));
+    let window = Duration::from_secs(settings.restart_window_seconds);
+
+    let mut recent_restarts: Vec<Instant> = Vec::new();
+    let mut restart_count: u32 = 0;
+    let mut last_reason: Option<String> = None;
+
+    write_log(&log_path, "Watchdog started");
+
+    let exit_code = loop {
+        let _ = fs::remove_file(&heartbeat);
+
+        let mut command = Command::new(&exe);
+        command
+            .args(&args)
+            .env(HEARTBEAT_FILE_ENV, &heartbeat)
+            .env(RESTART_COUNT_ENV, restart_count.to_string());
+        if let Some(reason) = &last_reason {
+            command.env(RESTART_REASON_ENV, reason);
+        }
+
+        let child = match command.spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                write_log(&log_path, &format!("Failed to start UI process: {}", e));
+                break 1;
+            }
+        };
+
+        let reason = match wait_for_child(child, &heartbeat, settings.heartbeat_timeout()) {
+            Ok(status) if status.success() => break 0,
+            Ok(status) => format!("UI process exited unexpectedly ({})", status),
+            Err(reason) => reason,
+        };
+
+        let now = Instant::now();
+        recent_restarts.retain(|at| now.duration_since(*at) < window);
+        recent_restarts.push(now);
+        restart_count += 1;
+
+        if recent_restarts.len() as u32 > settings.max_restarts {
+            let failure = format!(
+                "Gave up after {} restarts within {}s (last: {})",
+                recent_restarts.len(),
+                settings.restart_window_seconds,
+                reason
+            );
+            write_log(&log_path, &failure);
+
+            // 에러 화면을 보여주기 위해 감시 없이 한 번 더 실행
+            let status = Command::new(&exe).args(&args).env(FAILURE_ENV, &failure).status();
+            break status.ok().and_then(|status| status.code()).unwrap_or(1);
+        }
+
+        write_log(
+            &log_path,
+            &format!("Restarting UI process (restart #{}): {}", restart_count, reason),
+        );
+        last_reason = Some(reason);
+        std::thread::sleep(RESTART_DELAY);
+    };
+
+    let _ = fs::remove_file(&heartbeat);
+    write_log(&log_path, &format!("Watchdog stopped (exit code {})", exit_code));
+    exit_code
+}
+
+/// UI 프로세스 종료 또는 멈춤 대기
+/// 멈춘 경우 프로세스를 종료하고 사유를 Err로 반환
+fn wait_for_child(
+    mut child: Child,
+    heartbeat: &Path,
+    timeout: Duration,
+) -> Result<ExitStatus, String> {
+    let started = Instant::now();
+
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return Ok(status),
+            Ok(None) => {}
+            Err(e) => {
+                let _ = child.kill();
+                return Err(format!("Failed to check UI process: {}", e));
+            }
+        }
+
+        let hang = match heartbeat_age(heartbeat) {
+            Some(age) if age > timeout => Some(format!("Heartbeat stopped for {}s", age.as_secs())),
+            Some(_) => None,
+            None if started.elapsed() > STARTUP_GRACE.max(timeout) => Some(format!(
+                "No heartbeat within {}s of startup",
+                started.elapsed().as_secs()
+            )),
+            None => None,
+        };
+
+        if let Some(reason) = hang {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(reason);
+        }
+
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// 마지막 하트비트 이후 지난 시간 (아직 없으면 None)
+fn heartbeat_age(heartbeat: &Path) -> Option<Duration> {
+    let modified = fs::metadata(heartbeat).and_then(|meta| meta.modified()).ok()?;
+    Some(modified.elapsed().unwrap_or_default())
+}
+
+/// 감시 로그 경로 (Tauri 앱 데이터 폴더와 같은 위치)
+pub fn log_path() -> PathBuf {
+    app_data_dir().join(LOG_FILE_NAME)
+}
+
+/// Tauri 시작 전이라 PathResolver 대신 플랫폼별 앱 데이터 폴더를 직접 계산
+pub fn app_data_dir() -> PathBuf {
+    let base = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join("Library/Application Support"))
+    } else {
+        std::env::var_os("XDG_DATA_HOME").map(PathBuf::from).or_else(|| {
+            std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share"))
+        })
+    };
+
+    base.unwrap_or_else(std::env::temp_dir).join(APP_IDENTIFIER)
+}
+
+fn write_log(path: &Path, message: &str) {
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    let line = format!("[{}] {}\n", format_timestamp(now_ms() as i64), message);
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+        let _ = file.write_all(line.as_bytes());
+    }
+}
@@ -1,5 +1,6 @@
-import { useState, useEffect, useRef } from 'react'
-import { invoke } from '@tauri-apps/api/core'
+import { useState, useEffect, useRef, useCallback, useMemo } from 'react'
+import { convertFileSrc, invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
 import { getCurrentWindow } from '@tauri-apps/api/window'
 import {
   ProductPageContent,
@@ -7,8 +8,21 @@ import {
   ErrorScreen,
   setFavicon,
   setDocumentTitle,
+  collectProjectAssetIds,
+  getDefaultLocale,
+  getProjectLocales,
+  localizeProject,
+  type InteractionEvent,
   type Project,
+  type RemoteNavigation,
 } from '@viswave/shared'
+import AdminPinDialog from './components/AdminPinDialog'
+import LanguageSwitcher from './components/LanguageSwitcher'
+import { useKioskLockdown } from './hooks/useKioskLockdown'
+import LauncherPage, {
+  type EmbeddedProjectSummary,
+} from './pages/LauncherPage'
+import UnlockPage from './pages/UnlockPage'
 import ViewerPage from './pages/ViewerPage'
 
 /**
@@ -26,9 +40,102 @@ async function setWindowTitle(title: string) {
   }
 }
 
+// 라이선스 확인 결과
+type LicenseStatus =
+  | 'valid'
+  | 'notYetValid'
+  | 'expired'
+  | 'machineNotAllowed'
+  | 'invalid'
+
+// 라이선스 상태별 안내 문구
+const LICENSE_MESSAGES: Record<Exclude<LicenseStatus, 'valid'>, string> = {
+  notYetValid: '아직 사용 기간이 시작되지 않았습니다',
+  expired: '라이선스가 만료되었습니다',
+  machineNotAllowed: '이 PC에서는 실행할 수 없습니다',
+  invalid: '라이선스 정보가 손상되었습니다',
+}
+
+// 라이선스 재확인 주기 (1분)
+const LICENSE_CHECK_INTERVAL = 60 * 1000
+
+// 감시 프로세스에 보내는 하트비트 주기 (5초)
+const WATCHDOG_HEARTBEAT_INTERVAL = 5 * 1000
+
+// 입력 알림 최소 간격 (1초)
+const ACTIVITY_REPORT_INTERVAL = 1000
+
+// 재생 일정 확인 주기 (1분)
+const SCHEDULE_CHECK_INTERVAL = 60 * 1000
+
+// 대기 화면 복귀를 사용하지 않을 때 일정 전환/콘텐츠 교체 전 입력이 없어야 하는 시간 (2분)
+const SCHEDULE_SWITCH_IDLE = 2 * 60 * 1000
+
+/** 재생 일정에 맞는 프로젝트 ID (현지 시각 기준) */
+const getScheduledProject = () =>
+  invoke<string | null>('get_scheduled_project', {
+    utcOffsetMinutes: -new Date().getTimezoneOffset(),
+  })
+
+// 자동 재시작 상태
+interface WatchdogStatus {
+  supervised: boolean
+  restartCount: number
+  lastRestartReason?: string
+  failure?: string
+}
+
+// 상태 기록에 알리는 현재 화면
+type PlayerScreen =
+  | 'loading'
+  | 'playing'
+  | 'launcher'
+  | 'locked'
+  | 'license'
+  | 'error'
+  | 'viewer'
+
+// 원격 제어 API로 받은 명령
+type ControlCommand =
+  | { type: 'goto'; pageId: string }
+  | { type: 'next' }
+  | { type: 'home' }
+  | { type: 'reload' }
+  | { type: 'rollback' }
+  | { type: 'mute'; muted: boolean }
+
+// 핫 폴더 업데이트 상태
+interface ContentUpdateStatus {
+  enabled: boolean
+  currentVersion?: number
+  pendingVersion?: number
+  previousVersion?: number
+}
+
+// 함께 넣은 프로젝트 목록 (단일 프로젝트 빌드면 비어 있음)
+interface EmbeddedProjects {
+  projects: EmbeddedProjectSummary[]
+  selected?: string
+}
+
+/**
+ * 임베딩 미디어 URL (백엔드의 embedded URI 스킴)
+ * 웹뷰가 페이지를 표시할 때 필요한 구간만 요청하므로 큰 영상도 메모리에 전부 올리지 않음
+ */
+const embeddedMediaUrl = (id: string) => convertFileSrc(id, 'embedded')
+
+/** 프로젝트에서 사용하는 미디어/버튼 이미지 ID (모든 언어) */
+const collectUsedIds = (project: Project): Set<string> => {
+  const { mediaIds, buttonImageIds } = collectProjectAssetIds(project)
+  return new Set([...mediaIds, ...buttonImageIds])
+}
+
 // 임베딩 정보 타입
 interface EmbeddedInfo {
   hasEmbeddedData: boolean
+  locked: boolean
+  licenseStatus: LicenseStatus
+  machineId?: string
   manifest: {
     projectJsonOffset: number
     projectJsonSize: number
@@ -65,13 +172,38 @@ function App() {
   >({})
   const [embeddedIconUrl, setEmbeddedIconUrl] = useState<string | undefined>()
   const [embeddedError, setEmbeddedError] = useState<string | null>(null)
+  // 다국어 튜토리얼의 현재 언어 (대기 화면 복귀 시 시작 언어로 되돌림)
+  const [locale, setLocale] = useState<string | undefined>()
+  const startLocaleRef = useRef<string | undefined>()
+  // 여러 튜토리얼 빌드에서 선택 전이면 선택 화면에 표시할 목록
+  const [launcherProjects, setLauncherProjects] = useState<
+    EmbeddedProjectSummary[] | null
+  >(null)
+  const [hasMultipleProjects, setHasMultipleProjects] = useState(false)
+  // 일정이 바뀌어 다음 대기 화면 복귀 때 전환할 프로젝트
+  const pendingScheduleRef = useRef<string | null>(null)
+  // 핫 폴더에서 받아 두고 다음 대기 화면 복귀 때 적용할 새 콘텐츠가 있음
+  const pendingUpdateRef = useRef(false)
+  // 암호화된 콘텐츠 잠금 상태 (잠금 해제 후 reloadKey로 다시 로드)
+  const [isLocked, setIsLocked] = useState(false)
+  const [reloadKey, setReloadKey] = useState(0)
+  const [licenseStatus, setLicenseStatus] = useState<LicenseStatus>('valid')
+  const [machineId, setMachineId] = useState<string | undefined>()
+  // 대기 시간 초과 시 증가 (대기 화면 복귀 신호)
+  const [idleResetSignal, setIdleResetSignal] = useState(0)
+  const lastActivityRef = useRef(0)
+  // 원격 제어 이동 명령과 음소거 상태
+  const [remoteNavigation, setRemoteNavigation] = useState<
+    RemoteNavigation | undefined
+  >()
+  const [muted, setMuted] = useState(false)
+  const [showPinDialog, setShowPinDialog] = useState(false)
+  const kiosk = useKioskLockdown(!!embeddedProject)
+  // 자동 재시작을 포기한 사유 (있으면 에러 화면 표시)
+  const [watchdogFailure, setWatchdogFailure] = useState<string | null>(null)
 
-  // URL 참조를 ref로 유지 (cleanup 시 stale closure 방지)
-  const urlsRef = useRef({
-    mediaUrls: {} as Record<string, string>,
-    buttonUrls: {} as Record<string, string>,
-    iconUrl: undefined as string | undefined,
-  })
+  // 아이콘 URL 참조를 ref로 유지 (cleanup 시 stale closure 방지)
+  const iconUrlRef = useRef<string | undefined>()
 
   // 임베딩 데이터 확인 및 로드
   useEffect(() => {
@@ -79,45 +211,75 @@ function App() {
       try {
         const info = await invoke<EmbeddedInfo>('get_embedded_data_info')
 
+        if (info.hasEmbeddedData && info.locked) {
+          // 암호 입력 대기
+          setIsLocked(true)
+          setIsEmbeddedMode(true)
+          return
+        }
+
+        if (info.hasEmbeddedData && info.licenseStatus !== 'valid') {
+          // 라이선스 만료/허용되지 않은 PC - 튜토리얼 대신 안내 표시
+          setLicenseStatus(info.licenseStatus)
+          setMachineId(info.machineId)
+          setIsEmbeddedMode(true)
+          return
+        }
+
         if (info.hasEmbeddedData && info.manifest) {
+          // 여러 튜토리얼이 들어 있고 실행 인자로 고르지 않았으면 선택 화면 표시
+          const embedded = await invoke<EmbeddedProjects>(
+            'get_embedded_projects'
+          )
+          setHasMultipleProjects(embedded.projects.length > 1)
+          pendingScheduleRef.current = null
+
+          // 재생 일정이 있으면 지금 시간에 맞는 튜토리얼로 시작
+          if (embedded.projects.length > 1) {
+            const scheduled = await getScheduledProject()
+            if (scheduled && scheduled !== embedded.selected) {
+              await invoke('select_embedded_project', { id: scheduled })
+              embedded.selected = scheduled
+            }
+          }
+
+          if (embedded.projects.length > 1 && !embedded.selected) {
+            setLauncherProjects(embedded.projects)
+            setIsEmbeddedMode(true)
+            return
+          }
+
           // 임베딩된 프로젝트 JSON 로드
           const projectJson = await invoke<string>('get_embedded_project_json')
           const project: Project = JSON.parse(projectJson)
+          // 시작 언어 (실행 인자 --lang 또는 프로젝트의 기본 언어)
+          const launchLocale = await invoke<string | null>('get_launch_locale')
+          const startLocale =
+            launchLocale && getProjectLocales(project).includes(launchLocale)
+              ? launchLocale
+              : getDefaultLocale(project)
+          startLocaleRef.current = startLocale
+          setLocale(startLocale)
           setEmbeddedProject(project)
+          // 미디어는 여러 튜토리얼이 공유하므로 이 프로젝트가 쓰는 것만 URL 생성
+          // (데이터는 현재 페이지와 이동 가능한 페이지가 표시될 때 웹뷰가 요청)
+          const usedIds = collectUsedIds(project)
 
-          // 윈도우 타이틀 설정 (앱 타이틀 또는 프로젝트 이름)
-          const title = project.appTitle || project.name
-          if (title) {
-            setWindowTitle(title)
-          }
-
-          // 미디어 URL 생성
           const mediaUrls: Record<string, string> = {}
           for (const media of info.manifest.media) {
-            const data = await invoke<number[]>('get_embedded_media_data', {
-              id: media.id,
-            })
-            const blob = new Blob([new Uint8Array(data)], {
-              type: media.mimeType,
-            })
-            mediaUrls[media.id] = URL.createObjectURL(blob)
+            if (usedIds.has(media.id)) {
+              mediaUrls[media.id] = embeddedMediaUrl(media.id)
+            }
           }
           setEmbeddedMediaUrls(mediaUrls)
-          urlsRef.current.mediaUrls = mediaUrls
 
-          // 버튼 이미지 URL 생성
           const buttonUrls: Record<string, string> = {}
           for (const button of info.manifest.buttons) {
-            const data = await invoke<number[]>('get_embedded_media_data', {
-              id: button.id,
-            })
-            const blob = new Blob([new Uint8Array(data)], {
-              type: button.mimeType,
-            })
-            buttonUrls[button.id] = URL.createObjectURL(blob)
+            if (usedIds.has(button.id)) {
+              buttonUrls[button.id] = embeddedMediaUrl(button.id)
+            }
           }
           setEmbeddedButtonUrls(buttonUrls)
-          urlsRef.current.buttonUrls = buttonUrls
 
           // 앱 아이콘 로드 및 파비콘 설정
           if (info.manifest.appIconOffset && info.manifest.appIconSize) {
@@ -128,9 +290,10 @@ function App() {
               const blob = new Blob([new Uint8Array(iconData)], {
                 type: 'image/png',
               })
+              if (iconUrlRef.current) URL.revokeObjectURL(iconUrlRef.current)
               const iconUrl = URL.createObjectURL(blob)
               setEmbeddedIconUrl(iconUrl)
-              urlsRef.current.iconUrl = iconUrl
+              iconUrlRef.current = iconUrl
 
               // 파비콘도 설정
               setFavicon(iconUrl)
@@ -156,8 +319,224 @@ function App() {
     }
 
     checkEmbeddedData()
+  }, [reloadKey])
+
+  // 현재 언어로 바꾼 프로젝트 (페이지 구성은 모든 언어가 공유)
+  const localizedProject = useMemo(
+    () => embeddedProject && localizeProject(embeddedProject, locale),
+    [embeddedProject, locale]
+  )
+
+  // 윈도우 타이틀 설정 (현재 언어의 앱 타이틀 또는 프로젝트 이름)
+  useEffect(() => {
+    if (!localizedProject) return
+    const title = localizedProject.appTitle || localizedProject.name
+    if (title) {
+      setWindowTitle(title)
+    }
+  }, [localizedProject])
+
+  // 재생 중 라이선스 만료 감지 (장시간 켜두는 키오스크용)
+  useEffect(() => {
+    if (!embeddedProject) return
+
+    const timer = setInterval(async () => {
+      try {
+        const status = await invoke<LicenseStatus>('check_embedded_license')
+        if (status !== 'valid') {
+          setLicenseStatus(status)
+          setMachineId(await invoke<string | null>('get_machine_id') ?? undefined)
+        }
+      } catch (err) {
+        console.warn('License check failed:', err)
+      }
+    }, LICENSE_CHECK_INTERVAL)
+
+    return () => clearInterval(timer)
+  }, [embeddedProject])
+
+  // 자동 재시작 상태 확인 및 하트비트 전송 (화면이 멈추면 감시 프로세스가 재시작)
+  useEffect(() => {
+    invoke<WatchdogStatus>('get_watchdog_status')
+      .then((status) => {
+        if (status.failure) setWatchdogFailure(status.failure)
+        if (status.restartCount > 0) {
+          console.warn(
+            `Restarted by watchdog (${status.restartCount}):`,
+            status.lastRestartReason
+          )
+        }
+      })
+      .catch((err) => console.warn('Watchdog status check failed:', err))
+
+    const sendHeartbeat = () =>
+      invoke('watchdog_heartbeat').catch((err) =>
+        console.warn('Watchdog heartbeat failed:', err)
+      )
+    sendHeartbeat()
+    const timer = setInterval(sendHeartbeat, WATCHDOG_HEARTBEAT_INTERVAL)
+    return () => clearInterval(timer)
   }, [])
 
+  // 현재 화면 (아래 렌더링과 같은 순서로 판단)
+  const playerScreen = ((): PlayerScreen => {
+    if (isEmbeddedMode === null) return 'loading'
+    if (isLocked) return 'locked'
+    if (watchdogFailure) return 'error'
+    if (licenseStatus !== 'valid') return 'license'
+    if (embeddedError) return 'error'
+    if (launcherProjects) return 'launcher'
+    return isEmbeddedMode ? 'playing' : 'viewer'
+  })()
+  const playerError = watchdogFailure ?? embeddedError
+
+  // 현재 화면을 상태 기록에 알림 (모니터링에서 에러/라이선스 문제 감지)
+  useEffect(() => {
+    invoke('report_player_state', {
+      screen: playerScreen,
+      error: playerError,
+    }).catch((err) => console.warn('Player state report failed:', err))
+  }, [playerScreen, playerError])
+
+  // 처리하지 못한 에러를 상태 기록의 마지막 에러로 남김
+  useEffect(() => {
+    const reportError = (message: string) =>
+      invoke('report_player_error', { message }).catch((err) =>
+        console.warn('Player error report failed:', err)
+      )
+    const handleError = (event: ErrorEvent) => reportError(event.message)
+    const handleRejection = (event: PromiseRejectionEvent) =>
+      reportError(String(event.reason))
+
+    window.addEventListener('error', handleError)
+    window.addEventListener('unhandledrejection', handleRejection)
+    return () => {
+      window.removeEventListener('error', handleError)
+      window.removeEventListener('unhandledrejection', handleRejection)
+    }
+  }, [])
+
+  // 대기 화면 복귀 설정 및 백엔드 초기화 이벤트 수신
+  useEffect(() => {
+    if (!embeddedProject) return
+
+    invoke('configure_idle_reset', {
+      timeoutSeconds: embeddedProject.settings.idleTimeoutSeconds ?? 0,
+    }).catch((err) => console.warn('Idle reset setup failed:', err))
+
+    // 새 콘텐츠나 일정 전환이 대기 중이면 대기 화면 대신 전환
+    const unlisten = listen('idle-reset', () => {
+      if (pendingUpdateRef.current) {
+        applyContentUpdate()
+        return
+      }
+      const pending = pendingScheduleRef.current
+      if (pending) {
+        handleProjectSelect(pending).catch((err) =>
+          console.warn('Scheduled project switch failed:', err)
+        )
+        return
+      }
+      setLocale(startLocaleRef.current)
+      setIdleResetSignal((signal) => signal + 1)
+    })
+    return () => {
+      unlisten.then((fn) => fn())
+    }
+  }, [embeddedProject])
+
+  // 원격 제어 명령 수신 (이동은 화면 조작과 같은 탐색 로직으로 처리)
+  useEffect(() => {
+    const unlisten = listen<ControlCommand>('control-command', (event) => {
+      const command = event.payload
+      if (command.type === 'reload') {
+        handleReload()
+      } else if (command.type === 'rollback') {
+        invoke('rollback_content_update')
+          .catch((err) => console.warn('Content rollback failed:', err))
+          .finally(handleReload)
+      } else if (command.type === 'mute') {
+        setMuted(command.muted)
+      } else {
+        setRemoteNavigation((previous) => ({
+          seq: (previous?.seq ?? 0) + 1,
+          type: command.type,
+          pageId: command.type === 'goto' ? command.pageId : undefined,
+        }))
+      }
+    })
+    return () => {
+      unlisten.then((fn) => fn())
+    }
+  }, [])
+
+  // 핫 폴더 업데이트 (재생 전이면 즉시, 재생 중에는 대기 화면 복귀 시 교체)
+  // 대기 화면 복귀를 사용하지 않으면 입력이 한동안 없을 때 교체
+  useEffect(() => {
+    const idleResetEnabled =
+      (embeddedProject?.settings.idleTimeoutSeconds ?? 0) > 0
+
+    const unlisten = listen<number>('content-update-ready', () => {
+      if (embeddedProject) {
+        pendingUpdateRef.current = true
+      } else {
+        applyContentUpdate()
+      }
+    })
+
+    // 시작 전에 받아 둔 콘텐츠 (이벤트를 놓쳤을 수 있음)
+    if (embeddedProject) {
+      invoke<ContentUpdateStatus>('get_content_update_status')
+        .then((status) => {
+          if (status.pendingVersion) pendingUpdateRef.current = true
+        })
+        .catch((err) => console.warn('Content update status failed:', err))
+    }
+
+    const timer = setInterval(() => {
+      const isIdle =
+        Date.now() - lastActivityRef.current >= SCHEDULE_SWITCH_IDLE
+      if (pendingUpdateRef.current && !idleResetEnabled && isIdle) {
+        applyContentUpdate()
+      }
+    }, SCHEDULE_CHECK_INTERVAL)
+
+    return () => {
+      unlisten.then((fn) => fn())
+      clearInterval(timer)
+    }
+  }, [embeddedProject])
+
+  // 재생 일정 확인 (안전한 시점에 전환: 선택 화면은 즉시, 재생 중에는 대기 화면 복귀 시)
+  useEffect(() => {
+    if (!hasMultipleProjects) return
+
+    const checkSchedule = async () => {
+      try {
+        const scheduled = await getScheduledProject()
+        if (!scheduled || scheduled === embeddedProject?.id) {
+          pendingScheduleRef.current = null
+          return
+        }
+
+        const idleResetEnabled =
+          (embeddedProject?.settings.idleTimeoutSeconds ?? 0) > 0
+        const isIdle =
+          Date.now() - lastActivityRef.current >= SCHEDULE_SWITCH_IDLE
+        if (!embeddedProject || (!idleResetEnabled && isIdle)) {
+          await handleProjectSelect(scheduled)
+        } else {
+          pendingScheduleRef.current = scheduled
+        }
+      } catch (err) {
+        console.warn('Schedule check failed:', err)
+      }
+    }
+
+    const timer = setInterval(checkSchedule, SCHEDULE_CHECK_INTERVAL)
+    return () => clearInterval(timer)
+  }, [hasMultipleProjects, embeddedProject])
+
   // CLI 인자로 파일 경로 받기 (임베딩 모드가 아닐 때만)
   useEffect(() => {
     if (isEmbeddedMode !== false) return
@@ -179,16 +558,84 @@ function App() {
     checkCliArgs()
   }, [isEmbeddedMode])
 
-  // URL 정리 (ref를 사용하여 현재 값으로 cleanup)
+  // 아이콘 URL 정리 (ref를 사용하여 현재 값으로 cleanup)
   useEffect(() => {
     return () => {
-      const { mediaUrls, buttonUrls, iconUrl } = urlsRef.current
-      Object.values(mediaUrls).forEach(URL.revokeObjectURL)
-      Object.values(buttonUrls).forEach(URL.revokeObjectURL)
-      if (iconUrl) URL.revokeObjectURL(iconUrl)
+      if (iconUrlRef.current) URL.revokeObjectURL(iconUrlRef.current)
+    }
+  }, [])
+
+  // 암호로 잠금 해제 후 임베딩 데이터 다시 로드
+  const handleUnlock = async (passphrase: string) => {
+    await invoke('unlock_embedded_content', { passphrase })
+    setIsLocked(false)
+    setIsEmbeddedMode(null)
+    setReloadKey((key) => key + 1)
+  }
+
+  // 콘텐츠 다시 로드 (새 콘텐츠의 미디어는 같은 URL로 다시 요청됨)
+  const handleReload = () => {
+    setRemoteNavigation(undefined)
+    setLauncherProjects(null)
+    setIsEmbeddedMode(null)
+    setReloadKey((key) => key + 1)
+  }
+
+  // 받아 둔 새 콘텐츠로 교체 후 다시 로드 (실패하면 백엔드가 이전 콘텐츠로 되돌림)
+  const applyContentUpdate = async () => {
+    pendingUpdateRef.current = false
+    try {
+      await invoke('apply_content_update')
+    } catch (err) {
+      console.warn('Content update failed:', err)
     }
+    handleReload()
+  }
+
+  // 선택 화면에서 고른 튜토리얼 로드
+  const handleProjectSelect = async (id: string) => {
+    await invoke('select_embedded_project', { id })
+    handleReload()
+  }
+
+  // 현재 페이지를 알려 이동 가능한 다음 페이지의 미디어를 미리 읽게 함
+  const handlePageChange = useCallback(
+    (pageIndex: number, pageId?: string) => {
+      invoke('set_current_page', { pageIndex, pageId }).catch((err) =>
+        console.warn('Prefetch request failed:', err)
+      )
+    },
+    []
+  )
+
+  // 방문자 입력을 알려 대기 시간 초기화 (초당 한 번까지만 전송)
+  const handleActivity = useCallback(() => {
+    const now = Date.now()
+    if (now - lastActivityRef.current < ACTIVITY_REPORT_INTERVAL) return
+    lastActivityRef.current = now
+    invoke('report_activity').catch((err) =>
+      console.warn('Activity report failed:', err)
+    )
   }, [])
 
+  // 방문자 상호작용을 통계용으로 기록
+  const handleInteraction = useCallback((event: InteractionEvent) => {
+    invoke('record_analytics_event', { event }).catch((err) =>
+      console.warn('Analytics record failed:', err)
+    )
+  }, [])
+
+  // 키오스크 모드 종료 (종료 키 확인 후, 관리자 PIN이 있으면 PIN 입력)
+  const handleKioskExit = () => {
+    if (kiosk?.requiresPin) {
+      setShowPinDialog(true)
+      return
+    }
+    invoke('exit_kiosk', { pin: null }).catch((err) =>
+      console.error('Kiosk exit failed:', err)
+    )
+  }
+
   const handleFileSelect = (path: string) => {
     setFilePath(path)
   }
@@ -198,23 +645,88 @@ function App() {
     return <LoadingScreen />
   }
 
+  // 암호화된 콘텐츠 잠금 해제
+  if (isLocked) {
+    return <UnlockPage onUnlock={handleUnlock} />
+  }
+
+  // 반복된 비정상 종료로 자동 재시작 중단
+  if (watchdogFailure) {
+    return (
+      <ErrorScreen
+        title='재생을 계속할 수 없습니다'
+        message={`관리자에게 문의하세요. (${watchdogFailure})`}
+      />
+    )
+  }
+
+  // 라이선스 문제 (만료/허용되지 않은 PC)
+  if (licenseStatus !== 'valid') {
+    return (
+      <ErrorScreen
+        title={LICENSE_MESSAGES[licenseStatus]}
+        message={
+          machineId
+            ? `관리자에게 문의하세요. (PC ID: ${machineId})`
+            : '관리자에게 문의하세요.'
+        }
+      />
+    )
+  }
+
   // 임베딩 모드 에러
   if (embeddedError) {
     return <ErrorScreen title='로드 실패' message={embeddedError} />
   }
 
-  // 임베딩 모드: 바로 튜토리얼 재생
-  if (isEmbeddedMode && embeddedProject) {
+  // 여러 튜토리얼 중 선택
+  if (launcherProjects) {
     return (
-      <ProductPageContent
-        project={embeddedProject}
-        mediaUrls={embeddedMediaUrls}
-        buttonImageUrls={embeddedButtonUrls}
-        iconUrl={embeddedIconUrl}
+      <LauncherPage
+        projects={launcherProjects}
+        onSelect={handleProjectSelect}
       />
     )
   }
 
+  // 임베딩 모드: 바로 튜토리얼 재생
+  if (isEmbeddedMode && localizedProject && embeddedProject) {
+    const locales = getProjectLocales(embeddedProject)
+    return (
+      <>
+        <ProductPageContent
+          project={localizedProject}
+          mediaUrls={embeddedMediaUrls}
+          buttonImageUrls={embeddedButtonUrls}
+          iconUrl={embeddedIconUrl}
+          onExit={kiosk?.enabled ? handleKioskExit : undefined}
+          onPageChange={handlePageChange}
+          onInteraction={handleInteraction}
+          onActivity={handleActivity}
+          idleResetSignal={idleResetSignal}
+          remoteNavigation={remoteNavigation}
+          muted={muted}
+        />
+        {locale && locales.length > 1 && (
+          <LanguageSwitcher
+            locales={locales}
+            current={locale}
+            onChange={(next) => {
+              handleActivity()
+              setLocale(next)
+            }}
+          />
+        )}
+        {showPinDialog && (
+          <AdminPinDialog
+            onSubmit={(pin) => invoke('exit_kiosk', { pin })}
+            onCancel={() => setShowPinDialog(false)}
+          />
+        )}
+      </>
+    )
+  }
+
   // 일반 모드: ViewerPage 표시
   return <ViewerPage filePath={filePath} onFileSelect={handleFileSelect} />
 }
@@ -0,0 +1,82 @@
+import React, { useState } from 'react'
+
+// 여러 번 틀려 잠시 입력이 막혔을 때 player가 돌려주는 오류 (kiosk.rs와 동일)
+const PIN_THROTTLED_ERROR = 'Too many invalid admin PIN attempts'
+
+interface AdminPinDialogProps {
+  onSubmit: (pin: string) => Promise<void>
+  onCancel: () => void
+}
+
+/**
+ * 키오스크 모드 종료용 관리자 PIN 입력
+ */
+const AdminPinDialog: React.FC<AdminPinDialogProps> = ({
+  onSubmit,
+  onCancel,
+}) => {
+  const [pin, setPin] = useState('')
+  const [isChecking, setIsChecking] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault()
+    if (!pin) return
+
+    setIsChecking(true)
+    setError(null)
+
+    try {
+      await onSubmit(pin)
+    } catch (err) {
+      console.error('Kiosk exit failed:', err)
+      setError(
+        String(err) === PIN_THROTTLED_ERROR
+          ? 'PIN을 여러 번 틀렸습니다. 잠시 후 다시 시도하세요'
+          : 'PIN이 올바르지 않습니다'
+      )
+      setPin('')
+    } finally {
+      setIsChecking(false)
+    }
+  }
+
+  return (
+    <div className='fixed inset-0 z-50 flex items-center justify-center bg-black/70'>
+      <form
+        onSubmit={handleSubmit}
+        className='w-full max-w-xs space-y-3 rounded-xl bg-gray-900 p-6 text-white shadow-xl'
+      >
+        <h2 className='text-lg font-semibold'>관리자 PIN</h2>
+        <p className='text-sm text-gray-400'>종료하려면 PIN을 입력하세요</p>
+        <input
+          type='password'
+          inputMode='numeric'
+          value={pin}
+          onChange={(e) => setPin(e.target.value)}
+          autoFocus
+          className='w-full rounded-lg bg-gray-800 px-4 py-3 text-white focus:outline-none focus:ring-2 focus:ring-purple-500'
+        />
+        {error && <p className='text-center text-sm text-red-400'>{error}</p>}
+        <div className='flex gap-2'>
+          <button
+            type='button'
+            onClick={onCancel}
+            className='flex-1 rounded-lg bg-gray-700 px-4 py-3 transition-colors hover:bg-gray-600'
+          >
+            취소
+          </button>
+          <button
+            type='submit'
+            disabled={!pin || isChecking}
+            className='flex-1 rounded-lg bg-purple-600 px-4 py-3 font-semibold transition-colors hover:bg-purple-700 disabled:cursor-not-allowed disabled:opacity-50'
+          >
+            {isChecking ? '확인 중...' : '종료'}
+          </button>
+        </div>
+      </form>
+    </div>
+  )
+}
+
+export default AdminPinDialog
@@ -0,0 +1,35 @@
+import React from 'react'
+import { LOCALE_LABELS } from '@viswave/shared'
+
+interface LanguageSwitcherProps {
+  locales: string[]
+  current: string
+  onChange: (locale: string) => void
+}
+
+/**
+ * 다국어 튜토리얼의 언어 선택 버튼 (재생 중 화면 우측 하단)
+ */
+const LanguageSwitcher: React.FC<LanguageSwitcherProps> = ({
+  locales,
+  current,
+  onChange,
+}) => {
+  return (
+    <div className='fixed bottom-4 right-4 z-40 flex gap-1 rounded-lg bg-gray-800 bg-opacity-80 p-1 shadow-lg'>
+      {locales.map((locale) => (
+        <button
+          key={locale}
+          onClick={() => onChange(locale)}
+          className={`rounded px-3 py-1 text-sm text-white transition-colors ${
+            locale === current ? 'bg-purple-600' : 'hover:bg-gray-700'
+          }`}
+        >
+          {LOCALE_LABELS[locale] ?? locale}
+        </button>
+      ))}
+    </div>
+  )
+}
+
+export default LanguageSwitcher
@@ -0,0 +1,93 @@
+import { useState, useEffect } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+
+export interface KioskStatus {
+  enabled: boolean
+  requiresPin: boolean
+  hideCursorSeconds?: number
+}
+
+// 새로고침, 개발자 도구, 확대/축소 단축키
+const isBlockedShortcut = (e: KeyboardEvent) => {
+  if (e.key === 'F5' || e.key === 'F12') return true
+  if (!(e.ctrlKey || e.metaKey)) return false
+  const key = e.key.toLowerCase()
+  return (
+    key === 'r' ||
+    key === '+' ||
+    key === '=' ||
+    key === '-' ||
+    key === '0' ||
+    (e.shiftKey && (key === 'i' || key === 'j' || key === 'c'))
+  )
+}
+
+/**
+ * 키오스크 모드일 때 웹뷰 쪽 잠금 처리
+ * (창 닫기 차단/전체화면 유지는 백엔드에서 처리)
+ * - 우클릭 메뉴, 새로고침/개발자 도구/확대 단축키 차단
+ * - 입력이 없으면 커서 숨김
+ */
+export function useKioskLockdown(active: boolean) {
+  const [status, setStatus] = useState<KioskStatus | null>(null)
+
+  useEffect(() => {
+    if (!active) return
+
+    invoke<KioskStatus>('get_kiosk_status')
+      .then(setStatus)
+      .catch((err) => console.warn('Kiosk status check failed:', err))
+  }, [active])
+
+  // 우클릭 메뉴, 단축키, Ctrl+휠 확대 차단
+  useEffect(() => {
+    if (!status?.enabled) return
+
+    const preventContextMenu = (e: MouseEvent) => e.preventDefault()
+    const preventShortcut = (e: KeyboardEvent) => {
+      if (isBlockedShortcut(e)) {
+        e.preventDefault()
+        e.stopPropagation()
+      }
+    }
+    const preventZoom = (e: WheelEvent) => {
+      if (e.ctrlKey) e.preventDefault()
+    }
+
+    window.addEventListener('contextmenu', preventContextMenu)
+    window.addEventListener('keydown', preventShortcut, true)
+    window.addEventListener('wheel', preventZoom, { passive: false })
+    return () => {
+      window.removeEventListener('contextmenu', preventContextMenu)
+      window.removeEventListener('keydown', preventShortcut, true)
+      window.removeEventListener('wheel', preventZoom)
+    }
+  }, [status])
+
+  // 입력이 없으면 커서 숨김
+  useEffect(() => {
+    const hideAfter = status?.enabled ? status.hideCursorSeconds ?? 0 : 0
+    if (hideAfter <= 0) return
+
+    let timer: ReturnType<typeof setTimeout> | undefined
+    const showCursor = () => {
+      document.body.style.cursor = ''
+      clearTimeout(timer)
+      timer = setTimeout(() => {
+        document.body.style.cursor = 'none'
+      }, hideAfter * 1000)
+    }
+
+    showCursor()
+    window.addEventListener('pointermove', showCursor)
+    window.addEventListener('pointerdown', showCursor)
+    return () => {
+      clearTimeout(timer)
+      window.removeEventListener('pointermove', showCursor)
+      window.removeEventListener('pointerdown', showCursor)
+      document.body.style.cursor = ''
+    }
+  }, [status])
+
+  return status
+}
@@ -0,0 +1,55 @@
+import React, { useState } from 'react'
+
+export interface EmbeddedProjectSummary {
+  id: string
+  name: string
+}
+
+interface LauncherPageProps {
+  projects: EmbeddedProjectSummary[]
+  onSelect: (id: string) => Promise<void>
+}
+
+/**
+ * 여러 튜토리얼을 함께 넣은 실행 파일의 선택 화면
+ */
+const LauncherPage: React.FC<LauncherPageProps> = ({ projects, onSelect }) => {
+  const [selectingId, setSelectingId] = useState<string | null>(null)
+  const [error, setError] = useState<string | null>(null)
+
+  const handleSelect = async (id: string) => {
+    setSelectingId(id)
+    setError(null)
+
+    try {
+      await onSelect(id)
+    } catch (err) {
+      console.error('Project selection failed:', err)
+      setError('튜토리얼을 열 수 없습니다')
+      setSelectingId(null)
+    }
+  }
+
+  return (
+    <div className='flex min-h-screen flex-col items-center justify-center bg-gray-900 p-8 text-white'>
+      <h1 className='mb-8 text-2xl font-bold'>튜토리얼 선택</h1>
+
+      <div className='grid w-full max-w-3xl grid-cols-1 gap-4 sm:grid-cols-2'>
+        {projects.map((project) => (
+          <button
+            key={project.id}
+            onClick={() => handleSelect(project.id)}
+            disabled={selectingId !== null}
+            className='rounded-xl bg-gray-800 px-6 py-8 text-lg font-semibold transition-colors hover:bg-purple-600 disabled:cursor-not-allowed disabled:opacity-50'
+          >
+            {selectingId === project.id ? '여는 중...' : project.name}
+          </button>
+        ))}
+      </div>
+
+      {error && <p className='mt-6 text-sm text-red-400'>{error}</p>}
+    </div>
+  )
+}
+
+export default LauncherPage
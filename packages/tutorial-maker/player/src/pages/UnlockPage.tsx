@@ -0,0 +1,60 @@
+import React, { useState } from 'react'
+
+interface UnlockPageProps {
+  onUnlock: (passphrase: string) => Promise<void>
+}
+
+/**
+ * 암호화된 튜토리얼 잠금 해제 화면
+ */
+const UnlockPage: React.FC<UnlockPageProps> = ({ onUnlock }) => {
+  const [passphrase, setPassphrase] = useState('')
+  const [isUnlocking, setIsUnlocking] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault()
+    if (!passphrase) return
+
+    setIsUnlocking(true)
+    setError(null)
+
+    try {
+      await onUnlock(passphrase)
+    } catch (err) {
+      console.error('Unlock failed:', err)
+      setError('암호가 올바르지 않습니다')
+    } finally {
+      setIsUnlocking(false)
+    }
+  }
+
+  return (
+    <div className='flex min-h-screen flex-col items-center justify-center bg-gray-900 text-white'>
+      <h1 className='mb-2 text-2xl font-bold'>잠긴 튜토리얼</h1>
+      <p className='mb-8 text-sm text-gray-400'>
+        이 튜토리얼을 재생하려면 암호를 입력하세요
+      </p>
+
+      <form onSubmit={handleSubmit} className='w-full max-w-xs space-y-3'>
+        <input
+          type='password'
+          value={passphrase}
+          onChange={(e) => setPassphrase(e.target.value)}
+          autoFocus
+          className='w-full rounded-lg bg-gray-800 px-4 py-3 text-white focus:outline-none focus:ring-2 focus:ring-purple-500'
+        />
+        {error && <p className='text-center text-sm text-red-400'>{error}</p>}
+        <button
+          type='submit'
+          disabled={!passphrase || isUnlocking}
+          className='w-full rounded-lg bg-purple-600 px-4 py-3 font-semibold transition-colors hover:bg-purple-700 disabled:cursor-not-allowed disabled:opacity-50'
+        >
+          {isUnlocking ? '확인 중...' : '잠금 해제'}
+        </button>
+      </form>
+    </div>
+  )
+}
+
+export default UnlockPage
@@ -55,12 +55,20 @@ const ViewerPage: React.FC<ViewerPageProps> = ({ filePath, onFileSelect }) => {
 
   const [recentFiles, setRecentFiles] = React.useState<RecentFile[]>([])
   const [isExporting, setIsExporting] = useState(false)
+  const [machineId, setMachineId] = useState<string | null>(null)
 
   // 최근 파일 목록 로드
   React.useEffect(() => {
     setRecentFiles(viewerRecentFiles.getRecentFiles())
   }, [])
 
+  // 이 PC의 ID 로드 (라이선스 허용 PC 등록용)
+  React.useEffect(() => {
+    invoke<string | null>('get_machine_id')
+      .then(setMachineId)
+      .catch((err) => console.warn('Failed to get machine ID:', err))
+  }, [])
+
   // 파일 로드 성공 - 최근 파일에 추가
   React.useEffect(() => {
     if (filePath && project) {
@@ -260,6 +268,12 @@ const ViewerPage: React.FC<ViewerPageProps> = ({ filePath, onFileSelect }) => {
           .tutorial 또는 .zip 파일을 열 수 있습니다
         </p>
 
+        {machineId && (
+          <p className='mt-2 select-all font-mono text-xs text-gray-600'>
+            PC ID: {machineId}
+          </p>
+        )}
+
         <Footer className='mt-8' />
       </div>
     )
@@ -0,0 +1,354 @@
+use argon2::password_hash::{PasswordHasher, SaltString};
+use argon2::Argon2;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::{Read, Write};
+
+/// 암호화 청크 크기 (평문 기준 64KB)
+/// 청크 단위로 인증되므로 player가 필요한 구간만 복호화할 수 있음
+pub const CHUNK_SIZE: usize = 65536;
+
+/// 암호 알고리즘 식별자
+const CIPHER_NAME: &str = "chacha20poly1305";
+
+/// 솔트 크기
+const SALT_SIZE: usize = 16;
+
+/// AEAD 인증 태그 크기
+const TAG_SIZE: usize = 16;
+
+/// 영역 앞에 기록되는 nonce 접두사 크기
+/// 영역마다 무작위로 생성하므로 같은 키로 패치/압축해도 nonce가 겹치지 않음
+const NONCE_PREFIX_SIZE: usize = 8;
+
+/// 내장 키 난독화용 상수 (player와 동일해야 함)
+/// 보안 수단이 아니라 exe에서 키를 그대로 찾아낼 수 없게 하는 용도
+const KEY_OBFUSCATION_PEPPER: &[u8] = b"viswave-tutorial-content-key";
+
+/// 암호화 설정
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptionSettings {
+    /// 암호화 활성화 여부
+    pub enabled: bool,
+    /// 잠금 해제 암호 (없으면 키를 exe에 난독화하여 내장)
+    #[serde(default)]
+    pub passphrase: Option<String>,
+}
+
+/// 콘텐츠 키 출처
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeySource {
+    /// exe에 난독화되어 내장된 키
+    Embedded,
+    /// 실행 시 입력/전달되는 암호에서 유도한 키
+    Passphrase,
+}
+
+/// 암호화된 매니페스트 (VISTUT_E1 트레일러에 기록)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SealedManifest {
+    pub cipher: String,
+    pub chunk_size: u32,
+    pub key_source: KeySource,
+    /// 키 유도/난독화용 솔트 (base64)
+    pub salt: String,
+    /// 난독화된 콘텐츠 키 (base64, 내장 키 모드에서만)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub obfuscated_key: Option<String>,
+    /// 매니페스트 암호화 nonce (base64)
+    pub nonce: String,
+    /// 암호화된 BuildManifest JSON (base64)
+    pub manifest: String,
+    /// 콘텐츠 버전 (키 없이 새 버전인지 판단할 수 있도록 평문으로 기록)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_version: Option<u64>,
+}
+
+/// 빌드 단위 콘텐츠 암호화기
+pub struct ContentCipher {
+    cipher: ChaCha20Poly1305,
+    key_source: KeySource,
+    salt: [u8; SALT_SIZE],
+    obfuscated_key: Option<[u8; 32]>,
+}
+
+impl ContentCipher {
+    /// 설정에 따라 빌드용 키 생성
+    pub fn new(settings: &EncryptionSettings) -> Result<Self, String> {
+        let mut salt = [0u8; SALT_SIZE];
+        OsRng.fill_bytes(&mut salt);
+
+        let passphrase = settings.passphrase.as_deref().filter(|p| !p.is_empty());
+
+        let (key, key_source, obfuscated_key) = match passphrase {
+            Some(passphrase) => {
+                let mut key = [0u8; 32];
+                Argon2::default()
+                    .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+                    .map_err(|e| format!("Failed to derive content key: {}", e))?;
+                (key, KeySource::Passphrase, None)
+            }
+            None => {
+                let mut key = [0u8; 32];
+                OsRng.fill_bytes(&mut key);
+                let obfuscated = xor_key(&key, &obfuscation_mask(&salt));
+                (key, KeySource::Embedded, Some(obfuscated))
+            }
+        };
+
+        Ok(Self {
+            cipher: ChaCha20Poly1305::new(Key::from_slice(&key)),
+            key_source,
+            salt,
+            obfuscated_key,
+        })
+    }
+
+    /// 기존 빌드의 봉인된 매니페스트에서 키를 복원하고 매니페스트 JSON을 복호화
+    /// 같은 키/솔트를 유지하므로 패치 후에도 기존 암호로 잠금 해제 가능
+    pub fn open_sealed(
+        sealed_json: &[u8],
+        passphrase: Option<&str>,
+    ) -> Result<(Self, Vec<u8>), String> {
+        let sealed: SealedManifest = serde_json::from_slice(sealed_json)
+            .map_err(|e| format!("Failed to parse sealed manifest: {}", e))?;
+
+        if sealed.cipher != CIPHER_NAME {
+            return Err(format!("Unsupported cipher: {}", sealed.cipher));
+        }
+        if sealed.chunk_size as usize != CHUNK_SIZE {
+            return Err(format!("Unsupported chunk size: {}", sealed.chunk_size));
+        }
+
+        let salt: [u8; SALT_SIZE] = decode_base64(&sealed.salt, "salt")?
+            .try_into()
+            .map_err(|_| "Invalid salt length".to_string())?;
+
+        let (key, obfuscated_key) = match sealed.key_source {
+            KeySource::Embedded => {
+                let obfuscated: [u8; 32] = sealed
+                    .obfuscated_key
+                    .as_deref()
+                    .ok_or_else(|| "Embedded key is missing".to_string())
+                    .and_then(|k| decode_base64(k, "embedded key"))?
+                    .try_into()
+                    .map_err(|_| "Invalid embedded key length".to_string())?;
+                (xor_key(&obfuscated, &obfuscation_mask(&salt)), Some(obfuscated))
+            }
+            KeySource::Passphrase => {
+                let passphrase = passphrase
+                    .filter(|p| !p.is_empty())
+                    .ok_or_else(|| "Passphrase is required to update this build".to_string())?;
+                let mut key = [0u8; 32];
+                Argon2::default()
+                    .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+                    .map_err(|e| format!("Failed to derive content key: {}", e))?;
+                (key, None)
+            }
+        };
+
+        let cipher = Self {
+            cipher: ChaCha20Poly1305::new(Key::from_slice(&key)),
+            key_source: sealed.key_source,
+            salt,
+            obfuscated_key,
+        };
+
+        let nonce = decode_base64(&sealed.nonce, "manifest nonce")?;
+        if nonce.len() != 12 {
+            return Err("Invalid manifest nonce length".to_string());
+        }
+        let manifest = cipher
+            .cipher
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                decode_base64(&sealed.manifest, "manifest")?.as_slice(),
+            )
+            .map_err(|_| "Invalid content key".to_string())?;
+
+        Ok((cipher, manifest))
+    }
+
+    /// 소스를 청크 단위로 암호화하여 출력에 쓰고 기록된 바이트 수를 반환
+    /// 영역 앞에 무작위 nonce 접두사를 기록하므로 영역을 다른 위치로 옮겨도 그대로 복사 가능
+    pub fn encrypt_region<R: Read, W: Write>(
+        &self,
+        source: &mut R,
+        output: &mut W,
+    ) -> Result<u64, String> {
+        let mut prefix = [0u8; NONCE_PREFIX_SIZE];
+        OsRng.fill_bytes(&mut prefix);
+        output
+            .write_all(&prefix)
+            .map_err(|e| format!("Failed to write encrypted data: {}", e))?;
+
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+        let mut total_written = NONCE_PREFIX_SIZE as u64;
+        let mut index: u32 = 0;
+
+        loop {
+            let bytes_read = read_full(source, &mut buffer)
+                .map_err(|e| format!("Failed to read source data: {}", e))?;
+
+            if bytes_read == 0 {
+                break;
+            }
+
+            let sealed = self
+                .cipher
+                .encrypt(&chunk_nonce(&prefix, index), &buffer[..bytes_read])
+                .map_err(|_| "Failed to encrypt payload chunk".to_string())?;
+
+            output
+                .write_all(&sealed)
+                .map_err(|e| format!("Failed to write encrypted data: {}", e))?;
+
+            total_written += sealed.len() as u64;
+            index += 1;
+
+            if bytes_read < CHUNK_SIZE {
+                break;
+            }
+        }
+
+        Ok(total_written)
+    }
+
+    /// 암호화된 영역을 복호화하여 평문을 출력에 쓰기 (내보내기 검증용)
+    /// 모든 청크의 인증 태그가 확인되므로 손상된 영역은 에러가 됨
+    pub fn decrypt_region<R: Read, W: Write>(
+        &self,
+        stored_size: u64,
+        source: &mut R,
+        output: &mut W,
+    ) -> Result<(), String> {
+        let mut prefix = [0u8; NONCE_PREFIX_SIZE];
+        if stored_size < NONCE_PREFIX_SIZE as u64 {
+            return Err("Encrypted region is too small".to_string());
+        }
+        source
+            .read_exact(&mut prefix)
+            .map_err(|e| format!("Failed to read nonce prefix: {}", e))?;
+
+        let sealed_chunk = (CHUNK_SIZE + TAG_SIZE) as u64;
+        let mut buffer = vec![0u8; CHUNK_SIZE + TAG_SIZE];
+        let mut remaining = stored_size - NONCE_PREFIX_SIZE as u64;
+        let mut index: u32 = 0;
+
+        while remaining > 0 {
+            let chunk_len = sealed_chunk.min(remaining) as usize;
+            source
+                .read_exact(&mut buffer[..chunk_len])
+                .map_err(|e| format!("Failed to read encrypted chunk: {}", e))?;
+
+            let plain = self
+                .cipher
+                .decrypt(&chunk_nonce(&prefix, index), &buffer[..chunk_len])
+                .map_err(|_| "Encrypted content is corrupted or the key is invalid".to_string())?;
+            output
+                .write_all(&plain)
+                .map_err(|e| format!("Failed to write decrypted data: {}", e))?;
+
+            remaining -= chunk_len as u64;
+            index += 1;
+        }
+
+        Ok(())
+    }
+
+    /// 매니페스트 JSON을 암호화하여 트레일러에 기록할 JSON 반환 (콘텐츠 버전은 평문으로 함께 기록)
+    pub fn seal_manifest(
+        &self,
+        manifest_json: &[u8],
+        content_version: Option<u64>,
+    ) -> Result<String, String> {
+        let mut nonce = [0u8; 12];
+        OsRng.fill_bytes(&mut nonce);
+
+        let sealed = self
+            .cipher
+            .encrypt(Nonce::from_slice(&nonce), manifest_json)
+            .map_err(|_| "Failed to encrypt manifest".to_string())?;
+
+        let envelope = SealedManifest {
+            cipher: CIPHER_NAME.to_string(),
+            chunk_size: CHUNK_SIZE as u32,
+            key_source: self.key_source,
+            salt: BASE64.encode(self.salt),
+            obfuscated_key: self.obfuscated_key.map(|k| BASE64.encode(k)),
+            nonce: BASE64.encode(nonce),
+            manifest: BASE64.encode(sealed),
+            content_version,
+        };
+
+        serde_json::to_string(&envelope)
+            .map_err(|e| format!("Failed to serialize sealed manifest: {}", e))
+    }
+}
+
+/// 키오스크 관리자 PIN 해시 (솔트가 포함된 Argon2 PHC 문자열, player가 확인)
+pub fn hash_admin_pin(pin: &str) -> Result<String, String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(pin.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| format!("Failed to hash admin PIN: {}", e))
+}
+
+/// 평문 크기에 대한 암호화 후 크기 (nonce 접두사와 청크마다 인증 태그가 붙음)
+pub fn sealed_size(plain_size: u64) -> u64 {
+    let chunks = plain_size.div_ceil(CHUNK_SIZE as u64);
+    NONCE_PREFIX_SIZE as u64 + plain_size + chunks * TAG_SIZE as u64
+}
+
+/// 청크 nonce 생성: 영역의 무작위 접두사(8바이트) + 청크 인덱스(4바이트)
+/// 패치/압축은 기존 키를 유지하므로 오프셋 대신 영역마다 새로 뽑은 접두사로 nonce를 구분함
+/// (같은 키로 2^32개 영역을 넘게 쓰기 전까지 접두사 충돌 확률은 무시할 수 있음)
+fn chunk_nonce(prefix: &[u8; NONCE_PREFIX_SIZE], index: u32) -> Nonce {
+    let mut nonce = [0u8; 12];
+    nonce[..8].copy_from_slice(prefix);
+    nonce[8..].copy_from_slice(&index.to_be_bytes());
+    *Nonce::from_slice(&nonce)
+}
+
+/// 내장 키 난독화 마스크
+fn obfuscation_mask(salt: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(KEY_OBFUSCATION_PEPPER);
+    hasher.update(salt);
+    hasher.finalize().into()
+}
+
+fn xor_key(key: &[u8; 32], mask: &[u8; 32]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = key[i] ^ mask[i];
+    }
+    out
+}
+
+fn decode_base64(value: &str, field: &str) -> Result<Vec<u8>, String> {
+    BASE64
+        .decode(value)
+        .map_err(|e| format!("Invalid {} in sealed manifest: {}", field, e))
+}
+
+/// 버퍼가 가득 차거나 EOF가 될 때까지 읽기
+fn read_full<R: Read>(source: &mut R, buffer: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        let n = source.read(&mut buffer[filled..])?;
+        if n == 0 {
+            break;
+        }
+        filled += n;
+    }
+    Ok(filled)
+}
@@ -0,0 +1,339 @@
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use log::LevelFilter;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 로그 수준을 지정하는 환경 변수 (--log-level 인자가 우선)
+pub const LOG_LEVEL_ENV: &str = "VISTUT_LOG_LEVEL";
+
+/// 실행 인자나 환경 변수가 없을 때의 로그 수준
+pub const DEFAULT_LOG_LEVEL: LevelFilter = LevelFilter::Info;
+
+/// 로그 파일 이름 (maker.log, 최대 크기를 넘으면 날짜를 붙여 보관)
+pub const LOG_FILE_NAME: &str = "maker";
+
+/// 로그 파일 하나의 최대 크기
+pub const MAX_LOG_FILE_BYTES: u128 = 5 * 1024 * 1024;
+
+/// 보관할 로그 파일 수
+pub const KEEP_LOG_FILES: usize = 5;
+
+/// 로그 수준 이름 해석 (off, error, warn, info, debug, trace)
+pub fn parse_log_level(value: &str) -> Result<LevelFilter, String> {
+    value
+        .trim()
+        .parse::<LevelFilter>()
+        .map_err(|_| format!("Invalid log level: {}", value))
+}
+
+/// 실행 시 지정한 로그 수준 (--log-level 인자 또는 환경 변수)
+/// 로거 초기화 전에 호출되므로 잘못된 값은 에러로 돌려주고 호출하는 쪽에서 기록
+pub fn launch_log_level() -> Result<Option<LevelFilter>, String> {
+    let prefix = "--log-level=";
+    let mut args = std::env::args().skip(1);
+    let mut from_cli = None;
+    while let Some(arg) = args.next() {
+        if arg == "--log-level" {
+            from_cli = args.next();
+        } else if let Some(value) = arg.strip_prefix(prefix) {
+            from_cli = Some(value.to_string());
+        }
+    }
+    match from_cli.or_else(|| std::env::var(LOG_LEVEL_ENV).ok()) {
+        Some(value) => parse_log_level(&value).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// 진단 번들의 환경 정보 (environment.json)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MakerEnvironment {
+    pub created_at_ms: u64,
+    pub maker_version: &'static str,
+    pub os: &'static str,
+    pub arch: &'static str,
+    pub os_version: Option<String>,
+    pub exe_path: Option<String>,
+    /// VISTUT_로 시작하는 환경 변수
+    pub variables: BTreeMap<String, String>,
+    pub log_dir: String,
+    pub temp_dir: String,
+    pub ffmpeg_path: Option<String>,
+    /// ffmpeg -version의 첫 줄
+    pub ffmpeg_version: Option<String>,
+    pub rcedit_path: Option<String>,
+    /// 도구를 찾지 못한 이유
+    pub tool_errors: Vec<String>,
+}
+
+impl MakerEnvironment {
+    /// 현재 실행 환경 (도구 경로는 호출하는 쪽에서 채움)
+    pub fn current(log_dir: &Path) -> Self {
+        Self {
+            created_at_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_millis() as u64)
+                .unwrap_or_default(),
+            maker_version: env!("CARGO_PKG_VERSION"),
+            os: std::env::consts::OS,
+            arch: std::env::consts::ARCH,
+            os_version: os_version(),
+            exe_path: std::env::current_exe()
+                .ok()
+                .map(|path| path.display().to_string()),
+            variables: std::env::vars()
+                .filter(|(name, _)| name.starts_with("VISTUT_"))
+                .collect(),
+            log_dir: log_dir.display().to_string(),
+            temp_dir: std::env::temp_dir().display().to_string(),
+            ffmpeg_path: None,
+            ffmpeg_version: None,
+            rcedit_path: None,
+            tool_errors: Vec::new(),
+        }
+    }
+}
+
+/// 폴더의 파일 목록 (번들 안의 이름, 경로)
+pub fn log_files(dir: &Path, prefix: &str) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(String, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .map(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            (format!("{}/{}", prefix, name), path)
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// 파일과 환경 정보를 zip으로 저장 (없는 파일은 건너뜀, 넣은 파일 수 반환)
+pub fn write_bundle(
+    output_path: &Path,
+    files: &[(String, PathBuf)],
+    environment: &impl Serialize,
+) -> Result<usize, String> {
+    let environment = serde_json::to_vec_pretty(environment)
+        .map_err(|e| format!("Failed to serialize environment: {}", e))?;
+    let output = File::create(output_path)
+        .map_err(|e| format!("Failed to create {:?}: {}", output_path, e))?;
+
+    let mut zip = ZipWriter::new(BufWriter::new(output));
+    zip.add("environment.json", &environment, SystemTime::now())?;
+    let mut count = 1;
+    for (name, path) in files {
+        let data = match fs::read(path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => {
+                log::warn!("Diagnostics file skipped {:?}: {}", path, e);
+                continue;
+            }
+        };
+        let modified = fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .unwrap_or_else(|_| SystemTime::now());
+        zip.add(name, &data, modified)?;
+        count += 1;
+    }
+    zip.finish()?;
+    Ok(count)
+}
+
+/// zip 로컬 파일 헤더
+const LOCAL_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+/// zip 중앙 디렉터리 헤더
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+/// zip 중앙 디렉터리 끝
+const END_OF_CENTRAL_SIGNATURE: u32 = 0x0605_4b50;
+/// 압축 해제에 필요한 버전 (2.0, deflate)
+const ZIP_VERSION: u16 = 20;
+/// 파일 이름이 UTF-8임을 표시
+const UTF8_NAME_FLAG: u16 = 0x0800;
+const DEFLATE_METHOD: u16 = 8;
+
+/// 진단 번들용 zip 작성기 (deflate, zip64는 지원하지 않음, 로그 위주라 4GB를 넘지 않음)
+struct ZipWriter<W: Write> {
+    output: W,
+    offset: u64,
+    central: Vec<u8>,
+    count: u16,
+}
+
+impl<W: Write> ZipWriter<W> {
+    fn new(output: W) -> Self {
+        Self {
+            output,
+            offset: 0,
+            central: Vec::new(),
+            count: 0,
+        }
+    }
+
+    fn add(&mut self, name: &str, data: &[u8], modified: SystemTime) -> Result<(), String> {
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(data)
+            .map_err(|e| format!("Failed to compress {}: {}", name, e))?;
+        let compressed = encoder
+            .finish()
+            .map_err(|e| format!("Failed to compress {}: {}", name, e))?;
+
+        let too_large = || format!("Diagnostics bundle too large at {}", name);
+        let size = u32::try_from(data.len()).map_err(|_| too_large())?;
+        let compressed_size = u32::try_from(compressed.len()).map_err(|_| too_large())?;
+        let offset = u32::try_from(self.offset).map_err(|_| too_large())?;
+        let name_length = u16::try_from(name.len()).map_err(|_| too_large())?;
+        let (time, date) = dos_date_time(modified);
+
+        // 로컬 헤더와 중앙 디렉터리에 같은 순서로 들어가는 필드
+        let mut fields = Vec::with_capacity(24);
+        for value in [ZIP_VERSION, UTF8_NAME_FLAG, DEFLATE_METHOD, time, date] {
+            fields.extend_from_slice(&value.to_le_bytes());
+        }
+        for value in [crc32fast::hash(data), compressed_size, size] {
+            fields.extend_from_slice(&value.to_le_bytes());
+        }
+        fields.extend_from_slice(&name_length.to_le_bytes());
+
+        let mut header = Vec::with_capacity(30 + name.len());
+        header.extend_from_slice(&LOCAL_HEADER_SIGNATURE.to_le_bytes());
+        header.extend_from_slice(&fields);
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(name.as_bytes());
+        self.write(&header)?;
+        self.write(&compressed)?;
+
+        let central = &mut self.central;
+        central.extend_from_slice(&CENTRAL_HEADER_SIGNATURE.to_le_bytes());
+        central.extend_from_slice(&ZIP_VERSION.to_le_bytes());
+        central.extend_from_slice(&fields);
+        // 추가 필드, 주석, 디스크 번호, 내부 속성 (모두 0), 외부 속성 (0)
+        central.extend_from_slice(&[0u8; 12]);
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+
+        self.count = self
+            .count
+            .checked_add(1)
+            .ok_or("Too many files in diagnostics bundle")?;
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<W, String> {
+        let too_large = || "Diagnostics bundle too large".to_string();
+        let central_offset = u32::try_from(self.offset).map_err(|_| too_large())?;
+        let central_size = u32::try_from(self.central.len()).map_err(|_| too_large())?;
+        let central = std::mem::take(&mut self.central);
+        self.write(&central)?;
+
+        let mut end = Vec::with_capacity(22);
+        end.extend_from_slice(&END_OF_CENTRAL_SIGNATURE.to_le_bytes());
+        for value in [0, 0, self.count, self.count] {
+            end.extend_from_slice(&u16::to_le_bytes(value));
+        }
+        end.extend_from_slice(&central_size.to_le_bytes());
+        end.extend_from_slice(&central_offset.to_le_bytes());
+        end.extend_from_slice(&0u16.to_le_bytes());
+        self.write(&end)?;
+
+        self.output
+            .flush()
+            .map_err(|e| format!("Failed to write diagnostics bundle: {}", e))?;
+        Ok(self.output)
+    }
+
+    fn write(&mut self, data: &[u8]) -> Result<(), String> {
+        self.output
+            .write_all(data)
+            .map_err(|e| format!("Failed to write diagnostics bundle: {}", e))?;
+        self.offset += data.len() as u64;
+        Ok(())
+    }
+}
+
+/// zip에 기록할 수정 시각 (MS-DOS 형식, UTC 기준, 1980년 이전은 1980-01-01)
+fn dos_date_time(modified: SystemTime) -> (u16, u16) {
+    let seconds = modified
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or_default();
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    if year < 1980 {
+        return (0, (1 << 5) | 1);
+    }
+
+    let of_day = seconds.rem_euclid(86_400);
+    let time = ((of_day / 3600) << 11) | ((of_day % 3600 / 60) << 5) | (of_day % 60 / 2);
+    let date = ((year - 1980).min(127) << 9) | (month << 5) | day;
+    (time as u16, date as u16)
+}
+
+/// 1970-01-01 기준 일수를 (년, 월, 일)로 변환 (Howard Hinnant의 civil_from_days)
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// 운영체제 버전 (Windows)
+#[cfg(target_os = "windows")]
+fn os_version() -> Option<String> {
+    use std::os::windows::process::CommandExt;
+    use std::process::Command;
+
+    /// 콘솔 창을 띄우지 않고 실행
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    // "Microsoft Windows [Version 10.0.19045.4291]" 형식
+    let output = Command::new("cmd")
+        .args(["/C", "ver"])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .ok()?;
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!version.is_empty()).then_some(version)
+}
+
+/// 운영체제 버전 (macOS)
+#[cfg(target_os = "macos")]
+fn os_version() -> Option<String> {
+    use std::process::Command;
+
+    let output = Command::new("sw_vers").arg("-productVersion").output().ok()?;
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!version.is_empty()).then(|| format!("macOS {}", version))
+}
+
+/// 운영체제 버전 (Linux 등, /etc/os-release의 PRETTY_NAME)
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn os_version() -> Option<String> {
+    fs::read_to_string("/etc/os-release")
+        .ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix("PRETTY_NAME="))
+        .map(|name| name.trim_matches('"').to_string())
+}
@@ -1,14 +1,31 @@
+use crate::crypto::ContentCipher;
+use crate::error::BuildError;
+use crate::license::{BuildSigner, LicenseSettings, SIGNATURE_SIZE};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, File};
-use std::io::{Read, Write};
-use std::path::Path;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
 
 /// 매직 바이트 - 임베딩된 데이터 식별용
 const MAGIC_BYTES: &[u8] = b"VISTUT_V1";
 
+/// 매직 바이트 - 암호화된 임베딩 데이터 식별용 (매니페스트가 SealedManifest)
+const MAGIC_BYTES_SEALED: &[u8] = b"VISTUT_E1";
+
+/// 매직 바이트 - 서명된 임베딩 데이터 (매니페스트 뒤에 Ed25519 서명)
+const MAGIC_BYTES_SIGNED: &[u8] = b"VISTUT_V2";
+
+/// 매직 바이트 - 서명된 암호화 임베딩 데이터
+const MAGIC_BYTES_SEALED_SIGNED: &[u8] = b"VISTUT_E2";
+
 /// 스트리밍 읽기 버퍼 크기 (64KB)
 const STREAM_BUFFER_SIZE: usize = 65536;
 
+/// 페이로드 zstd 압축 레벨
+const PAYLOAD_ZSTD_LEVEL: i32 = 19;
+
 /// 빌드 시점에 viewer.exe를 임베드
 const VIEWER_EXE: &[u8] = include_bytes!(env!("VIEWER_EXE_PATH"));
 
@@ -18,6 +35,13 @@ pub enum MediaSource {
     Path(String),
 }
 
+/// 임베딩된 엔트리의 저장 인코딩 (없으면 원본 그대로 저장)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PayloadEncoding {
+    Zstd,
+}
+
 /// 미디어 파일 매니페스트 엔트리
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -26,165 +50,1576 @@ pub struct MediaManifestEntry {
     pub name: String,
     pub mime_type: String,
     pub offset: u64,
+    /// 저장된 바이트 수 (압축된 경우 압축 후 크기)
     pub size: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<PayloadEncoding>,
+    /// 압축 전 원본 크기 (압축된 경우에만 기록)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_size: Option<u64>,
+    /// 변환 전 원본 소스의 SHA-256 (패치 시 변경 여부 판단용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_hash: Option<String>,
+}
+
+/// 여러 튜토리얼을 함께 넣은 빌드의 프로젝트 엔트리
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectManifestEntry {
+    pub id: String,
+    /// 선택 화면에 표시할 이름 (앱 타이틀 또는 프로젝트 이름)
+    pub name: String,
+    pub offset: u64,
+    pub size: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<PayloadEncoding>,
+    /// 자동으로 재생할 시간 조건 (하나라도 맞으면 재생, 비어 있으면 자동 선택하지 않음)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schedule: Vec<ScheduleRule>,
+}
+
+/// 프로젝트를 자동으로 재생할 시간 조건 (플레이어 PC의 현지 시각 기준)
+/// 지정하지 않은 항목은 제한하지 않음
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleRule {
+    /// 요일 (0 = 일요일 ~ 6 = 토요일)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub weekdays: Vec<u8>,
+    /// 시작/종료 시각 ("HH:MM", 종료가 시작보다 이르면 자정을 넘김)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<String>,
+    /// 시작/종료 날짜 ("YYYY-MM-DD", 종료일 포함)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+}
+
+impl ScheduleRule {
+    /// 빌드 전에 형식 확인 (플레이어는 잘못된 조건을 무시하므로 미리 알림)
+    fn validate(&self) -> Result<(), String> {
+        if let Some(day) = self.weekdays.iter().find(|day| **day > 6) {
+            return Err(format!("Invalid weekday: {}", day));
+        }
+        for time in self.start_time.iter().chain(self.end_time.iter()) {
+            let valid = time.split_once(':').is_some_and(|(hour, minute)| {
+                hour.parse::<u32>().is_ok_and(|hour| hour < 24)
+                    && minute.len() == 2
+                    && minute.parse::<u32>().is_ok_and(|minute| minute < 60)
+            });
+            if !valid {
+                return Err(format!("Invalid time: {}", time));
+            }
+        }
+        for date in self.start_date.iter().chain(self.end_date.iter()) {
+            let parts: Vec<&str> = date.split('-').collect();
+            let valid = parts.len() == 3
+                && parts[0].len() == 4
+                && parts[1].len() == 2
+                && parts[2].len() == 2
+                && parts.iter().all(|part| part.parse::<u32>().is_ok());
+            if !valid {
+                return Err(format!("Invalid date: {}", date));
+            }
+        }
+        Ok(())
+    }
 }
 
 /// 빌드 매니페스트
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BuildManifest {
+    /// 기본 프로젝트 (여러 프로젝트면 첫 번째, 이전 버전 뷰어는 이것만 재생)
     pub project_json_offset: u64,
     pub project_json_size: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_json_encoding: Option<PayloadEncoding>,
+    /// 함께 넣은 모든 프로젝트 (2개 이상일 때만 기록, 미디어는 공유)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<ProjectManifestEntry>,
+    /// 함께 넣은 프로젝트가 제공하는 언어 (다국어 프로젝트가 없으면 비어 있음)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locales: Vec<String>,
     pub media: Vec<MediaManifestEntry>,
     pub buttons: Vec<MediaManifestEntry>,
     pub app_icon_offset: Option<u64>,
     pub app_icon_size: Option<u64>,
+    /// 앱 아이콘 저장 인코딩 (ICO/BMP 등 무압축 비트맵은 zstd로 줄어듦)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_icon_encoding: Option<PayloadEncoding>,
+    /// 라이선스 조건 (사용 기간/허용 PC 제한, 트레일러 서명으로 보호)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<LicenseSettings>,
+    /// 임베딩 데이터 시작 오프셋 (viewer exe의 크기, 압축 시 기준)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_offset: Option<u64>,
+    /// 콘텐츠 버전 (빌드/패치 시각, ms, 핫 폴더 업데이트에서 새 버전 판단용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_version: Option<u64>,
+    /// 기본 프로젝트 ID (핫 폴더 업데이트가 같은 프로젝트의 콘텐츠인지 확인용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// payload_offset부터 매니페스트 앞까지의 SHA-256 (hex)
+    /// 서명된 매니페스트에 들어가므로 엔트리 데이터도 서명으로 보호됨
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_sha256: Option<String>,
+}
+
+/// 내장된 viewer.exe 크기 (빌드 전 용량 예측용)
+pub fn viewer_executable_size() -> u64 {
+    VIEWER_EXE.len() as u64
+}
+
+/// 내장된 viewer.exe가 이 공개 키(base64)를 신뢰하도록 빌드되었는지 확인
+/// (player는 빌드 시 VISTUT_LICENSE_PUBLIC_KEY 문자열을 그대로 포함함)
+pub fn viewer_trusts_key(public_key: &str) -> bool {
+    VIEWER_EXE
+        .windows(public_key.len())
+        .any(|window| window == public_key.as_bytes())
 }
 
 /// 기본 실행 파일 생성 (viewer.exe만 복사)
 /// rcedit로 아이콘 설정 전에 호출해야 함
-pub fn prepare_base_executable(output_path: &Path) -> Result<(), String> {
+pub fn prepare_base_executable(output_path: &Path) -> Result<(), BuildError> {
     fs::write(output_path, VIEWER_EXE)
-        .map_err(|e| format!("Failed to write viewer exe: {}", e))
+        .map_err(|e| BuildError::io("writeExecutable", Some(output_path), e))
+}
+
+/// 임베딩할 프로젝트
+pub struct EmbedProject {
+    pub id: String,
+    pub name: String,
+    pub json: String,
+    /// 프로젝트 설정의 재생 일정 (settings.schedule)
+    pub schedule: Vec<ScheduleRule>,
+    /// 프로젝트 설정의 제공 언어 (settings.locales)
+    pub locales: Vec<String>,
+}
+
+impl EmbedProject {
+    /// 프로젝트 JSON에서 ID, 표시 이름(앱 타이틀 또는 프로젝트 이름), 재생 일정, 언어 읽기
+    pub fn from_json(json: String) -> Result<Self, BuildError> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ProjectInfo {
+            id: String,
+            #[serde(default)]
+            name: String,
+            #[serde(default)]
+            app_title: String,
+            #[serde(default)]
+            settings: Option<SettingsInfo>,
+        }
+
+        #[derive(Default, Deserialize)]
+        struct SettingsInfo {
+            #[serde(default)]
+            schedule: Vec<ScheduleRule>,
+            #[serde(default)]
+            locales: Vec<String>,
+        }
+
+        let info: ProjectInfo = serde_json::from_str(&json).map_err(|e| {
+            BuildError::InvalidProject {
+                detail: e.to_string(),
+            }
+        })?;
+        let name = if info.app_title.is_empty() {
+            info.name
+        } else {
+            info.app_title
+        };
+
+        let settings = info.settings.unwrap_or_default();
+        let schedule = settings.schedule;
+        for rule in &schedule {
+            rule.validate().map_err(|detail| BuildError::InvalidSchedule {
+                project: name.clone(),
+                detail,
+            })?;
+        }
+
+        Ok(Self {
+            id: info.id,
+            name,
+            json,
+            schedule,
+            locales: settings.locales,
+        })
+    }
+}
+
+/// 임베딩할 파일
+pub struct EmbedFile {
+    pub id: String,
+    pub name: String,
+    pub mime_type: String,
+    pub source: MediaSource,
+    /// 변환(영상 압축 등) 전 원본 소스의 SHA-256 (패치 시 변경 여부 판단용)
+    pub source_hash: Option<String>,
+}
+
+/// 패치 시 엔트리 처리 방식
+pub enum PatchEntry {
+    /// 기존 영역을 그대로 참조
+    Keep(MediaManifestEntry),
+    /// 새로 기록
+    Write(EmbedFile),
+}
+
+/// 데이터 팩 분리 설정 (Export 요청)
+/// FAT32(파일당 4GB 제한) 배포나 대용량 exe를 검사하지 못하는 백신을 위해 사용
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitSettings {
+    /// exe 대신 옆의 데이터 팩에 기록
+    pub enabled: bool,
+    /// 볼륨 크기 (MB, 없으면 하나의 .pak 파일)
+    #[serde(default)]
+    pub volume_size_mb: Option<u64>,
+}
+
+/// 임베딩 데이터를 기록할 위치
+pub enum EmbedTarget<'p> {
+    /// exe 끝에 추가 (단일 파일 배포)
+    Executable(&'p Path),
+    /// exe 옆의 데이터 팩 (volume_size가 있으면 번호가 붙은 볼륨 파일로 분할)
+    Pack {
+        path: &'p Path,
+        volume_size: Option<u64>,
+    },
+}
+
+/// 임베딩 결과
+pub struct EmbedOutcome {
+    /// 정리할 임시 파일 경로
+    pub temp_files: Vec<String>,
+    /// 내용이 같은 엔트리를 한 번만 저장하여 절약한 바이트 수
+    pub deduplicated_bytes: u64,
+    /// 기록된 데이터 팩 파일 (exe에 추가한 경우 비어 있음)
+    pub pack_files: Vec<PathBuf>,
+    /// 기록된 매니페스트 (빌드 보고서 작성용)
+    pub manifest: BuildManifest,
+}
+
+/// 패치 결과
+pub struct PatchOutcome {
+    /// 정리할 임시 파일 경로
+    pub temp_files: Vec<String>,
+    /// 내용이 같은 엔트리를 한 번만 저장하여 절약한 바이트 수
+    pub deduplicated_bytes: u64,
+    /// 새로 기록한 엔트리 수
+    pub written_entries: usize,
+    /// 기존 영역을 재사용한 엔트리 수
+    pub reused_entries: usize,
+    /// 파일 끝에 추가된 바이트 수
+    pub appended_bytes: u64,
+}
+
+/// 트레일러 보호 키 (둘 다 없으면 평문, 서명 없는 트레일러)
+#[derive(Clone, Copy, Default)]
+pub struct TrailerKeys<'a> {
+    /// 엔트리와 매니페스트 암호화
+    pub cipher: Option<&'a ContentCipher>,
+    /// 매니페스트 서명 (player가 공개 키로 확인)
+    pub signer: Option<&'a BuildSigner>,
 }
 
 /// 임베딩 데이터 추가 (아이콘 설정 후 호출)
-/// 현재 파일 크기를 기준으로 오프셋 계산
+/// exe에 추가하면 현재 파일 크기를, 데이터 팩이면 0을 기준으로 오프셋 계산
+/// 데이터 팩도 exe 트레일러와 같은 레이아웃(데이터 + 매니페스트 + 매직 바이트)으로 기록
+/// cipher가 있으면 모든 엔트리와 매니페스트를 암호화하고, signer가 있으면 매니페스트에 서명
+/// 내용이 같은 미디어/버튼 이미지는 한 영역만 저장하고 여러 엔트리가 이를 가리킴
+/// 프로젝트가 여럿이면 미디어를 공유하고 첫 번째 프로젝트가 기본 프로젝트
 pub fn append_embedded_data(
-    output_path: &Path,
-    project_json: &str,
-    media_files: Vec<(String, String, String, MediaSource)>, // (id, name, mime_type, source)
-    button_files: Vec<(String, String, String, MediaSource)>,
+    target: EmbedTarget,
+    projects: &[EmbedProject],
+    media_files: Vec<EmbedFile>,
+    button_files: Vec<EmbedFile>,
     app_icon: Option<Vec<u8>>,
-) -> Result<Vec<String>, String> {
-    // 정리할 임시 파일 경로 수집
-    let mut temp_files_to_cleanup: Vec<String> = Vec::new();
+    keys: TrailerKeys,
+    license: Option<LicenseSettings>,
+) -> Result<EmbedOutcome, BuildError> {
+    let media_files: Vec<_> = media_files.into_iter().map(PatchEntry::Write).collect();
+    let button_files: Vec<_> = button_files.into_iter().map(PatchEntry::Write).collect();
 
-    // 현재 파일 크기를 시작 오프셋으로 사용 (rcedit가 파일을 수정했을 수 있음)
-    let current_file_size = fs::metadata(output_path)
-        .map_err(|e| format!("Failed to get file metadata: {}", e))?
-        .len();
+    match target {
+        EmbedTarget::Executable(output_path) => {
+            // 현재 파일 크기를 시작 오프셋으로 사용 (rcedit가 파일을 수정했을 수 있음)
+            let current_file_size = fs::metadata(output_path)
+                .map_err(|e| BuildError::io("readMetadata", Some(output_path), e))?
+                .len();
 
-    // 출력 파일 열기 (append 모드)
-    let mut file = fs::OpenOptions::new()
-        .append(true)
-        .open(output_path)
-        .map_err(|e| format!("Failed to open output file: {}", e))?;
+            // 출력 파일 열기 (append 모드)
+            let file = fs::OpenOptions::new()
+                .append(true)
+                .open(output_path)
+                .map_err(|e| BuildError::io("openExecutable", Some(output_path), e))?;
 
-    let mut current_offset = current_file_size;
+            let mut writer = TrailerWriter::new(file, current_file_size, keys);
+            let outcome = write_embedded(
+                &mut writer,
+                current_file_size,
+                projects,
+                media_files,
+                button_files,
+                app_icon,
+                license,
+            )?;
+            writer
+                .file
+                .inner
+                .sync_all()
+                .map_err(|e| BuildError::io("flushOutput", Some(output_path), e))?;
+            Ok(outcome)
+        }
+        EmbedTarget::Pack { path, volume_size } => {
+            let volumes = VolumeWriter::create(path, volume_size)?;
+            let mut writer = TrailerWriter::new(volumes, 0, keys);
+            let mut outcome = write_embedded(
+                &mut writer,
+                0,
+                projects,
+                media_files,
+                button_files,
+                app_icon,
+                license,
+            )?;
+            outcome.pack_files = writer.file.inner.finish()?;
+            Ok(outcome)
+        }
+    }
+}
+
+/// 엔트리, 앱 아이콘, 프로젝트 JSON, 매니페스트 순서로 트레일러 기록
+/// `payload_offset`은 임베딩 데이터 시작 위치 (패치 시 최초 빌드의 값 유지)
+fn write_embedded<W: Write>(
+    writer: &mut TrailerWriter<W>,
+    payload_offset: u64,
+    projects: &[EmbedProject],
+    media_files: Vec<PatchEntry>,
+    button_files: Vec<PatchEntry>,
+    app_icon: Option<Vec<u8>>,
+    license: Option<LicenseSettings>,
+) -> Result<EmbedOutcome, BuildError> {
+    if projects.is_empty() {
+        return Err(BuildError::NoProject);
+    }
 
-    // 미디어 파일들 쓰기
-    let mut media_entries: Vec<MediaManifestEntry> = Vec::new();
-    for (id, name, mime_type, source) in media_files {
-        let size = write_media_source(&mut file, &source, &mut temp_files_to_cleanup)?;
+    writer.plan_deduplication(media_files.iter().chain(button_files.iter()));
 
-        media_entries.push(MediaManifestEntry {
-            id,
-            name,
-            mime_type,
-            offset: current_offset,
+    // 미디어 파일, 버튼 이미지, 앱 아이콘, 프로젝트 JSON 순서로 쓰기
+    let media = writer.write_entries(media_files)?;
+    let buttons = writer.write_entries(button_files)?;
+    let app_icon = writer.write_app_icon(app_icon)?;
+
+    let mut project_entries = Vec::with_capacity(projects.len());
+    for project in projects {
+        let (offset, size, encoding) = writer.write_project_json(&project.json)?;
+        project_entries.push(ProjectManifestEntry {
+            id: project.id.clone(),
+            name: project.name.clone(),
+            offset,
             size,
+            encoding,
+            schedule: project.schedule.clone(),
         });
+    }
 
-        current_offset += size;
+    let mut locales: Vec<String> = Vec::new();
+    for locale in projects.iter().flat_map(|project| project.locales.iter()) {
+        if !locales.contains(locale) {
+            locales.push(locale.clone());
+        }
     }
 
-    // 버튼 이미지들 쓰기
-    let mut button_entries: Vec<MediaManifestEntry> = Vec::new();
-    for (id, name, mime_type, source) in button_files {
-        let size = write_media_source(&mut file, &source, &mut temp_files_to_cleanup)?;
+    let default_project = project_entries[0].clone();
+    if project_entries.len() == 1 {
+        project_entries.clear();
+    }
 
-        button_entries.push(MediaManifestEntry {
-            id,
-            name,
-            mime_type,
-            offset: current_offset,
-            size,
-        });
+    let manifest = BuildManifest {
+        project_json_offset: default_project.offset,
+        project_json_size: default_project.size,
+        project_json_encoding: default_project.encoding,
+        projects: project_entries,
+        locales,
+        media,
+        buttons,
+        app_icon_offset: app_icon.map(|(offset, _, _)| offset),
+        app_icon_size: app_icon.map(|(_, size, _)| size),
+        app_icon_encoding: app_icon.and_then(|(_, _, encoding)| encoding),
+        license,
+        payload_offset: Some(payload_offset),
+        content_version: Some(build_time_ms()),
+        project_id: Some(default_project.id.clone()),
+        payload_sha256: None,
+    };
+
+    writer.finish(&manifest)
+}
+
+/// 빌드 시각 (ms, 콘텐츠 버전으로 사용하므로 패치할 때마다 증가)
+fn build_time_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// 데이터 팩 볼륨 파일 경로 (<이름>.pak.001, <이름>.pak.002, ...)
+fn volume_path(pack_path: &Path, index: usize) -> PathBuf {
+    let mut name = pack_path.as_os_str().to_os_string();
+    name.push(format!(".{:03}", index));
+    PathBuf::from(name)
+}
 
-        current_offset += size;
+/// 데이터 팩 체크섬 파일 경로 (<이름>.pak.sha256)
+fn checksum_path(pack_path: &Path) -> PathBuf {
+    let mut name = pack_path.as_os_str().to_os_string();
+    name.push(".sha256");
+    PathBuf::from(name)
+}
+
+/// 데이터 팩 파일의 SHA-256을 sha256sum 형식으로 기록하고 체크섬 파일 경로를 반환
+/// 핫 폴더 업데이트에서 플레이어가 복사한 팩이 온전한지 확인하는 데 사용
+pub fn write_pack_checksums(
+    pack_path: &Path,
+    files: &[PathBuf],
+) -> Result<PathBuf, BuildError> {
+    let mut lines = String::new();
+    for file in files {
+        let hash = hash_file(&file.to_string_lossy())?;
+        let name = file.file_name().unwrap_or_default().to_string_lossy();
+        lines.push_str(&format!("{}  {}\n", hash, name));
     }
 
-    // 앱 아이콘 쓰기
-    let (app_icon_offset, app_icon_size) = if let Some(icon_data) = app_icon {
-        let offset = current_offset;
-        let size = icon_data.len() as u64;
-        file.write_all(&icon_data)
-            .map_err(|e| format!("Failed to write app icon: {}", e))?;
-        current_offset += size;
-        (Some(offset), Some(size))
+    let path = checksum_path(pack_path);
+    fs::write(&path, lines).map_err(|e| BuildError::io("writeChecksums", Some(&path), e))?;
+    Ok(path)
+}
+
+/// 데이터 팩과 번호가 이어지는 볼륨 파일, 체크섬 파일 삭제
+pub fn remove_pack_files(pack_path: &Path) {
+    let _ = fs::remove_file(pack_path);
+    let _ = fs::remove_file(checksum_path(pack_path));
+    let mut index = 1;
+    while fs::remove_file(volume_path(pack_path, index)).is_ok() {
+        index += 1;
+    }
+}
+
+/// 데이터 팩 출력
+/// 볼륨 크기가 지정되면 크기를 넘을 때마다 다음 번호의 볼륨 파일로 이어서 기록
+struct VolumeWriter {
+    pack_path: PathBuf,
+    volume_size: Option<u64>,
+    current: File,
+    current_len: u64,
+    paths: Vec<PathBuf>,
+}
+
+impl VolumeWriter {
+    fn create(pack_path: &Path, volume_size: Option<u64>) -> Result<Self, BuildError> {
+        if volume_size == Some(0) {
+            return Err(BuildError::InvalidVolumeSize);
+        }
+
+        // 이전 export의 팩/볼륨이 남아 있으면 player가 섞어 읽으므로 먼저 삭제
+        remove_pack_files(pack_path);
+
+        let first_path = match volume_size {
+            Some(_) => volume_path(pack_path, 1),
+            None => pack_path.to_path_buf(),
+        };
+        let current = File::create(&first_path)
+            .map_err(|e| BuildError::io("createPack", Some(&first_path), e))?;
+
+        Ok(Self {
+            pack_path: pack_path.to_path_buf(),
+            volume_size,
+            current,
+            current_len: 0,
+            paths: vec![first_path],
+        })
+    }
+
+    /// 기록을 마치고 생성된 볼륨 경로 목록을 반환
+    fn finish(&mut self) -> Result<Vec<PathBuf>, BuildError> {
+        let current_path = self.paths.last().map(PathBuf::as_path);
+        self.current
+            .sync_all()
+            .map_err(|e| BuildError::io("flushOutput", current_path, e))?;
+
+        // 마지막 볼륨 뒤에 번호가 이어지는 파일이 남아 있으면 player가 팩의 일부로 읽으므로 삭제
+        if self.volume_size.is_some() {
+            let mut index = self.paths.len() + 1;
+            while fs::remove_file(volume_path(&self.pack_path, index)).is_ok() {
+                index += 1;
+            }
+        }
+
+        Ok(self.paths.clone())
+    }
+
+    fn next_volume(&mut self) -> std::io::Result<()> {
+        self.current.sync_all()?;
+        let path = volume_path(&self.pack_path, self.paths.len() + 1);
+        self.current = File::create(&path)?;
+        self.current_len = 0;
+        self.paths.push(path);
+        Ok(())
+    }
+}
+
+impl Write for VolumeWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let room = match self.volume_size {
+            Some(volume_size) => {
+                if self.current_len >= volume_size {
+                    self.next_volume()?;
+                }
+                (volume_size - self.current_len).min(buf.len() as u64) as usize
+            }
+            None => buf.len(),
+        };
+
+        let written = self.current.write(&buf[..room])?;
+        self.current_len += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.current.flush()
+    }
+}
+
+/// 패치/압축 대상 데이터 파일 결정
+/// 트레일러가 없는 exe 옆에 단일 데이터 팩(<이름>.pak)이 있으면 팩을 대상으로 함
+pub fn resolve_data_file(path: &Path) -> PathBuf {
+    let pack_path = path.with_extension("pak");
+    if pack_path != path && !has_trailer(path) && pack_path.exists() {
+        return pack_path;
+    }
+    path.to_path_buf()
+}
+
+/// 파일 끝에 임베딩 트레일러가 있는지 확인
+fn has_trailer(path: &Path) -> bool {
+    let mut magic = [0u8; 9];
+    File::open(path)
+        .and_then(|mut file| {
+            file.seek(SeekFrom::End(-(magic.len() as i64)))?;
+            file.read_exact(&mut magic)
+        })
+        .map(|_| trailer_format(&magic).is_some())
+        .unwrap_or(false)
+}
+
+/// 매직 바이트로 트레일러 형식 확인 (암호화 여부, 서명 여부)
+fn trailer_format(magic: &[u8]) -> Option<(bool, bool)> {
+    match magic {
+        m if m == MAGIC_BYTES => Some((false, false)),
+        m if m == MAGIC_BYTES_SEALED => Some((true, false)),
+        m if m == MAGIC_BYTES_SIGNED => Some((false, true)),
+        m if m == MAGIC_BYTES_SEALED_SIGNED => Some((true, true)),
+        _ => None,
+    }
+}
+
+/// 기존 빌드의 트레일러
+pub struct ExistingBuild {
+    pub manifest: BuildManifest,
+    /// 암호화된 빌드의 복원한 키
+    pub cipher: Option<ContentCipher>,
+    /// 매니페스트에 서명되어 있는지 (서명된 빌드는 서명 키 없이 다시 쓸 수 없음)
+    pub signed: bool,
+}
+
+/// 기존에 내보낸 exe(또는 단일 데이터 팩)의 매니페스트 읽기
+/// 암호화된 빌드는 키를 복원하여 cipher도 함께 반환 (암호 모드는 passphrase 필요)
+pub fn read_build_manifest(
+    path: &Path,
+    passphrase: Option<&str>,
+) -> Result<ExistingBuild, BuildError> {
+    let mut file = File::open(path).map_err(|e| BuildError::io("openExecutable", Some(path), e))?;
+    let file_size = file
+        .metadata()
+        .map_err(|e| BuildError::io("readMetadata", Some(path), e))?
+        .len();
+
+    read_manifest(&mut file, file_size, passphrase)
+}
+
+/// 데이터 끝의 트레일러에서 매니페스트 읽기 (서명은 player가 확인하므로 건너뜀)
+fn read_manifest<R: Read + Seek>(
+    file: &mut R,
+    file_size: u64,
+    passphrase: Option<&str>,
+) -> Result<ExistingBuild, BuildError> {
+    let trailer_size = (MAGIC_BYTES.len() + 8) as u64;
+    if file_size < trailer_size {
+        return Err(BuildError::NotExported);
+    }
+
+    // 매직 바이트와 매니페스트 크기 읽기
+    let mut trailer = vec![0u8; trailer_size as usize];
+    file.seek(SeekFrom::Start(file_size - trailer_size))
+        .and_then(|_| file.read_exact(&mut trailer))
+        .map_err(|e| BuildError::io("readManifest", None, e))?;
+
+    let (size_bytes, magic) = trailer.split_at(8);
+    let (sealed, signed) = trailer_format(magic).ok_or(BuildError::NotExported)?;
+    let signature_size = if signed { SIGNATURE_SIZE as u64 } else { 0 };
+
+    let manifest_size = u64::from_le_bytes(size_bytes.try_into().unwrap());
+    let available = (file_size - trailer_size).saturating_sub(signature_size);
+    if manifest_size > available {
+        return Err(BuildError::ManifestTooLarge {
+            size: manifest_size,
+            available,
+        });
+    }
+
+    let mut manifest_bytes = vec![0u8; manifest_size as usize];
+    file.seek(SeekFrom::Start(available - manifest_size))
+        .and_then(|_| file.read_exact(&mut manifest_bytes))
+        .map_err(|e| BuildError::io("readManifest", None, e))?;
+
+    let (manifest_json, cipher) = if sealed {
+        let (cipher, json) = ContentCipher::open_sealed(&manifest_bytes, passphrase)
+            .map_err(|detail| BuildError::DecryptFailed { detail })?;
+        (json, Some(cipher))
     } else {
-        (None, None)
+        (manifest_bytes, None)
     };
 
-    // 프로젝트 JSON 쓰기
-    let project_json_bytes = project_json.as_bytes();
-    let project_json_offset = current_offset;
-    let project_json_size = project_json_bytes.len() as u64;
-    file.write_all(project_json_bytes)
-        .map_err(|e| format!("Failed to write project JSON: {}", e))?;
+    let manifest: BuildManifest = serde_json::from_slice(&manifest_json).map_err(|e| {
+        BuildError::CorruptTrailer {
+            detail: format!("Failed to parse manifest: {}", e),
+        }
+    })?;
 
-    // 매니페스트 생성 및 쓰기
-    let manifest = BuildManifest {
-        project_json_offset,
-        project_json_size,
-        media: media_entries,
-        buttons: button_entries,
-        app_icon_offset,
-        app_icon_size,
+    Ok(ExistingBuild {
+        manifest,
+        cipher,
+        signed,
+    })
+}
+
+/// 내보내기 중간 파일 경로 (<이름>.partial.<확장자>, 같은 폴더)
+/// 완성된 뒤에만 최종 경로로 rename하므로 실패해도 대상 경로에 깨진 파일이 남지 않음
+/// 확장자를 유지하여 rcedit 등 도구가 그대로 처리할 수 있게 함
+pub fn staging_path(path: &Path) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(extension) => format!("{}.partial.{}", stem, extension.to_string_lossy()),
+        None => format!("{}.partial", stem),
+    };
+    path.with_file_name(name)
+}
+
+/// 기록된 데이터를 다시 읽어 검증 (exe 또는 데이터 팩 볼륨 목록)
+/// 매니페스트를 읽고 모든 영역이 데이터 범위 안에 있는지, 끝까지 읽히는지 확인
+/// 암호화된 영역은 청크 인증 태그를, zstd 영역은 압축 해제까지 확인
+pub fn verify_embedded_data(
+    paths: &[PathBuf],
+    passphrase: Option<&str>,
+) -> Result<(), BuildError> {
+    let mut reader = VolumeReader::open(paths)?;
+    let total = reader.total;
+    let ExistingBuild {
+        manifest, cipher, ..
+    } = read_manifest(&mut reader, total, passphrase)?;
+
+    let mut regions: Vec<(u64, u64, Option<PayloadEncoding>)> = manifest
+        .media
+        .iter()
+        .chain(manifest.buttons.iter())
+        .map(|entry| (entry.offset, entry.size, entry.encoding))
+        .collect();
+    if let (Some(offset), Some(size)) = (manifest.app_icon_offset, manifest.app_icon_size) {
+        regions.push((offset, size, manifest.app_icon_encoding));
+    }
+    regions.push((
+        manifest.project_json_offset,
+        manifest.project_json_size,
+        manifest.project_json_encoding,
+    ));
+    regions.extend(
+        manifest
+            .projects
+            .iter()
+            .map(|project| (project.offset, project.size, project.encoding)),
+    );
+
+    // 중복 제거로 여러 엔트리가 같은 영역을 가리킬 수 있으므로 한 번씩만 확인
+    let mut verified = HashSet::new();
+    for (offset, size, encoding) in regions {
+        if !verified.insert(offset) {
+            continue;
+        }
+        let corrupted = |detail: String| BuildError::CorruptData { offset, detail };
+        match offset.checked_add(size) {
+            Some(end) if end <= total => {}
+            _ => return Err(corrupted("Region is out of bounds".to_string())),
+        }
+
+        reader
+            .seek(SeekFrom::Start(offset))
+            .map_err(|e| BuildError::io("readEmbeddedData", None, e))?;
+        let mut output: Box<dyn Write> = match encoding {
+            Some(PayloadEncoding::Zstd) => Box::new(
+                zstd::stream::write::Decoder::new(std::io::sink())
+                    .map_err(|e| corrupted(format!("Failed to create decoder: {}", e)))?,
+            ),
+            None => Box::new(std::io::sink()),
+        };
+
+        match cipher {
+            Some(ref cipher) => cipher
+                .decrypt_region(size, &mut reader, &mut output)
+                .map_err(corrupted)?,
+            None => {
+                let copied = std::io::copy(&mut (&mut reader).take(size), &mut output)
+                    .map_err(|e| corrupted(e.to_string()))?;
+                if copied != size {
+                    return Err(corrupted("Region is truncated".to_string()));
+                }
+            }
+        }
+        output.flush().map_err(|e| corrupted(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+/// 중간 파일을 디스크에 기록한 뒤 최종 경로로 교체
+/// (rename 직후 전원이 꺼져도 대상 경로에 내용이 비어 있는 파일이 남지 않도록)
+pub fn commit_staged_file(staged: &Path, target: &Path) -> Result<(), BuildError> {
+    let result = File::open(staged)
+        .and_then(|file| file.sync_all())
+        .map_err(|e| BuildError::io("flushOutput", Some(staged), e))
+        .and_then(|_| {
+            fs::rename(staged, target).map_err(|e| BuildError::io("replaceFile", Some(target), e))
+        });
+    if result.is_err() {
+        let _ = fs::remove_file(staged);
+    }
+    result
+}
+
+/// 교체 전 기존 데이터 팩 파일의 백업 (exe 교체가 실패하면 이전 빌드로 되돌리는 용도)
+pub struct PackBackup {
+    pack_path: PathBuf,
+    /// (원래 경로, 백업 경로)
+    files: Vec<(PathBuf, PathBuf)>,
+}
+
+impl PackBackup {
+    /// 기존 팩, 체크섬, 볼륨 파일을 백업 경로(<파일>.bak)로 이동
+    fn create(pack_path: &Path) -> Result<Self, BuildError> {
+        let mut backup = Self {
+            pack_path: pack_path.to_path_buf(),
+            files: Vec::new(),
+        };
+
+        let volumes = (1..).map(|index| volume_path(pack_path, index));
+        let existing = [pack_path.to_path_buf(), checksum_path(pack_path)]
+            .into_iter()
+            .filter(|path| path.exists())
+            .chain(volumes.take_while(|path| path.exists()))
+            .collect::<Vec<_>>();
+
+        for original in existing {
+            let mut name = original.as_os_str().to_os_string();
+            name.push(".bak");
+            let backup_path = PathBuf::from(name);
+            if let Err(e) = fs::rename(&original, &backup_path) {
+                backup.put_back();
+                return Err(BuildError::io("replaceFile", Some(&original), e));
+            }
+            backup.files.push((original, backup_path));
+        }
+        Ok(backup)
+    }
+
+    /// 새로 교체한 팩 파일을 지우고 백업을 원래 경로로 되돌림
+    pub fn restore(self) {
+        remove_pack_files(&self.pack_path);
+        self.put_back();
+    }
+
+    fn put_back(&self) {
+        for (original, backup_path) in &self.files {
+            if let Err(e) = fs::rename(backup_path, original) {
+                log::warn!("Failed to restore {}: {}", original.display(), e);
+            }
+        }
+    }
+
+    /// 교체가 끝났으므로 백업 삭제
+    pub fn discard(self) {
+        for (_, backup_path) in &self.files {
+            let _ = fs::remove_file(backup_path);
+        }
+    }
+}
+
+/// 중간 데이터 팩 볼륨을 최종 경로로 교체하고 최종 경로 목록과 이전 팩의 백업 반환
+/// 이전 export의 팩/볼륨은 섞여 읽히지 않도록 백업 경로로 옮기며,
+/// exe 교체까지 끝나면 discard, 실패하면 restore로 이전 빌드를 되돌려야 함
+pub fn commit_staged_pack(
+    staged: &[PathBuf],
+    pack_path: &Path,
+    volumes: bool,
+) -> Result<(Vec<PathBuf>, PackBackup), BuildError> {
+    let backup = PackBackup::create(pack_path)?;
+
+    let mut committed = Vec::with_capacity(staged.len());
+    for (index, path) in staged.iter().enumerate() {
+        let target = if volumes {
+            volume_path(pack_path, index + 1)
+        } else {
+            pack_path.to_path_buf()
+        };
+        if let Err(e) = commit_staged_file(path, &target) {
+            backup.restore();
+            return Err(e);
+        }
+        committed.push(target);
+    }
+    Ok((committed, backup))
+}
+
+/// 여러 볼륨 파일을 하나로 이어 읽는 리더 (검증용, player의 VolumeReader와 동일)
+struct VolumeReader {
+    volumes: Vec<(File, u64)>,
+    starts: Vec<u64>,
+    total: u64,
+    position: u64,
+}
+
+impl VolumeReader {
+    fn open(paths: &[PathBuf]) -> Result<Self, BuildError> {
+        let mut volumes = Vec::with_capacity(paths.len());
+        let mut starts = Vec::with_capacity(paths.len());
+        let mut total: u64 = 0;
+
+        for path in paths {
+            let file = File::open(path).map_err(|e| BuildError::io("openFile", Some(path), e))?;
+            let len = file
+                .metadata()
+                .map_err(|e| BuildError::io("readMetadata", Some(path), e))?
+                .len();
+            starts.push(total);
+            volumes.push((file, len));
+            total += len;
+        }
+
+        Ok(Self {
+            volumes,
+            starts,
+            total,
+            position: 0,
+        })
+    }
+}
+
+impl Read for VolumeReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.position >= self.total || buf.is_empty() {
+            return Ok(0);
+        }
+
+        // 현재 위치를 포함하는 볼륨 찾기
+        let index = self.starts.partition_point(|&start| start <= self.position) - 1;
+        let local = self.position - self.starts[index];
+        let (file, len) = &mut self.volumes[index];
+
+        file.seek(SeekFrom::Start(local))?;
+        let max = (*len - local).min(buf.len() as u64) as usize;
+        let bytes_read = file.read(&mut buf[..max])?;
+
+        self.position += bytes_read as u64;
+        Ok(bytes_read)
+    }
+}
+
+impl Seek for VolumeReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(delta) => self.total.checked_add_signed(delta),
+            SeekFrom::Current(delta) => self.position.checked_add_signed(delta),
+        }
+        .ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, "Invalid seek position")
+        })?;
+
+        self.position = position;
+        Ok(position)
+    }
+}
+
+/// 기존 exe 패치: 새로 추가되거나 변경된 엔트리만 파일 끝에 추가하고
+/// 변경되지 않은 엔트리는 기존 오프셋을 가리키는 새 매니페스트를 기록
+///
+/// 이전 매니페스트와 데이터는 파일에 남으므로 필요하면 compact_embedded_data로 정리
+/// PE 아이콘(rcedit)은 다시 설정하지 않음 (설정 시 임베딩 데이터가 손상됨)
+/// 같은 폴더의 중간 파일(<이름>.partial.<확장자>)에 복사해 패치한 뒤 교체하므로
+/// 실패하거나 중간에 전원이 꺼져도 기존 빌드는 그대로 남음
+pub fn patch_embedded_data(
+    target_path: &Path,
+    previous: &BuildManifest,
+    projects: &[EmbedProject],
+    media: Vec<PatchEntry>,
+    buttons: Vec<PatchEntry>,
+    app_icon: Option<Vec<u8>>,
+    keys: TrailerKeys,
+) -> Result<PatchOutcome, BuildError> {
+    let original_size = fs::metadata(target_path)
+        .map_err(|e| BuildError::io("readMetadata", Some(target_path), e))?
+        .len();
+
+    let reused_entries = media
+        .iter()
+        .chain(buttons.iter())
+        .filter(|entry| matches!(entry, PatchEntry::Keep(_)))
+        .count();
+    let written_entries = media.len() + buttons.len() - reused_entries;
+
+    let payload_start = previous.payload_start();
+    let existing_size = original_size.checked_sub(payload_start).ok_or_else(|| {
+        BuildError::CorruptTrailer {
+            detail: "Executable is shorter than its payload offset".to_string(),
+        }
+    })?;
+
+    let staged = staging_path(target_path);
+    let result = (|| {
+        let mut source = File::open(target_path)
+            .map_err(|e| BuildError::io("openExecutable", Some(target_path), e))?;
+        let mut output = File::create(&staged)
+            .map_err(|e| BuildError::io("createTempFile", Some(&staged), e))?;
+
+        // viewer exe (+ PE 아이콘) 부분은 그대로 복사
+        std::io::copy(&mut (&mut source).take(payload_start), &mut output)
+            .map_err(|e| BuildError::io("copyData", Some(&staged), e))?;
+
+        // 기존 임베딩 데이터는 페이로드 해시에 포함되도록 기록기를 거쳐 복사
+        let mut writer = TrailerWriter::new(output, payload_start, keys);
+        writer.copy_region(&mut source, payload_start, existing_size)?;
+        let embedded = write_embedded(
+            &mut writer,
+            payload_start,
+            projects,
+            media,
+            buttons,
+            app_icon,
+            previous.license.clone(),
+        )?;
+        Ok((embedded, writer.offset))
+    })();
+
+    let (embedded, patched_size) = match result {
+        Ok(patched) => patched,
+        Err(e) => {
+            let _ = fs::remove_file(&staged);
+            return Err(e);
+        }
+    };
+    commit_staged_file(&staged, target_path)?;
+
+    Ok(PatchOutcome {
+        temp_files: embedded.temp_files,
+        deduplicated_bytes: embedded.deduplicated_bytes,
+        written_entries,
+        reused_entries,
+        appended_bytes: patched_size - original_size,
+    })
+}
+
+/// 패치로 쌓인 사용되지 않는 영역을 제거하여 exe 크기를 줄임
+/// 같은 폴더의 임시 파일에 다시 쓴 뒤 교체하며, 줄어든 바이트 수를 반환
+pub fn compact_embedded_data(
+    target_path: &Path,
+    manifest: &BuildManifest,
+    keys: TrailerKeys,
+) -> Result<u64, BuildError> {
+    let original_size = fs::metadata(target_path)
+        .map_err(|e| BuildError::io("readMetadata", Some(target_path), e))?
+        .len();
+    let payload_start = manifest.payload_start();
+
+    let temp_path = target_path.with_extension("compact.tmp");
+    let mut source = File::open(target_path)
+        .map_err(|e| BuildError::io("openExecutable", Some(target_path), e))?;
+    let mut output = File::create(&temp_path)
+        .map_err(|e| BuildError::io("createTempFile", Some(&temp_path), e))?;
+
+    let result = (|| {
+        // viewer exe (+ PE 아이콘) 부분은 그대로 복사
+        let copied = std::io::copy(&mut (&mut source).take(payload_start), &mut output)
+            .map_err(|e| BuildError::io("copyData", Some(&temp_path), e))?;
+        if copied != payload_start {
+            return Err(BuildError::CorruptTrailer {
+                detail: "Executable is shorter than its payload offset".to_string(),
+            });
+        }
+
+        let mut writer = TrailerWriter::new(output, payload_start, keys);
+
+        // 여러 엔트리가 같은 영역을 가리킬 수 있으므로 이동한 영역을 기억
+        let mut moved: HashMap<u64, (u64, u64)> = HashMap::new();
+        let mut relocate = |writer: &mut TrailerWriter<File>, offset: u64, size: u64| {
+            if let Some(&(new_offset, new_size)) = moved.get(&offset) {
+                return Ok((new_offset, new_size));
+            }
+            let relocated = writer.copy_region(&mut source, offset, size)?;
+            moved.insert(offset, relocated);
+            Ok::<_, BuildError>(relocated)
+        };
+
+        let mut compacted = manifest.clone();
+        for entry in compacted.media.iter_mut().chain(compacted.buttons.iter_mut()) {
+            (entry.offset, entry.size) = relocate(&mut writer, entry.offset, entry.size)?;
+        }
+        if let (Some(offset), Some(size)) = (compacted.app_icon_offset, compacted.app_icon_size) {
+            let (new_offset, new_size) = relocate(&mut writer, offset, size)?;
+            compacted.app_icon_offset = Some(new_offset);
+            compacted.app_icon_size = Some(new_size);
+        }
+        (compacted.project_json_offset, compacted.project_json_size) = relocate(
+            &mut writer,
+            compacted.project_json_offset,
+            compacted.project_json_size,
+        )?;
+        for project in compacted.projects.iter_mut() {
+            (project.offset, project.size) = relocate(&mut writer, project.offset, project.size)?;
+        }
+        compacted.payload_offset = Some(payload_start);
+
+        writer.finish_synced(&compacted)
+    })();
+
+    let compacted_size = match result {
+        Ok(size) => size,
+        Err(e) => {
+            let _ = fs::remove_file(&temp_path);
+            return Err(e);
+        }
     };
 
-    let manifest_json =
-        serde_json::to_string(&manifest).map_err(|e| format!("Failed to serialize manifest: {}", e))?;
-    let manifest_bytes = manifest_json.as_bytes();
-    let manifest_size = manifest_bytes.len() as u64;
+    drop(source);
+    fs::rename(&temp_path, target_path).map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        BuildError::io("replaceFile", Some(target_path), e)
+    })?;
+
+    Ok(original_size.saturating_sub(compacted_size))
+}
+
+impl BuildManifest {
+    /// 임베딩 데이터가 시작되는 오프셋 (viewer exe의 끝)
+    /// 이전 버전 빌드는 기록이 없으므로 가장 앞선 영역의 오프셋으로 대체
+    pub fn payload_start(&self) -> u64 {
+        self.payload_offset.unwrap_or_else(|| {
+            self.media
+                .iter()
+                .chain(self.buttons.iter())
+                .map(|entry| entry.offset)
+                .chain(self.app_icon_offset)
+                .chain(std::iter::once(self.project_json_offset))
+                .min()
+                .unwrap_or(self.project_json_offset)
+        })
+    }
+}
+
+/// 기록한 바이트의 SHA-256을 함께 계산하는 출력 (매니페스트 앞까지의 페이로드 해시용)
+struct DigestWriter<W: Write> {
+    inner: W,
+    hasher: Sha256,
+}
+
+impl<W: Write> Write for DigestWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// 트레일러(엔트리 데이터 + 매니페스트 + 매직 바이트) 기록기
+/// 새 빌드, 패치, 압축에서 공통으로 사용
+struct TrailerWriter<'a, W: Write> {
+    file: DigestWriter<W>,
+    offset: u64,
+    cipher: Option<&'a ContentCipher>,
+    signer: Option<&'a BuildSigner>,
+    temp_files: Vec<String>,
+    /// 둘 이상의 소스가 같은 크기를 가지는 크기 목록 (이 크기의 소스만 해시하여 비교)
+    duplicate_sizes: HashSet<u64>,
+    /// 내용 해시별로 이미 기록된 영역
+    stored_regions: HashMap<String, (u64, WrittenEntry)>,
+    /// 패치에서 재사용하는 기존 영역 ((원본 해시, MIME 타입)별)
+    kept_regions: HashMap<(String, String), MediaManifestEntry>,
+    deduplicated_bytes: u64,
+}
+
+impl<'a, W: Write> TrailerWriter<'a, W> {
+    /// `offset`은 다음에 기록될 위치 (파일의 현재 끝)
+    fn new(file: W, offset: u64, keys: TrailerKeys<'a>) -> Self {
+        Self {
+            file: DigestWriter {
+                inner: file,
+                hasher: Sha256::new(),
+            },
+            offset,
+            cipher: keys.cipher,
+            signer: keys.signer,
+            temp_files: Vec::new(),
+            duplicate_sizes: HashSet::new(),
+            stored_regions: HashMap::new(),
+            kept_regions: HashMap::new(),
+            deduplicated_bytes: 0,
+        }
+    }
+
+    /// 중복 제거 대상 준비
+    /// 크기가 유일한 소스는 중복일 수 없으므로 해시 계산(전체 읽기)을 생략
+    /// 재사용하는 기존 영역은 원본 해시로 등록하여 새 엔트리가 가리킬 수 있게 함
+    fn plan_deduplication<'e>(&mut self, entries: impl Iterator<Item = &'e PatchEntry>) {
+        let mut seen: HashSet<u64> = HashSet::new();
+        for entry in entries {
+            match entry {
+                PatchEntry::Keep(existing) => {
+                    if let Some(ref source_hash) = existing.source_hash {
+                        let key = (source_hash.clone(), existing.mime_type.clone());
+                        self.kept_regions.entry(key).or_insert_with(|| existing.clone());
+                    }
+                }
+                PatchEntry::Write(embed) => {
+                    if let Some(size) = source_size(&embed.source) {
+                        if !seen.insert(size) {
+                            self.duplicate_sizes.insert(size);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// 원본이 같은 기존 영역 (패치에서 재사용하는 엔트리 중)
+    fn kept_region(&self, embed: &EmbedFile) -> Option<&MediaManifestEntry> {
+        let source_hash = embed.source_hash.clone()?;
+        self.kept_regions.get(&(source_hash, embed.mime_type.clone()))
+    }
+
+    /// 엔트리 목록 기록 (Keep은 기존 영역을 그대로 참조)
+    fn write_entries(
+        &mut self,
+        entries: Vec<PatchEntry>,
+    ) -> Result<Vec<MediaManifestEntry>, BuildError> {
+        let mut manifest_entries = Vec::with_capacity(entries.len());
+
+        for entry in entries {
+            let embed = match entry {
+                PatchEntry::Keep(existing) => {
+                    manifest_entries.push(existing);
+                    continue;
+                }
+                PatchEntry::Write(embed) => embed,
+            };
+
+            // 기존 빌드에 같은 원본이 이미 저장되어 있으면 그 영역을 가리킴
+            if let Some(kept) = self.kept_region(&embed) {
+                let kept = kept.clone();
+                if let MediaSource::Path(path) = &embed.source {
+                    self.temp_files.push(path.clone());
+                }
+                self.deduplicated_bytes += kept.size;
+                log::info!(
+                    "Deduplicated embedded entry against existing data: {} ({} bytes)",
+                    embed.name,
+                    kept.size
+                );
+
+                manifest_entries.push(MediaManifestEntry {
+                    id: embed.id,
+                    name: embed.name,
+                    mime_type: embed.mime_type,
+                    source_hash: embed.source_hash,
+                    ..kept
+                });
+                continue;
+            }
+
+            let content_hash = match source_size(&embed.source) {
+                Some(size) if self.duplicate_sizes.contains(&size) => {
+                    Some(hash_media_source(&embed.source)?)
+                }
+                _ => None,
+            };
+
+            // 같은 내용이 이미 기록되었으면 그 영역을 가리킴
+            let stored = content_hash
+                .as_ref()
+                .and_then(|hash| self.stored_regions.get(hash));
+            if let Some(&(offset, written)) = stored {
+                if let MediaSource::Path(path) = &embed.source {
+                    self.temp_files.push(path.clone());
+                }
+                self.deduplicated_bytes += written.size;
+                log::info!(
+                    "Deduplicated embedded entry: {} ({} bytes)",
+                    embed.name,
+                    written.size
+                );
 
-    file.write_all(manifest_bytes)
-        .map_err(|e| format!("Failed to write manifest: {}", e))?;
+                manifest_entries.push(MediaManifestEntry {
+                    id: embed.id,
+                    name: embed.name,
+                    mime_type: embed.mime_type,
+                    offset,
+                    size: written.size,
+                    encoding: written.encoding,
+                    original_size: written.original_size,
+                    source_hash: embed.source_hash,
+                });
+                continue;
+            }
 
-    // 매니페스트 크기 쓰기 (8바이트, little-endian)
-    file.write_all(&manifest_size.to_le_bytes())
-        .map_err(|e| format!("Failed to write manifest size: {}", e))?;
+            let written = write_media_source(
+                &mut self.file,
+                &embed.mime_type,
+                &embed.source,
+                self.cipher,
+                &mut self.temp_files,
+            )?;
+
+            manifest_entries.push(MediaManifestEntry {
+                id: embed.id,
+                name: embed.name,
+                mime_type: embed.mime_type,
+                offset: self.offset,
+                size: written.size,
+                encoding: written.encoding,
+                original_size: written.original_size,
+                source_hash: embed.source_hash,
+            });
+
+            if let Some(hash) = content_hash {
+                self.stored_regions.insert(hash, (self.offset, written));
+            }
+            self.offset += written.size;
+        }
+
+        Ok(manifest_entries)
+    }
+
+    /// 앱 아이콘 쓰기 (압축해서 더 작아지면 압축본 저장)
+    fn write_app_icon(
+        &mut self,
+        app_icon: Option<Vec<u8>>,
+    ) -> Result<Option<(u64, u64, Option<PayloadEncoding>)>, BuildError> {
+        let Some(icon_data) = app_icon else {
+            return Ok(None);
+        };
+
+        // PNG/JPEG처럼 이미 압축된 아이콘은 압축본이 더 커지므로 원본 그대로 저장됨
+        let (stored, encoding) = encode_payload(&icon_data)?;
+        let offset = self.offset;
+        let size = write_payload(&mut self.file, &mut stored.as_slice(), self.cipher)?;
+        self.offset += size;
+        Ok(Some((offset, size, encoding)))
+    }
+
+    /// 프로젝트 JSON 쓰기 (압축해서 더 작아지면 압축본 저장)
+    fn write_project_json(
+        &mut self,
+        project_json: &str,
+    ) -> Result<(u64, u64, Option<PayloadEncoding>), BuildError> {
+        let (project_json_bytes, encoding) = encode_payload(project_json.as_bytes())?;
+        let offset = self.offset;
+        let size = write_payload(&mut self.file, &mut project_json_bytes.as_slice(), self.cipher)?;
+        self.offset += size;
+        Ok((offset, size, encoding))
+    }
 
-    // 매직 바이트 쓰기
-    file.write_all(MAGIC_BYTES)
-        .map_err(|e| format!("Failed to write magic bytes: {}", e))?;
+    /// 다른 파일의 영역을 현재 위치로 복사하고 새 (오프셋, 크기)를 반환
+    /// 암호화된 영역도 nonce가 오프셋에 묶이지 않으므로 그대로 복사
+    fn copy_region(
+        &mut self,
+        source: &mut File,
+        offset: u64,
+        size: u64,
+    ) -> Result<(u64, u64), BuildError> {
+        source
+            .seek(SeekFrom::Start(offset))
+            .map_err(|e| BuildError::io("readEmbeddedData", None, e))?;
 
-    Ok(temp_files_to_cleanup)
+        let new_offset = self.offset;
+        let written = std::io::copy(&mut source.take(size), &mut self.file)
+            .map_err(|e| BuildError::io("copyData", None, e))?;
+        if written != size {
+            return Err(BuildError::CorruptData {
+                offset,
+                detail: "Region is truncated".to_string(),
+            });
+        }
+
+        self.offset += written;
+        Ok((new_offset, written))
+    }
+
+    /// 매니페스트, 매니페스트 크기, 매직 바이트를 쓰고 임베딩 결과를 반환
+    fn finish(&mut self, manifest: &BuildManifest) -> Result<EmbedOutcome, BuildError> {
+        let manifest = self.write_manifest(manifest)?;
+        Ok(EmbedOutcome {
+            temp_files: std::mem::take(&mut self.temp_files),
+            deduplicated_bytes: self.deduplicated_bytes,
+            pack_files: Vec::new(),
+            manifest,
+        })
+    }
+
+    /// 페이로드 해시를 채운 매니페스트를 기록하고 기록한 매니페스트를 반환
+    fn write_manifest(&mut self, manifest: &BuildManifest) -> Result<BuildManifest, BuildError> {
+        let manifest = BuildManifest {
+            payload_sha256: Some(to_hex(&self.file.hasher.clone().finalize())),
+            ..manifest.clone()
+        };
+        let manifest_json =
+            serde_json::to_string(&manifest).map_err(|e| BuildError::ManifestSerialize {
+                detail: e.to_string(),
+            })?;
+
+        // 암호화 시 매니페스트 자체도 암호화 (오프셋이 노출되지 않도록)
+        let (manifest_json, sealed) = match self.cipher {
+            Some(cipher) => (
+                cipher
+                    .seal_manifest(manifest_json.as_bytes(), manifest.content_version)
+                    .map_err(|detail| BuildError::EncryptFailed { detail })?,
+                true,
+            ),
+            None => (manifest_json, false),
+        };
+        let magic_bytes = match (sealed, self.signer.is_some()) {
+            (false, false) => MAGIC_BYTES,
+            (true, false) => MAGIC_BYTES_SEALED,
+            (false, true) => MAGIC_BYTES_SIGNED,
+            (true, true) => MAGIC_BYTES_SEALED_SIGNED,
+        };
+        let manifest_bytes = manifest_json.as_bytes();
+        let manifest_size = manifest_bytes.len() as u64;
+
+        let write_error = |e| BuildError::io("writeManifest", None, e);
+        self.file.write_all(manifest_bytes).map_err(write_error)?;
+
+        // 서명 쓰기 (매니페스트와 매니페스트 크기 사이, 64바이트)
+        let mut signature_size = 0;
+        if let Some(signer) = self.signer {
+            let signature = signer.sign(magic_bytes, manifest_bytes);
+            self.file.write_all(&signature).map_err(write_error)?;
+            signature_size = signature.len() as u64;
+        }
+
+        // 매니페스트 크기 쓰기 (8바이트, little-endian)
+        self.file
+            .write_all(&manifest_size.to_le_bytes())
+            .map_err(write_error)?;
+
+        // 매직 바이트 쓰기
+        self.file.write_all(magic_bytes).map_err(write_error)?;
+
+        self.offset += manifest_size + signature_size + 8 + magic_bytes.len() as u64;
+        Ok(manifest)
+    }
+}
+
+impl TrailerWriter<'_, File> {
+    /// finish와 같지만 디스크에 동기화하고 최종 파일 크기를 반환
+    fn finish_synced(&mut self, manifest: &BuildManifest) -> Result<u64, BuildError> {
+        self.write_manifest(manifest)?;
+        self.file
+            .inner
+            .sync_all()
+            .map_err(|e| BuildError::io("flushOutput", None, e))?;
+        self.file
+            .inner
+            .metadata()
+            .map(|metadata| metadata.len())
+            .map_err(|e| BuildError::io("readMetadata", None, e))
+    }
 }
 
-/// 미디어 소스를 파일에 쓰고 크기를 반환
-fn write_media_source(
-    file: &mut File,
+/// 원본 데이터의 SHA-256 (hex)
+pub fn hash_bytes(data: &[u8]) -> String {
+    to_hex(&Sha256::digest(data))
+}
+
+/// 원본 파일의 SHA-256 (hex, 스트리밍으로 계산)
+pub fn hash_file(path: &str) -> Result<String, BuildError> {
+    let mut file = File::open(path).map_err(|e| BuildError::source(path, e))?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; STREAM_BUFFER_SIZE];
+
+    loop {
+        let bytes_read = file
+            .read(&mut buffer)
+            .map_err(|e| BuildError::source(path, e))?;
+        if bytes_read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..bytes_read]);
+    }
+
+    Ok(to_hex(&hasher.finalize()))
+}
+
+/// 미디어 소스의 SHA-256 (hex)
+fn hash_media_source(source: &MediaSource) -> Result<String, BuildError> {
+    match source {
+        MediaSource::Data(data) => Ok(hash_bytes(data)),
+        MediaSource::Path(path) => hash_file(path),
+    }
+}
+
+/// 미디어 소스의 크기 (파일 정보를 읽을 수 없으면 None)
+fn source_size(source: &MediaSource) -> Option<u64> {
+    match source {
+        MediaSource::Data(data) => Some(data.len() as u64),
+        MediaSource::Path(path) => fs::metadata(path).ok().map(|metadata| metadata.len()),
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// 출력 파일에 기록된 엔트리 정보
+#[derive(Clone, Copy)]
+struct WrittenEntry {
+    size: u64,
+    encoding: Option<PayloadEncoding>,
+    original_size: Option<u64>,
+}
+
+/// 미디어 소스를 파일에 쓰고 기록된 크기/인코딩을 반환
+/// 압축 가능한 타입(JSON, SVG, 무압축 이미지 등)은 zstd로 압축 시도
+fn write_media_source<W: Write>(
+    file: &mut W,
+    mime_type: &str,
     source: &MediaSource,
+    cipher: Option<&ContentCipher>,
     temp_files: &mut Vec<String>,
-) -> Result<u64, String> {
-    match source {
-        MediaSource::Data(data) => {
-            file.write_all(data)
-                .map_err(|e| format!("Failed to write media data: {}", e))?;
-            Ok(data.len() as u64)
-        }
+) -> Result<WrittenEntry, BuildError> {
+    if is_compressible_mime(mime_type) {
+        let data = match source {
+            MediaSource::Data(data) => data.clone(),
+            MediaSource::Path(path) => {
+                temp_files.push(path.clone());
+                fs::read(path).map_err(|e| BuildError::source(path, e))?
+            }
+        };
+
+        let (stored, encoding) = encode_payload(&data)?;
+        let size = write_payload(file, &mut stored.as_slice(), cipher)?;
+
+        return Ok(WrittenEntry {
+            size,
+            encoding,
+            original_size: encoding.map(|_| data.len() as u64),
+        });
+    }
+
+    let size = match source {
+        MediaSource::Data(data) => write_payload(file, &mut data.as_slice(), cipher)?,
         MediaSource::Path(path) => {
             temp_files.push(path.clone());
-            let size = stream_file_to_output(file, Path::new(path))?;
-            Ok(size)
+            let mut source_file = File::open(path).map_err(|e| BuildError::source(path, e))?;
+            write_payload(file, &mut source_file, cipher)?
         }
+    };
+
+    Ok(WrittenEntry {
+        size,
+        encoding: None,
+        original_size: None,
+    })
+}
+
+/// 데이터를 출력 파일에 쓰고 기록된 바이트 수를 반환
+/// cipher가 있으면 청크 단위로 암호화
+fn write_payload<R: Read, W: Write>(
+    output: &mut W,
+    source: &mut R,
+    cipher: Option<&ContentCipher>,
+) -> Result<u64, BuildError> {
+    match cipher {
+        Some(cipher) => cipher
+            .encrypt_region(source, output)
+            .map_err(|detail| BuildError::EncryptFailed { detail }),
+        None => stream_to_output(output, source),
     }
 }
 
-/// 파일을 스트리밍으로 읽어서 출력 파일에 쓰기
-fn stream_file_to_output(output: &mut File, source_path: &Path) -> Result<u64, String> {
-    let mut source = File::open(source_path)
-        .map_err(|e| format!("Failed to open source file {:?}: {}", source_path, e))?;
+/// 압축 효과가 있는 MIME 타입인지 확인
+/// 영상, PNG, JPEG 등 이미 압축된 포맷은 원본 그대로 저장
+fn is_compressible_mime(mime_type: &str) -> bool {
+    let mime_type = mime_type.to_ascii_lowercase();
+    mime_type.starts_with("text/")
+        || mime_type.ends_with("+xml")
+        || mime_type.ends_with("+json")
+        || matches!(
+            mime_type.as_str(),
+            "application/json"
+                | "application/xml"
+                | "image/bmp"
+                | "image/x-ms-bmp"
+                | "image/tiff"
+                | "image/x-icon"
+                | "image/vnd.microsoft.icon"
+        )
+}
+
+/// 데이터를 zstd로 압축하고, 원본보다 작을 때만 압축본을 반환
+fn encode_payload(data: &[u8]) -> Result<(Vec<u8>, Option<PayloadEncoding>), BuildError> {
+    let compressed = zstd::bulk::compress(data, PAYLOAD_ZSTD_LEVEL).map_err(|e| {
+        BuildError::CompressFailed {
+            detail: e.to_string(),
+        }
+    })?;
+
+    if compressed.len() < data.len() {
+        Ok((compressed, Some(PayloadEncoding::Zstd)))
+    } else {
+        Ok((data.to_vec(), None))
+    }
+}
 
+/// 소스를 스트리밍으로 읽어서 출력 파일에 쓰기
+fn stream_to_output<R: Read, W: Write>(
+    output: &mut W,
+    source: &mut R,
+) -> Result<u64, BuildError> {
     let mut buffer = [0u8; STREAM_BUFFER_SIZE];
     let mut total_written: u64 = 0;
 
     loop {
         let bytes_read = source
             .read(&mut buffer)
-            .map_err(|e| format!("Failed to read source file: {}", e))?;
+            .map_err(|e| BuildError::io("readSource", None, e))?;
 
         if bytes_read == 0 {
             break;
@@ -192,7 +1627,7 @@ fn stream_file_to_output(output: &mut File, source_path: &Path) -> Result<u64, S
 
         output
             .write_all(&buffer[..bytes_read])
-            .map_err(|e| format!("Failed to write to output: {}", e))?;
+            .map_err(|e| BuildError::io("writeOutput", None, e))?;
 
         total_written += bytes_read as u64;
     }
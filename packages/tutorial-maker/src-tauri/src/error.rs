@@ -0,0 +1,171 @@
+use serde::Serialize;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+/// 빌드/패치/압축 에러
+/// 프론트엔드에는 code와 필드로 전달되어 UI에서 메시지를 현지화하고 해결 방법을 안내함
+/// (code 값은 UI가 의존하므로 변경하지 말 것)
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "code", rename_all_fields = "camelCase")]
+pub enum BuildError {
+    /// FFmpeg 실행 파일을 찾지 못함
+    FfmpegNotFound,
+    /// rcedit 실행 파일을 찾지 못함
+    RceditNotFound,
+    /// 원본 파일이 없음 (이동/삭제됨)
+    SourceMissing { path: String },
+    /// 디스크 공간 부족
+    DiskFull { path: Option<String> },
+    /// 쓰기/읽기 권한 없음 (실행 중인 exe, 읽기 전용 폴더 등)
+    PermissionDenied { path: Option<String> },
+    /// 임베딩 트레일러가 없음 (내보낸 튜토리얼 파일이 아님)
+    NotExported,
+    /// 트레일러나 매니페스트가 손상됨
+    CorruptTrailer { detail: String },
+    /// 매니페스트 크기가 파일에 남은 데이터보다 큼
+    ManifestTooLarge { size: u64, available: u64 },
+    /// 임베딩된 영역이 손상되었거나 잘림
+    CorruptData { offset: u64, detail: String },
+    /// 암호화된 매니페스트를 열지 못함 (암호 누락/불일치 등)
+    DecryptFailed { detail: String },
+    /// 프로젝트 JSON을 해석하지 못함
+    InvalidProject { detail: String },
+    /// 재생 일정 형식 오류
+    InvalidSchedule { project: String, detail: String },
+    /// 같은 프로젝트가 빌드에 두 번 포함됨
+    DuplicateProject { project: String },
+    /// 임베딩할 프로젝트가 없음
+    NoProject,
+    /// 데이터 팩 볼륨 크기가 0
+    InvalidVolumeSize,
+    /// 영상 길이를 읽지 못함
+    ProbeFailed { file: String, detail: String },
+    /// 영상 압축(FFmpeg 인코딩) 실패
+    EncodeFailed { file: String, detail: String },
+    /// 앱 아이콘 이미지를 변환하지 못함
+    InvalidIcon { detail: String },
+    /// rcedit로 exe 아이콘을 설정하지 못함
+    IconFailed { detail: String },
+    /// 콘텐츠 키 생성, 영역 암호화, 매니페스트 봉인 실패
+    EncryptFailed { detail: String },
+    /// 프로젝트 JSON/무압축 미디어의 zstd 압축 실패
+    CompressFailed { detail: String },
+    /// 라이선스 설정이 올바르지 않아 서명하지 못함 (날짜 형식 등)
+    LicenseInvalid { detail: String },
+    /// 매니페스트를 JSON으로 변환하지 못함
+    ManifestSerialize { detail: String },
+    /// 기록 후 다시 읽은 검증에서 실패
+    VerificationFailed { cause: Box<BuildError> },
+    /// 그 밖의 입출력 에러 (operation은 readManifest, writeOutput 등 작업 식별자)
+    Io {
+        operation: &'static str,
+        path: Option<String>,
+        detail: String,
+    },
+}
+
+impl BuildError {
+    /// 입출력 에러 분류 (디스크 부족, 권한 없음은 별도 code)
+    pub fn io(operation: &'static str, path: Option<&Path>, error: io::Error) -> Self {
+        let path = path.map(|path| path.display().to_string());
+        if is_disk_full(&error) {
+            return Self::DiskFull { path };
+        }
+        if error.kind() == io::ErrorKind::PermissionDenied {
+            return Self::PermissionDenied { path };
+        }
+        Self::Io {
+            operation,
+            path,
+            detail: error.to_string(),
+        }
+    }
+
+    /// 원본 파일 읽기 에러 (없는 파일은 SourceMissing)
+    pub fn source(path: &str, error: io::Error) -> Self {
+        if error.kind() == io::ErrorKind::NotFound {
+            return Self::SourceMissing {
+                path: path.to_string(),
+            };
+        }
+        Self::io("readSource", Some(Path::new(path)), error)
+    }
+}
+
+/// 디스크 공간 부족 여부 (ErrorKind::StorageFull은 Rust 1.83부터라 OS 에러 코드로 확인)
+fn is_disk_full(error: &io::Error) -> bool {
+    match error.raw_os_error() {
+        // ERROR_HANDLE_DISK_FULL, ERROR_DISK_FULL
+        Some(code) if cfg!(windows) => code == 39 || code == 112,
+        // ENOSPC
+        Some(code) => code == 28,
+        None => false,
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path_suffix = |path: &Option<String>| match path {
+            Some(path) => format!(": {}", path),
+            None => String::new(),
+        };
+        match self {
+            Self::FfmpegNotFound => write!(f, "FFmpeg not found (ffmpeg.exe)"),
+            Self::RceditNotFound => write!(f, "rcedit-x64.exe not found"),
+            Self::SourceMissing { path } => write!(f, "Source file not found: {}", path),
+            Self::DiskFull { path } => write!(f, "Disk is full{}", path_suffix(path)),
+            Self::PermissionDenied { path } => {
+                write!(f, "Permission denied{}", path_suffix(path))
+            }
+            Self::NotExported => write!(f, "Not an exported tutorial executable"),
+            Self::CorruptTrailer { detail } => {
+                write!(f, "Embedded trailer is corrupted: {}", detail)
+            }
+            Self::ManifestTooLarge { size, available } => write!(
+                f,
+                "Invalid manifest size: {} bytes ({} bytes available)",
+                size, available
+            ),
+            Self::CorruptData { offset, detail } => {
+                write!(f, "Embedded region at {} is corrupted: {}", offset, detail)
+            }
+            Self::DecryptFailed { detail } => write!(f, "Failed to open content: {}", detail),
+            Self::InvalidProject { detail } => {
+                write!(f, "Failed to parse project JSON: {}", detail)
+            }
+            Self::InvalidSchedule { project, detail } => {
+                write!(f, "Invalid schedule in project {}: {}", project, detail)
+            }
+            Self::DuplicateProject { project } => {
+                write!(f, "Duplicate project in build: {}", project)
+            }
+            Self::NoProject => write!(f, "No project to embed"),
+            Self::InvalidVolumeSize => write!(f, "Volume size must be greater than zero"),
+            Self::ProbeFailed { file, detail } => {
+                write!(f, "Failed to get video duration of {}: {}", file, detail)
+            }
+            Self::EncodeFailed { file, detail } => {
+                write!(f, "FFmpeg failed for {}: {}", file, detail)
+            }
+            Self::InvalidIcon { detail } => write!(f, "Failed to convert app icon: {}", detail),
+            Self::IconFailed { detail } => write!(f, "rcedit failed: {}", detail),
+            Self::EncryptFailed { detail } => write!(f, "Failed to encrypt content: {}", detail),
+            Self::CompressFailed { detail } => write!(f, "Failed to compress payload: {}", detail),
+            Self::LicenseInvalid { detail } => write!(f, "Invalid license settings: {}", detail),
+            Self::ManifestSerialize { detail } => {
+                write!(f, "Failed to serialize manifest: {}", detail)
+            }
+            Self::VerificationFailed { cause } => {
+                write!(f, "Export verification failed: {}", cause)
+            }
+            Self::Io {
+                operation,
+                path,
+                detail,
+            } => write!(f, "I/O error ({}){}: {}", operation, path_suffix(path), detail),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
@@ -1,3 +1,4 @@
+use crate::error::BuildError;
 use ico::{IconDir, IconDirEntry, IconImage, ResourceType};
 use image::imageops::FilterType;
 use image::DynamicImage;
@@ -10,10 +11,11 @@ use std::process::Command;
 const ICON_SIZES: &[u32] = &[256, 128, 64, 48, 32, 16];
 
 /// PNG/JPEG 이미지 데이터를 ICO 파일로 변환
-pub fn convert_to_ico(image_data: &[u8], output_path: &Path) -> Result<(), String> {
+pub fn convert_to_ico(image_data: &[u8], output_path: &Path) -> Result<(), BuildError> {
     // 이미지 로드
-    let img = image::load_from_memory(image_data)
-        .map_err(|e| format!("Failed to load image: {}", e))?;
+    let img = image::load_from_memory(image_data).map_err(|e| BuildError::InvalidIcon {
+        detail: e.to_string(),
+    })?;
 
     // ICO 디렉토리 생성
     let mut icon_dir = IconDir::new(ResourceType::Icon);
@@ -24,17 +26,19 @@ pub fn convert_to_ico(image_data: &[u8], output_path: &Path) -> Result<(), Strin
         let rgba = resized.to_rgba8();
         let icon_image = IconImage::from_rgba_data(size, size, rgba.into_raw());
         icon_dir.add_entry(IconDirEntry::encode(&icon_image).map_err(|e| {
-            format!("Failed to encode icon at size {}: {}", size, e)
+            BuildError::InvalidIcon {
+                detail: format!("Failed to encode icon at size {}: {}", size, e),
+            }
         })?);
     }
 
     // ICO 파일 쓰기
     let file = File::create(output_path)
-        .map_err(|e| format!("Failed to create ICO file: {}", e))?;
+        .map_err(|e| BuildError::io("writeIcon", Some(output_path), e))?;
     let writer = BufWriter::new(file);
     icon_dir
         .write(writer)
-        .map_err(|e| format!("Failed to write ICO file: {}", e))?;
+        .map_err(|e| BuildError::io("writeIcon", Some(output_path), e))?;
 
     Ok(())
 }
@@ -45,17 +49,25 @@ fn resize_image(img: &DynamicImage, size: u32) -> DynamicImage {
 }
 
 /// rcedit를 사용하여 실행 파일의 아이콘 설정
-pub fn set_exe_icon(exe_path: &Path, ico_path: &Path, rcedit_path: &Path) -> Result<(), String> {
+pub fn set_exe_icon(
+    exe_path: &Path,
+    ico_path: &Path,
+    rcedit_path: &Path,
+) -> Result<(), BuildError> {
     let output = Command::new(rcedit_path)
         .arg(exe_path)
         .arg("--set-icon")
         .arg(ico_path)
         .output()
-        .map_err(|e| format!("Failed to execute rcedit: {}", e))?;
+        .map_err(|e| BuildError::IconFailed {
+            detail: format!("Failed to execute rcedit: {}", e),
+        })?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("rcedit failed: {}", stderr));
+        return Err(BuildError::IconFailed {
+            detail: stderr.trim().to_string(),
+        });
     }
 
     Ok(())
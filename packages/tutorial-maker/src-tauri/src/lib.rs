@@ -1,15 +1,42 @@
+mod crypto;
+mod diagnostics;
 mod embedded;
+mod error;
 mod icon;
+mod license;
+mod preflight;
+mod report;
 mod video;
 
-use embedded::{append_embedded_data, prepare_base_executable, MediaSource};
+use crypto::{sealed_size, ContentCipher, EncryptionSettings};
+use diagnostics::{
+    launch_log_level, log_files, write_bundle, MakerEnvironment, DEFAULT_LOG_LEVEL,
+    KEEP_LOG_FILES, LOG_FILE_NAME, MAX_LOG_FILE_BYTES,
+};
+use embedded::{
+    append_embedded_data, commit_staged_file, commit_staged_pack, compact_embedded_data,
+    hash_bytes, hash_file, patch_embedded_data, prepare_base_executable, read_build_manifest,
+    remove_pack_files, resolve_data_file, staging_path, verify_embedded_data,
+    viewer_executable_size, viewer_trusts_key, write_pack_checksums, EmbedFile, EmbedProject,
+    EmbedTarget, ExistingBuild, MediaManifestEntry, MediaSource, PatchEntry, SplitSettings,
+    TrailerKeys,
+};
+use error::BuildError;
 use icon::{convert_to_ico, set_exe_icon};
+use license::{license_terms, BuildSigner, LicenseSettings, SIGNING_KEY_ENV};
+use preflight::{
+    check_disk_space, check_output_writable, format_bytes, CheckStatus, PreflightReport,
+    FAT32_MAX_FILE_SIZE,
+};
+use report::{write_report_files, BuildReport, BuildWarning, ReportCollector};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use tauri::{Emitter, Manager};
+use tauri_plugin_log::{RotationStrategy, Target, TargetKind, TimezoneStrategy};
 use video::{
-    compress_video_with_progress, find_ffmpeg_path, get_temp_compressed_path, get_video_duration,
-    is_video_file, CompressionSettings,
+    compress_video_with_progress, estimate_compressed_size, find_ffmpeg_path,
+    get_ffmpeg_version, get_temp_compressed_path, get_video_duration, is_video_file, probe_video,
+    CompressionSettings,
 };
 
 /// 빌드 진행 상황 이벤트
@@ -50,15 +77,86 @@ struct ExportMediaFile {
 struct ExportRequest {
     output_path: String,
     project_json: String,
+    /// 함께 넣을 다른 프로젝트 JSON (실행 시 선택 화면 표시, 미디어는 공유)
+    #[serde(default)]
+    additional_projects: Vec<String>,
     media_files: Vec<ExportMediaFile>,
     button_files: Vec<ExportMediaFile>,
     app_icon: Option<Vec<u8>>,
     #[serde(default)]
     compression: Option<CompressionSettings>,
+    #[serde(default)]
+    encryption: Option<EncryptionSettings>,
+    #[serde(default)]
+    license: Option<LicenseSettings>,
+    /// 데이터 팩 분리 (exe + .pak/볼륨)
+    #[serde(default)]
+    split: Option<SplitSettings>,
+    /// 교체 전에 기록된 데이터를 다시 읽어 검증
+    #[serde(default)]
+    verify: bool,
+    /// 빌드 보고서를 exe 옆에 JSON/HTML로 저장
+    #[serde(default)]
+    save_report: bool,
+}
+
+/// 패치 요청 (기존에 내보낸 exe 갱신)
+/// 전체 프로젝트 상태를 전달하면 변경된 파일만 추가로 기록
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PatchRequest {
+    target_path: String,
+    project_json: String,
+    #[serde(default)]
+    additional_projects: Vec<String>,
+    media_files: Vec<ExportMediaFile>,
+    button_files: Vec<ExportMediaFile>,
+    app_icon: Option<Vec<u8>>,
+    #[serde(default)]
+    compression: Option<CompressionSettings>,
+    /// 암호 모드로 암호화된 빌드의 잠금 해제 암호
+    #[serde(default)]
+    passphrase: Option<String>,
+    /// 새 라이선스 조건 (없으면 기존 라이선스 유지)
+    #[serde(default)]
+    license: Option<LicenseSettings>,
+}
+
+/// 패치 결과
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PatchResult {
+    written_entries: usize,
+    reused_entries: usize,
+    appended_bytes: u64,
+    deduplicated_bytes: u64,
+}
+
+/// Export 결과
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportResult {
+    /// 내용이 같은 파일을 한 번만 저장하여 절약한 바이트 수
+    deduplicated_bytes: u64,
+    /// exe와 함께 배포해야 하는 데이터 팩 파일 경로
+    pack_files: Vec<String>,
+    /// 빌드 보고서 (엔트리별 크기/코덱/해시, 경고)
+    report: BuildReport,
+    /// 저장된 보고서 파일 경로 (save_report일 때만)
+    report_files: Vec<String>,
+}
+
+/// 압축(사용되지 않는 영역 정리) 요청
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CompactRequest {
+    target_path: String,
+    #[serde(default)]
+    passphrase: Option<String>,
 }
 
-/// ExportMediaFile을 MediaSource로 변환
-fn to_media_source(file: ExportMediaFile) -> (String, String, String, MediaSource) {
+/// ExportMediaFile을 EmbedFile로 변환
+fn to_media_source(file: ExportMediaFile) -> EmbedFile {
     let source = if let Some(path) = file.path {
         MediaSource::Path(path)
     } else if let Some(data) = file.data {
@@ -67,7 +165,57 @@ fn to_media_source(file: ExportMediaFile) -> (String, String, String, MediaSourc
         // 둘 다 없으면 빈 데이터로 처리
         MediaSource::Data(Vec::new())
     };
-    (file.id, file.name, file.mime_type, source)
+    EmbedFile {
+        id: file.id,
+        name: file.name,
+        mime_type: file.mime_type,
+        source,
+        source_hash: None,
+    }
+}
+
+/// 변환 전 원본 소스의 크기 (보고서용, 확인할 수 없으면 0)
+fn export_source_size(file: &ExportMediaFile) -> u64 {
+    match (&file.path, &file.data) {
+        (Some(path), _) => std::fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+        (None, Some(data)) => data.len() as u64,
+        (None, None) => 0,
+    }
+}
+
+/// 변환 전 원본 소스의 해시 계산
+fn export_source_hash(file: &ExportMediaFile) -> Result<String, BuildError> {
+    match (&file.path, &file.data) {
+        (Some(path), _) => hash_file(path),
+        (None, Some(data)) => Ok(hash_bytes(data)),
+        (None, None) => Ok(hash_bytes(&[])),
+    }
+}
+
+/// 해시를 포함한 EmbedFile로 변환
+fn to_hashed_media_source(file: ExportMediaFile) -> Result<EmbedFile, BuildError> {
+    let source_hash = export_source_hash(&file)?;
+    let mut embed = to_media_source(file);
+    embed.source_hash = Some(source_hash);
+    Ok(embed)
+}
+
+/// 임베딩할 프로젝트 목록 (기본 프로젝트가 첫 번째)
+fn embed_projects(
+    project_json: &str,
+    additional: &[String],
+) -> Result<Vec<EmbedProject>, BuildError> {
+    let mut projects = vec![EmbedProject::from_json(project_json.to_string())?];
+    for json in additional {
+        let project = EmbedProject::from_json(json.clone())?;
+        if projects.iter().any(|existing| existing.id == project.id) {
+            return Err(BuildError::DuplicateProject {
+                project: project.name,
+            });
+        }
+        projects.push(project);
+    }
+    Ok(projects)
 }
 
 /// 실행 파일로 내보내기
@@ -76,14 +224,20 @@ fn to_media_source(file: ExportMediaFile) -> (String, String, String, MediaSourc
 /// 1. prepare_base_executable - viewer.exe 복사
 /// 2. set_executable_icon - rcedit로 PE 아이콘 설정 (선택적)
 /// 3. append_embedded_data - 바이너리 데이터 추가
+/// 4. verify_embedded_data - 다시 읽어 검증 (선택적)
+/// 5. commit_staged_file - 중간 파일(<이름>.partial.exe)을 대상 경로로 교체
 ///
 /// # 주의사항
 /// - rcedit는 반드시 데이터 임베딩 전에 실행해야 함!
 /// - rcedit가 PE 파일을 수정하면 파일 끝에 추가된 데이터가 손상됨
 /// - 이 순서를 변경하면 내보낸 exe가 튜토리얼 대신 파일 선택기를 표시함
 #[tauri::command]
-fn export_as_executable(app: tauri::AppHandle, request: ExportRequest) -> Result<(), String> {
+fn export_as_executable(
+    app: tauri::AppHandle,
+    request: ExportRequest,
+) -> Result<ExportResult, BuildError> {
     let output_path = PathBuf::from(&request.output_path);
+    let mut report = ReportCollector::default();
 
     // 압축 설정 확인
     let compression_enabled = request
@@ -92,9 +246,34 @@ fn export_as_executable(app: tauri::AppHandle, request: ExportRequest) -> Result
         .map(|c| c.enabled)
         .unwrap_or(false);
 
+    // 콘텐츠 암호화 키 (암호화 활성화 시 빌드 단위로 생성)
+    let cipher = match request.encryption {
+        Some(ref settings) if settings.enabled => Some(
+            ContentCipher::new(settings).map_err(|detail| BuildError::EncryptFailed { detail })?,
+        ),
+        _ => None,
+    };
+
+    // 빌드 서명 키와 라이선스 조건 (서명 키가 있으면 모든 빌드에 서명)
+    let signer = build_signer()?;
+    let license = license_terms(request.license.as_ref(), signer.as_ref())
+        .map_err(|detail| BuildError::LicenseInvalid { detail })?;
+
     // FFmpeg 경로 (압축 활성화 시 미리 확인)
+    // 압축하지 않으면 영상 길이/코덱 확인에만 쓰므로 없어도 빌드는 진행
+    let has_video = request
+        .media_files
+        .iter()
+        .any(|f| is_video_file(&f.mime_type));
     let ffmpeg_path = if compression_enabled {
         Some(find_ffmpeg_path(&app)?)
+    } else if has_video {
+        find_ffmpeg_path(&app)
+            .map_err(|e| {
+                log::warn!("Video probe skipped: {}", e);
+                report.warn(None, BuildWarning::ProbeUnavailable);
+            })
+            .ok()
     } else {
         None
     };
@@ -115,7 +294,7 @@ fn export_as_executable(app: tauri::AppHandle, request: ExportRequest) -> Result
 
     // 미디어 파일 처리 (압축 적용)
     let mut current_video_index = 0usize;
-    let mut media_files: Vec<(String, String, String, MediaSource)> = Vec::new();
+    let mut media_files: Vec<EmbedFile> = Vec::new();
 
     for file in request.media_files {
         let is_video = is_video_file(&file.mime_type);
@@ -125,80 +304,577 @@ fn export_as_executable(app: tauri::AppHandle, request: ExportRequest) -> Result
             current_video_index += 1;
         }
 
-        let result = process_media_file_for_export(
-            &app,
+        if file.path.is_none() && file.data.is_none() {
+            report.warn(Some(&file.name), BuildWarning::EmptySource);
+        }
+        report.media_source(&file.id).original_size = export_source_size(&file);
+
+        // 이후 패치에서 변경 여부를 판단할 수 있도록 변환 전 해시 기록
+        let source_hash = export_source_hash(&file)?;
+        let mut result = process_media_file_for_export(
             file,
             &request.compression,
             &ffmpeg_path,
             &mut compressed_temp_files,
-            current_video_index,
-            total_videos,
+            ExportProgress {
+                app: &app,
+                current_video_index,
+                total_videos,
+                report: &mut report,
+            },
         )?;
+        result.source_hash = Some(source_hash);
         media_files.push(result);
     }
 
-    let button_files: Vec<_> = request
+    for file in &request.button_files {
+        report.button_source(&file.id, export_source_size(file));
+    }
+    let button_files = request
         .button_files
         .into_iter()
-        .map(to_media_source)
-        .collect();
+        .map(to_hashed_media_source)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // 같은 폴더의 중간 파일에 빌드한 뒤 완성되면 rename으로 교체
+    // (실패하거나 중단되어도 대상 경로에 깨진 exe가 남지 않음)
+    let staged_exe = staging_path(&output_path);
+    let pack_path = output_path.with_extension("pak");
+    let staged_pack = staging_path(&pack_path);
+    let split_volumes = match request.split {
+        Some(ref split) if split.enabled => Some(split.volume_size_mb),
+        _ => None,
+    };
+    let verify_passphrase = request
+        .encryption
+        .as_ref()
+        .and_then(|settings| settings.passphrase.clone());
+
+    let built = (|| {
+        // ⚠️ 순서 중요! 아래 순서를 절대 변경하지 말 것
+
+        // 1. 기본 실행 파일 생성 (viewer.exe 복사)
+        prepare_base_executable(&staged_exe)?;
+
+        // 2. 앱 아이콘이 있으면 PE 리소스에 설정
+        // ⚠️ 반드시 데이터 임베딩 전에 수행! (rcedit가 파일 구조를 변경함)
+        if let Some(ref icon_data) = request.app_icon {
+            set_executable_icon(&app, &staged_exe, icon_data)?;
+        }
+
+        // 3. 임베딩 데이터 추가 (아이콘 설정 후)
+        // ⚠️ 이 단계가 마지막이어야 매직 바이트가 파일 끝에 위치함
+        // 분리 모드면 exe는 뷰어만 두고 같은 레이아웃의 데이터 팩(<이름>.pak)에 기록
+        let target = match split_volumes {
+            Some(volume_size_mb) => EmbedTarget::Pack {
+                path: &staged_pack,
+                volume_size: volume_size_mb.map(|mb| mb * 1024 * 1024),
+            },
+            None => EmbedTarget::Executable(&staged_exe),
+        };
+
+        let mut embedded = append_embedded_data(
+            target,
+            &embed_projects(&request.project_json, &request.additional_projects)?,
+            media_files,
+            button_files,
+            request.app_icon,
+            TrailerKeys {
+                cipher: cipher.as_ref(),
+                signer: signer.as_ref(),
+            },
+            license,
+        )?;
 
-    // ⚠️ 순서 중요! 아래 순서를 절대 변경하지 말 것
+        // 4. 임시 파일 정리
+        for temp_path in &embedded.temp_files {
+            let _ = std::fs::remove_file(temp_path);
+        }
 
-    // 1. 기본 실행 파일 생성 (viewer.exe 복사)
-    prepare_base_executable(&output_path)?;
+        // 5. 기록된 데이터를 다시 읽어 검증 (선택)
+        if request.verify {
+            let data_files = match split_volumes {
+                Some(_) => embedded.pack_files.clone(),
+                None => vec![staged_exe.clone()],
+            };
+            verify_embedded_data(&data_files, verify_passphrase.as_deref()).map_err(|cause| {
+                BuildError::VerificationFailed {
+                    cause: Box::new(cause),
+                }
+            })?;
+        }
 
-    // 2. 앱 아이콘이 있으면 PE 리소스에 설정
-    // ⚠️ 반드시 데이터 임베딩 전에 수행! (rcedit가 파일 구조를 변경함)
-    if let Some(ref icon_data) = request.app_icon {
-        set_executable_icon(&app, &output_path, icon_data)?;
+        // 6. 완성된 파일을 최종 경로로 교체 (데이터 팩 먼저, exe는 마지막)
+        // exe 교체가 실패하면 이전 팩을 되돌려 기존 exe와 팩이 짝을 유지하도록 함
+        match split_volumes {
+            Some(volume_size_mb) => {
+                let (pack_files, backup) = commit_staged_pack(
+                    &embedded.pack_files,
+                    &pack_path,
+                    volume_size_mb.is_some(),
+                )?;
+                embedded.pack_files = pack_files;
+                if let Err(e) = commit_staged_file(&staged_exe, &output_path) {
+                    backup.restore();
+                    return Err(e);
+                }
+                backup.discard();
+            }
+            None => commit_staged_file(&staged_exe, &output_path)?,
+        }
+
+        Ok::<_, BuildError>(embedded)
+    })();
+
+    // 압축된 임시 파일 정리
+    for temp_path in compressed_temp_files {
+        let _ = std::fs::remove_file(&temp_path);
     }
 
-    // 3. 임베딩 데이터 추가 (아이콘 설정 후)
-    // ⚠️ 이 단계가 마지막이어야 매직 바이트가 파일 끝에 위치함
-    let temp_files = append_embedded_data(
+    // 실패하면 중간 파일 삭제 (대상 경로의 기존 파일은 그대로 유지)
+    let embedded = built.map_err(|e| {
+        let _ = std::fs::remove_file(&staged_exe);
+        remove_pack_files(&staged_pack);
+        e
+    })?;
+
+    if embedded.deduplicated_bytes > 0 {
+        log::info!("Deduplication saved {} bytes", embedded.deduplicated_bytes);
+    }
+
+    // 핫 폴더 업데이트용 체크섬 (실패해도 빌드 결과는 유효하므로 경고만 기록)
+    if split_volumes.is_some() {
+        if let Err(e) = write_pack_checksums(&pack_path, &embedded.pack_files) {
+            log::warn!("Pack checksums not written: {}", e);
+            report.warn(
+                None,
+                BuildWarning::ChecksumsNotWritten {
+                    detail: e.to_string(),
+                },
+            );
+        }
+    }
+
+    let report = report.finish(
+        &embedded.manifest,
         &output_path,
-        &request.project_json,
-        media_files,
-        button_files,
+        &embedded.pack_files,
+        embedded.deduplicated_bytes,
+        request.compression,
+        cipher.is_some(),
+    );
+    log::info!(
+        "Export finished: {} bytes in {} ms, {} warnings",
+        report.final_size,
+        report.elapsed_ms,
+        report.warnings.len()
+    );
+
+    // 보고서 저장 실패는 빌드 결과에 영향이 없으므로 로그만 남김
+    let report_files = if request.save_report {
+        write_report_files(&report, &output_path)
+            .inspect_err(|e| log::warn!("Build report not written: {}", e))
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    Ok(ExportResult {
+        deduplicated_bytes: embedded.deduplicated_bytes,
+        pack_files: embedded
+            .pack_files
+            .iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect(),
+        report,
+        report_files: report_files
+            .iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect(),
+    })
+}
+
+/// 기존에 내보낸 실행 파일 패치
+///
+/// 원본 해시가 같은 파일은 기존 영역을 그대로 참조하고(영상 재압축도 생략),
+/// 새로 추가되거나 변경된 파일과 프로젝트 JSON, 새 매니페스트만 파일 끝에 추가함
+/// 사용되지 않게 된 이전 영역은 compact_executable로 정리
+///
+/// # 주의사항
+/// - 뷰어 바이너리와 PE 아이콘은 갱신되지 않음 (rcedit는 임베딩 데이터를 손상시킴)
+/// - 암호화 빌드는 기존 키를 유지하므로 암호 모드면 passphrase가 필요함
+/// - 분리 모드 빌드는 단일 데이터 팩(.pak)만 패치 가능 (볼륨으로 나뉜 팩은 다시 빌드)
+#[tauri::command]
+fn patch_executable(
+    app: tauri::AppHandle,
+    request: PatchRequest,
+) -> Result<PatchResult, BuildError> {
+    let target_path = resolve_data_file(Path::new(&request.target_path));
+
+    let ExistingBuild {
+        manifest: mut previous,
+        cipher,
+        signed,
+    } = read_build_manifest(&target_path, request.passphrase.as_deref())?;
+    let signer = existing_build_signer(signed)?;
+    let projects = embed_projects(&request.project_json, &request.additional_projects)?;
+
+    // 라이선스 조건이 전달되면 새로 기록 (없으면 기존 라이선스 유지)
+    if request.license.is_some() || previous.license.is_none() {
+        previous.license = license_terms(request.license.as_ref(), signer.as_ref())
+            .map_err(|detail| BuildError::LicenseInvalid { detail })?;
+    }
+
+    // 원본 해시가 같은 엔트리는 재사용
+    let mut media_plan: Vec<(ExportMediaFile, String, Option<MediaManifestEntry>)> = Vec::new();
+    for file in request.media_files {
+        let source_hash = export_source_hash(&file)?;
+        let existing = find_unchanged_entry(&previous.media, &file.id, &source_hash);
+        media_plan.push((file, source_hash, existing));
+    }
+
+    let compression_enabled = request
+        .compression
+        .as_ref()
+        .map(|c| c.enabled)
+        .unwrap_or(false);
+
+    // 다시 기록할 영상 수 (진행률 표시용)
+    let total_videos = if compression_enabled {
+        media_plan
+            .iter()
+            .filter(|(file, _, existing)| existing.is_none() && is_video_file(&file.mime_type))
+            .count()
+    } else {
+        0
+    };
+
+    let ffmpeg_path = if total_videos > 0 {
+        Some(find_ffmpeg_path(&app)?)
+    } else {
+        None
+    };
+
+    let mut compressed_temp_files: Vec<String> = Vec::new();
+    let mut current_video_index = 0usize;
+    let mut media_entries: Vec<PatchEntry> = Vec::new();
+    // 패치 결과에는 보고서를 포함하지 않음 (경고는 로그로 남음)
+    let mut report = ReportCollector::default();
+
+    for (file, source_hash, existing) in media_plan {
+        if let Some(entry) = existing {
+            media_entries.push(PatchEntry::Keep(entry));
+            continue;
+        }
+
+        if compression_enabled && is_video_file(&file.mime_type) {
+            current_video_index += 1;
+        }
+
+        let mut embed = process_media_file_for_export(
+            file,
+            &request.compression,
+            &ffmpeg_path,
+            &mut compressed_temp_files,
+            ExportProgress {
+                app: &app,
+                current_video_index,
+                total_videos,
+                report: &mut report,
+            },
+        )?;
+        embed.source_hash = Some(source_hash);
+        media_entries.push(PatchEntry::Write(embed));
+    }
+
+    let mut button_entries: Vec<PatchEntry> = Vec::new();
+    for file in request.button_files {
+        let source_hash = export_source_hash(&file)?;
+        match find_unchanged_entry(&previous.buttons, &file.id, &source_hash) {
+            Some(entry) => button_entries.push(PatchEntry::Keep(entry)),
+            None => {
+                let mut embed = to_media_source(file);
+                embed.source_hash = Some(source_hash);
+                button_entries.push(PatchEntry::Write(embed));
+            }
+        }
+    }
+
+    let result = patch_embedded_data(
+        &target_path,
+        &previous,
+        &projects,
+        media_entries,
+        button_entries,
         request.app_icon,
-    )?;
+        TrailerKeys {
+            cipher: cipher.as_ref(),
+            signer: signer.as_ref(),
+        },
+    );
 
-    // 4. 임시 파일 정리
-    for temp_path in temp_files {
+    // 임시 파일 정리 (실패해도 정리)
+    for temp_path in compressed_temp_files {
         let _ = std::fs::remove_file(&temp_path);
     }
 
-    // 5. 압축된 임시 파일 정리
-    for temp_path in compressed_temp_files {
+    let outcome = result?;
+    for temp_path in outcome.temp_files {
         let _ = std::fs::remove_file(&temp_path);
     }
+    refresh_pack_checksums(&target_path);
 
-    Ok(())
+    log::info!(
+        "Executable patched: {} written, {} reused, {} bytes appended, {} bytes deduplicated",
+        outcome.written_entries,
+        outcome.reused_entries,
+        outcome.appended_bytes,
+        outcome.deduplicated_bytes
+    );
+
+    Ok(PatchResult {
+        written_entries: outcome.written_entries,
+        reused_entries: outcome.reused_entries,
+        appended_bytes: outcome.appended_bytes,
+        deduplicated_bytes: outcome.deduplicated_bytes,
+    })
+}
+
+/// 패치로 쌓인 사용되지 않는 영역을 정리하고 줄어든 바이트 수를 반환
+#[tauri::command]
+fn compact_executable(request: CompactRequest) -> Result<u64, BuildError> {
+    let target_path = resolve_data_file(Path::new(&request.target_path));
+    let existing = read_build_manifest(&target_path, request.passphrase.as_deref())?;
+    let signer = existing_build_signer(existing.signed)?;
+    let keys = TrailerKeys {
+        cipher: existing.cipher.as_ref(),
+        signer: signer.as_ref(),
+    };
+    let reclaimed = compact_embedded_data(&target_path, &existing.manifest, keys)?;
+    refresh_pack_checksums(&target_path);
+
+    log::info!("Executable compacted: {} bytes reclaimed", reclaimed);
+    Ok(reclaimed)
 }
 
-/// 미디어 파일 처리 (압축 적용)
+/// 빌드 서명 키 (설정되지 않았으면 None)
+/// 내장 viewer가 이 키의 공개 키로 빌드되지 않았으면 서명을 확인할 수 없으므로 에러
+fn build_signer() -> Result<Option<BuildSigner>, BuildError> {
+    let signer = BuildSigner::from_env().map_err(|detail| BuildError::LicenseInvalid { detail })?;
+    if let Some(ref signer) = signer {
+        if !viewer_trusts_key(&signer.public_key()) {
+            return Err(BuildError::LicenseInvalid {
+                detail: format!(
+                    "The bundled viewer was not built with the public key of {}",
+                    SIGNING_KEY_ENV
+                ),
+            });
+        }
+    }
+    Ok(signer)
+}
+
+/// 기존 빌드를 다시 쓸 때의 서명 키 (서명된 빌드는 서명 키가 있어야 함)
+fn existing_build_signer(signed: bool) -> Result<Option<BuildSigner>, BuildError> {
+    let signer = build_signer()?;
+    if signed && signer.is_none() {
+        return Err(BuildError::LicenseInvalid {
+            detail: format!("This build is signed; set {} to modify it", SIGNING_KEY_ENV),
+        });
+    }
+    Ok(signer)
+}
+
+/// 패치/압축한 대상이 데이터 팩이면 체크섬 파일 다시 기록
+fn refresh_pack_checksums(target_path: &Path) {
+    if target_path.extension().is_some_and(|extension| extension == "pak") {
+        if let Err(e) = write_pack_checksums(target_path, &[target_path.to_path_buf()]) {
+            log::warn!("Pack checksums not written: {}", e);
+        }
+    }
+}
+
+/// 빌드 전 점검 및 출력 크기 예측
+///
+/// export_as_executable과 같은 요청을 받아 실제로 쓰지 않고 다음을 확인함
+/// - 원본 파일 존재 여부와 크기 합계, 영상 길이 기반 압축 후 예상 크기
+/// - 압축 시 FFmpeg, 앱 아이콘 설정 시 rcedit 존재 여부
+/// - 라이선스 조건 유효성, 출력 경로 쓰기 가능 여부
+/// - 출력/임시 폴더 드라이브 여유 공간
+#[tauri::command]
+fn preflight_export(
+    app: tauri::AppHandle,
+    request: ExportRequest,
+) -> Result<PreflightReport, BuildError> {
+    let mut report = PreflightReport::default();
+    let output_path = PathBuf::from(&request.output_path);
+
+    let compression = request.compression.as_ref().filter(|c| c.enabled);
+    let encrypted = request.encryption.as_ref().is_some_and(|e| e.enabled);
+    let has_videos = request
+        .media_files
+        .iter()
+        .any(|f| is_video_file(&f.mime_type));
+
+    // FFmpeg (영상 압축 시에만 필요)
+    let ffmpeg_path = match compression {
+        Some(_) if has_videos => match find_ffmpeg_path(&app) {
+            Ok(path) => {
+                report.push("ffmpeg", CheckStatus::Ok, path.to_string_lossy());
+                Some(path)
+            }
+            Err(e) => {
+                report.push("ffmpeg", CheckStatus::Error, e.to_string());
+                None
+            }
+        },
+        _ => None,
+    };
+
+    // rcedit (앱 아이콘 설정 시에만 필요)
+    if request.app_icon.is_some() {
+        match find_rcedit_path(&app) {
+            Ok(path) => report.push("rcedit", CheckStatus::Ok, path.to_string_lossy()),
+            Err(e) => report.push("rcedit", CheckStatus::Error, e.to_string()),
+        }
+    }
+
+    if let Some(ref settings) = request.license {
+        let terms = build_signer()
+            .map_err(|e| e.to_string())
+            .and_then(|signer| license_terms(Some(settings), signer.as_ref()));
+        match terms {
+            Ok(_) => report.push("license", CheckStatus::Ok, "License terms are valid"),
+            Err(e) => report.push("license", CheckStatus::Error, e),
+        }
+    }
+
+    // 원본 크기 합계 및 예상 저장 크기
+    let mut payload_bytes = 0u64;
+    let mut missing_files = Vec::new();
+    for file in request.media_files.iter().chain(request.button_files.iter()) {
+        let original_size = match (&file.path, &file.data) {
+            (Some(path), _) => match std::fs::metadata(path) {
+                Ok(metadata) => metadata.len(),
+                Err(_) => {
+                    missing_files.push(file.name.clone());
+                    continue;
+                }
+            },
+            (None, Some(data)) => data.len() as u64,
+            (None, None) => 0,
+        };
+        report.source_bytes += original_size;
+
+        let stored_size = match (compression, &ffmpeg_path) {
+            (Some(settings), Some(ffmpeg)) if is_video_file(&file.mime_type) => {
+                // 경로가 없는 영상은 압축 전에 임시 파일로 기록됨
+                let duration_secs = match file.path {
+                    Some(ref path) => get_video_duration(ffmpeg, Path::new(path)).unwrap_or(0.0),
+                    None => {
+                        report.estimated_temp_bytes += original_size;
+                        0.0
+                    }
+                };
+                let compressed = estimate_compressed_size(settings, duration_secs, original_size);
+                report.estimated_temp_bytes += compressed;
+                compressed
+            }
+            _ => original_size,
+        };
+
+        payload_bytes += if encrypted {
+            sealed_size(stored_size)
+        } else {
+            stored_size
+        };
+    }
+
+    if missing_files.is_empty() {
+        report.push("sources", CheckStatus::Ok, "All source files are available");
+    } else {
+        report.push(
+            "sources",
+            CheckStatus::Error,
+            format!("Source files not found: {}", missing_files.join(", ")),
+        );
+    }
+
+    let icon_bytes = request.app_icon.as_ref().map_or(0, |icon| icon.len() as u64);
+    report.estimated_output_bytes = viewer_executable_size()
+        + payload_bytes
+        + request.project_json.len() as u64
+        + request.additional_projects.iter().map(|json| json.len() as u64).sum::<u64>()
+        + icon_bytes;
+
+    match check_output_writable(&output_path) {
+        Ok(()) => report.push("output", CheckStatus::Ok, "Output path is writable"),
+        Err(e) => report.push("output", CheckStatus::Error, e),
+    }
+
+    check_disk_space(&mut report, &output_path, &std::env::temp_dir());
+
+    // 단일 파일이 4GB를 넘으면 FAT32 USB 등에 복사할 수 없음
+    let split_enabled = request.split.as_ref().is_some_and(|s| s.enabled);
+    if !split_enabled && report.estimated_output_bytes > FAT32_MAX_FILE_SIZE {
+        report.push(
+            "fileSize",
+            CheckStatus::Warning,
+            format!(
+                "Output is about {}; enable split export to copy it to FAT32 drives",
+                format_bytes(report.estimated_output_bytes)
+            ),
+        );
+    }
+
+    Ok(report.finish())
+}
+
+/// ID와 원본 해시가 같은 기존 엔트리 찾기 (해시가 없는 이전 빌드는 항상 다시 기록)
+fn find_unchanged_entry(
+    entries: &[MediaManifestEntry],
+    id: &str,
+    source_hash: &str,
+) -> Option<MediaManifestEntry> {
+    entries
+        .iter()
+        .find(|entry| entry.id == id && entry.source_hash.as_deref() == Some(source_hash))
+        .cloned()
+}
+
+/// 미디어 처리 중 진행률 이벤트와 보고서 기록에 쓰는 내보내기 상태
+struct ExportProgress<'a> {
+    app: &'a tauri::AppHandle,
+    /// 현재 영상 인덱스 (1-based)
+    current_video_index: usize,
+    /// 압축할 전체 영상 수
+    total_videos: usize,
+    report: &'a mut ReportCollector,
+}
+
+/// 미디어 파일 처리 (영상이면 헤더로 길이/코덱 확인, 압축 적용)
 fn process_media_file_for_export(
-    app: &tauri::AppHandle,
     file: ExportMediaFile,
     compression: &Option<CompressionSettings>,
     ffmpeg_path: &Option<PathBuf>,
     compressed_temp_files: &mut Vec<String>,
-    current_video_index: usize,
-    total_videos: usize,
-) -> Result<(String, String, String, MediaSource), String> {
-    // 압축이 비활성화되었거나 영상 파일이 아니면 그대로 반환
-    let should_compress = compression
-        .as_ref()
-        .map(|c| c.enabled && is_video_file(&file.mime_type))
-        .unwrap_or(false);
+    progress: ExportProgress<'_>,
+) -> Result<EmbedFile, BuildError> {
+    let ExportProgress {
+        app,
+        current_video_index,
+        total_videos,
+        report,
+    } = progress;
 
-    if !should_compress {
+    // 영상 파일이 아니거나 FFmpeg가 없으면 (압축하지 않는 빌드) 그대로 반환
+    let Some(ffmpeg) = ffmpeg_path
+        .as_ref()
+        .filter(|_| is_video_file(&file.mime_type))
+    else {
         return Ok(to_media_source(file));
-    }
-
-    let settings = compression.as_ref().unwrap();
-    let ffmpeg = ffmpeg_path.as_ref().unwrap();
+    };
+    let settings = compression.as_ref().filter(|c| c.enabled);
 
     // 입력 파일 경로 결정 (path가 있으면 사용, 없으면 data를 임시 파일로)
     let input_path = if let Some(ref path) = file.path {
@@ -207,19 +883,35 @@ fn process_media_file_for_export(
         // 데이터를 임시 파일로 저장
         let temp_input = get_temp_input_path(&file.name);
         std::fs::write(&temp_input, data)
-            .map_err(|e| format!("Failed to write temp input file: {}", e))?;
+            .map_err(|e| BuildError::io("writeTempInput", Some(&temp_input), e))?;
         compressed_temp_files.push(temp_input.to_string_lossy().to_string());
         temp_input
     } else {
         return Ok(to_media_source(file));
     };
 
+    // 영상 길이/코덱 가져오기 (실패해도 압축은 진행하고 진행률만 표시하지 않음)
+    let info = probe_video(ffmpeg, &input_path).unwrap_or_else(|e| {
+        log::warn!("Video probe failed for {}: {}", file.name, e);
+        let detail = match e {
+            BuildError::ProbeFailed { detail, .. } => detail,
+            e => e.to_string(),
+        };
+        report.warn(Some(&file.name), BuildWarning::ProbeFailed { detail });
+        Default::default()
+    });
+    let source = report.media_source(&file.id);
+    source.duration_seconds = Some(info.duration_secs).filter(|secs| *secs > 0.0);
+    source.codec = info.codec;
+    let duration_secs = info.duration_secs;
+
+    let Some(settings) = settings else {
+        return Ok(to_media_source(file));
+    };
+
     // 압축 출력 경로
     let output_path = get_temp_compressed_path(&file.name);
 
-    // 영상 길이 가져오기
-    let duration_secs = get_video_duration(ffmpeg, &input_path).unwrap_or(0.0);
-
     // 진행률 콜백용 데이터
     let file_name = file.name.clone();
     let app_handle = app.clone();
@@ -260,15 +952,35 @@ fn process_media_file_for_export(
             // 압축된 파일 경로 추적
             compressed_temp_files.push(result.output_path.clone());
 
-            Ok((
-                file.id,
-                file.name,
-                "video/mp4".to_string(), // H.264로 재인코딩됨
-                MediaSource::Path(result.output_path),
-            ))
+            let source = report.media_source(&file.id);
+            source.codec = Some("h264".to_string());
+            source.transcoded = true;
+            if result.compressed_size > result.original_size {
+                report.warn(
+                    Some(&file.name),
+                    BuildWarning::LargerAfterCompression {
+                        original_size: result.original_size,
+                        compressed_size: result.compressed_size,
+                    },
+                );
+            }
+
+            Ok(EmbedFile {
+                id: file.id,
+                name: file.name,
+                mime_type: "video/mp4".to_string(), // H.264로 재인코딩됨
+                source: MediaSource::Path(result.output_path),
+                source_hash: None,
+            })
         }
         Err(e) => {
             log::warn!("Video compression failed for {}: {}. Using original.", file.name, e);
+            report.warn(
+                Some(&file.name),
+                BuildWarning::CompressionFallback {
+                    detail: e.to_string(),
+                },
+            );
             // 압축 실패 시 원본 사용
             Ok(to_media_source(file))
         }
@@ -307,7 +1019,7 @@ fn set_executable_icon(
     app: &tauri::AppHandle,
     exe_path: &PathBuf,
     icon_data: &[u8],
-) -> Result<(), String> {
+) -> Result<(), BuildError> {
     // 임시 ICO 파일 경로
     let temp_dir = std::env::temp_dir();
     let ico_path = temp_dir.join("temp_icon.ico");
@@ -328,7 +1040,7 @@ fn set_executable_icon(
 }
 
 /// rcedit 경로 찾기
-fn find_rcedit_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+fn find_rcedit_path(app: &tauri::AppHandle) -> Result<PathBuf, BuildError> {
     // 1. 번들된 리소스에서 찾기 (production)
     if let Ok(path) = app
         .path()
@@ -347,23 +1059,94 @@ fn find_rcedit_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
         return Ok(dev_path);
     }
 
-    Err("rcedit-x64.exe not found".to_string())
+    Err(BuildError::RceditNotFound)
+}
+
+/// 문제 보고용 진단 번들 저장 (로그, 환경 정보, FFmpeg/rcedit 정보), 넣은 파일 수 반환
+#[tauri::command]
+fn export_diagnostics(app: tauri::AppHandle, output_path: String) -> Result<usize, BuildError> {
+    log::info!("Exporting diagnostics bundle: {}", output_path);
+    let log_dir = app
+        .path()
+        .app_log_dir()
+        .map_err(|e| BuildError::Io {
+            operation: "findLogDir",
+            path: None,
+            detail: e.to_string(),
+        })?;
+
+    let mut environment = MakerEnvironment::current(&log_dir);
+    match find_ffmpeg_path(&app) {
+        Ok(path) => {
+            environment.ffmpeg_version = get_ffmpeg_version(&path);
+            environment.ffmpeg_path = Some(path.display().to_string());
+        }
+        Err(e) => environment.tool_errors.push(e.to_string()),
+    }
+    match find_rcedit_path(&app) {
+        Ok(path) => environment.rcedit_path = Some(path.display().to_string()),
+        Err(e) => environment.tool_errors.push(e.to_string()),
+    }
+
+    let files = log_files(&log_dir, "logs");
+    let count = write_bundle(Path::new(&output_path), &files, &environment)
+        .inspect_err(|e| log::error!("Failed to export diagnostics: {}", e))
+        .map_err(|detail| BuildError::Io {
+            operation: "writeDiagnostics",
+            path: Some(output_path.clone()),
+            detail,
+        })?;
+    log::info!("Diagnostics bundle written: {} ({} files)", output_path, count);
+    Ok(count)
+}
+
+/// 키오스크 관리자 PIN 해시 (프로젝트 설정의 adminPinHash에 저장)
+#[tauri::command]
+fn hash_admin_pin(pin: String) -> Result<String, String> {
+    crypto::hash_admin_pin(&pin)
+}
+
+/// 로그 플러그인 (릴리스 빌드도 앱 로그 폴더에 기록하고 최대 크기를 넘으면 새 파일로 교체)
+/// 모든 수준을 받아 두고 실제 수준은 log::set_max_level로 조절
+fn log_plugin<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
+    let mut targets = vec![Target::new(TargetKind::LogDir {
+        file_name: Some(LOG_FILE_NAME.to_string()),
+    })];
+    if cfg!(debug_assertions) {
+        targets.push(Target::new(TargetKind::Stdout));
+    }
+
+    tauri_plugin_log::Builder::default()
+        .targets(targets)
+        .level(log::LevelFilter::Trace)
+        .max_file_size(MAX_LOG_FILE_BYTES)
+        .rotation_strategy(RotationStrategy::KeepSome(KEEP_LOG_FILES))
+        .timezone_strategy(TimezoneStrategy::UseLocal)
+        .build()
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        .plugin(log_plugin())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
-        .invoke_handler(tauri::generate_handler![export_as_executable])
-        .setup(|app| {
-            if cfg!(debug_assertions) {
-                app.handle().plugin(
-                    tauri_plugin_log::Builder::default()
-                        .level(log::LevelFilter::Info)
-                        .build(),
-                )?;
-            }
+        .invoke_handler(tauri::generate_handler![
+            export_as_executable,
+            patch_executable,
+            compact_executable,
+            preflight_export,
+            export_diagnostics,
+            hash_admin_pin
+        ])
+        .setup(|_app| {
+            // 실행 인자/환경 변수로 로그 수준 지정 (--log-level debug, VISTUT_LOG_LEVEL=debug)
+            let launch_level = launch_log_level().unwrap_or_else(|e| {
+                log::warn!("Launch log level ignored: {}", e);
+                None
+            });
+            log::set_max_level(launch_level.unwrap_or(DEFAULT_LOG_LEVEL));
+            log::info!("Tutorial Maker {} started", env!("CARGO_PKG_VERSION"));
             Ok(())
         })
         .run(tauri::generate_context!())
@@ -0,0 +1,117 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use ed25519_dalek::{Signer, SigningKey};
+use serde::{Deserialize, Serialize};
+
+/// 빌드 서명 키를 전달하는 환경 변수 (Ed25519 비밀 키 32바이트, base64)
+/// 비밀 키는 maker를 실행하는 PC에만 두고, player에는 빌드 시
+/// VISTUT_LICENSE_PUBLIC_KEY로 공개 키만 넣음
+pub const SIGNING_KEY_ENV: &str = "VISTUT_LICENSE_SIGNING_KEY";
+
+/// Ed25519 서명 크기 (player와 동일해야 함)
+pub const SIGNATURE_SIZE: usize = 64;
+
+/// 라이선스 조건 (Export 요청)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LicenseSettings {
+    /// 사용 시작 시각 (Unix ms)
+    #[serde(default)]
+    pub not_before: Option<u64>,
+    /// 만료 시각 (Unix ms)
+    #[serde(default)]
+    pub not_after: Option<u64>,
+    /// 실행을 허용할 머신 ID 목록 (비어 있으면 제한 없음)
+    #[serde(default)]
+    pub machine_ids: Vec<String>,
+}
+
+impl LicenseSettings {
+    /// 제한 조건이 하나라도 있는지 확인
+    pub fn has_restrictions(&self) -> bool {
+        self.not_before.is_some() || self.not_after.is_some() || !self.machine_ids.is_empty()
+    }
+}
+
+/// 트레일러 서명 키
+/// 매직 바이트와 매니페스트(암호화된 경우 봉인된 매니페스트)에 서명하므로
+/// 매니페스트 안의 라이선스 조건을 바꾸거나 지우면 서명이 맞지 않음
+pub struct BuildSigner {
+    key: SigningKey,
+}
+
+impl BuildSigner {
+    /// 환경 변수에서 서명 키 읽기 (설정되지 않았으면 None, 서명하지 않은 빌드를 만듦)
+    pub fn from_env() -> Result<Option<Self>, String> {
+        let Some(encoded) = std::env::var(SIGNING_KEY_ENV)
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+        else {
+            return Ok(None);
+        };
+
+        let bytes = BASE64
+            .decode(encoded.trim())
+            .map_err(|e| format!("Invalid license signing key: {}", e))?;
+        let secret: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| "License signing key must be 32 bytes".to_string())?;
+
+        Ok(Some(Self {
+            key: SigningKey::from_bytes(&secret),
+        }))
+    }
+
+    /// 공개 키 (base64, player 빌드의 VISTUT_LICENSE_PUBLIC_KEY와 같아야 함)
+    pub fn public_key(&self) -> String {
+        BASE64.encode(self.key.verifying_key().as_bytes())
+    }
+
+    /// 트레일러의 매직 바이트와 매니페스트 바이트에 서명
+    pub fn sign(&self, magic: &[u8], manifest: &[u8]) -> [u8; SIGNATURE_SIZE] {
+        let mut message = Vec::with_capacity(magic.len() + manifest.len());
+        message.extend_from_slice(magic);
+        message.extend_from_slice(manifest);
+        self.key.sign(&message).to_bytes()
+    }
+}
+
+/// 매니페스트에 기록할 라이선스 조건
+/// 서명 키가 있으면 제한이 없어도 빈 조건을 기록 (player는 라이선스가 없는 빌드를 거부)
+/// 제한이 있는데 서명 키가 없으면 player가 확인할 수 없으므로 에러
+pub fn license_terms(
+    settings: Option<&LicenseSettings>,
+    signer: Option<&BuildSigner>,
+) -> Result<Option<LicenseSettings>, String> {
+    let Some(settings) = settings.filter(|settings| settings.has_restrictions()) else {
+        return Ok(signer.map(|_| LicenseSettings::default()));
+    };
+
+    if signer.is_none() {
+        return Err(format!(
+            "License signing key is not configured (set {})",
+            SIGNING_KEY_ENV
+        ));
+    }
+
+    if let (Some(not_before), Some(not_after)) = (settings.not_before, settings.not_after) {
+        if not_before >= not_after {
+            return Err("License start must be earlier than its expiry".to_string());
+        }
+    }
+
+    Ok(Some(LicenseSettings {
+        not_before: settings.not_before,
+        not_after: settings.not_after,
+        machine_ids: settings
+            .machine_ids
+            .iter()
+            .map(|id| normalize_machine_id(id))
+            .filter(|id| !id.is_empty())
+            .collect(),
+    }))
+}
+
+/// 머신 ID 비교용 정규화 (공백 제거, 대문자)
+fn normalize_machine_id(id: &str) -> String {
+    id.trim().to_uppercase()
+}
@@ -0,0 +1,166 @@
+use serde::Serialize;
+use std::fs::{self, OpenOptions};
+use std::path::{Component, Path};
+
+/// 여유 공간 판단 시 추가로 확보할 여유분 (64MB)
+const SPACE_MARGIN_BYTES: u64 = 64 * 1024 * 1024;
+
+/// FAT32 파일 크기 제한 (4GB - 1)
+pub const FAT32_MAX_FILE_SIZE: u64 = u32::MAX as u64;
+
+/// 점검 항목 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Ok,
+    /// 빌드는 가능하지만 확인이 필요함
+    Warning,
+    /// 빌드하면 실패함
+    Error,
+}
+
+/// 점검 항목
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreflightCheck {
+    /// 항목 식별자 (ffmpeg, rcedit, output, outputSpace, tempSpace 등)
+    pub id: String,
+    pub status: CheckStatus,
+    pub message: String,
+}
+
+/// 빌드 전 점검 결과
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreflightReport {
+    /// 빌드 진행 가능 여부 (Error 항목이 없으면 true)
+    pub ok: bool,
+    /// 원본 파일 크기 합계
+    pub source_bytes: u64,
+    /// 예상 출력 크기 (exe + 데이터 팩)
+    pub estimated_output_bytes: u64,
+    /// 빌드 중 임시 폴더에 필요한 예상 크기 (압축된 영상 등)
+    pub estimated_temp_bytes: u64,
+    /// 출력 폴더 드라이브의 여유 공간 (확인 실패 시 None)
+    pub output_free_bytes: Option<u64>,
+    /// 임시 폴더 드라이브의 여유 공간 (확인 실패 시 None)
+    pub temp_free_bytes: Option<u64>,
+    pub checks: Vec<PreflightCheck>,
+}
+
+impl PreflightReport {
+    /// 점검 항목 추가
+    pub fn push(&mut self, id: &str, status: CheckStatus, message: impl Into<String>) {
+        self.checks.push(PreflightCheck {
+            id: id.to_string(),
+            status,
+            message: message.into(),
+        });
+    }
+
+    /// Error 항목 유무로 진행 가능 여부 확정
+    pub fn finish(mut self) -> Self {
+        self.ok = !self
+            .checks
+            .iter()
+            .any(|check| check.status == CheckStatus::Error);
+        self
+    }
+}
+
+/// 출력 경로에 쓸 수 있는지 확인
+/// 기존 파일이 실행 중(Windows에서 잠김)이거나 읽기 전용이면 덮어쓸 수 없음
+pub fn check_output_writable(output_path: &Path) -> Result<(), String> {
+    let output_dir = parent_dir(output_path);
+    if !output_dir.is_dir() {
+        return Err(format!("Output folder does not exist: {}", output_dir.display()));
+    }
+
+    if output_path.exists() {
+        OpenOptions::new()
+            .write(true)
+            .open(output_path)
+            .map_err(|e| format!("Output file cannot be overwritten: {}", e))?;
+        return Ok(());
+    }
+
+    let probe = output_dir.join(format!(".viswave_preflight_{}", std::process::id()));
+    fs::File::create(&probe).map_err(|e| format!("Output folder is not writable: {}", e))?;
+    let _ = fs::remove_file(&probe);
+    Ok(())
+}
+
+/// 출력/임시 폴더 여유 공간 확인
+/// 같은 드라이브면 두 용량의 합이 필요함
+pub fn check_disk_space(report: &mut PreflightReport, output_path: &Path, temp_dir: &Path) {
+    let output_dir = parent_dir(output_path);
+    report.output_free_bytes = fs4::available_space(output_dir).ok();
+    report.temp_free_bytes = fs4::available_space(temp_dir).ok();
+
+    let shared = same_volume(output_dir, temp_dir);
+    let output_needed = if shared {
+        report.estimated_output_bytes + report.estimated_temp_bytes
+    } else {
+        report.estimated_output_bytes
+    };
+
+    push_space_check(report, "outputSpace", report.output_free_bytes, output_needed);
+    if !shared {
+        push_space_check(
+            report,
+            "tempSpace",
+            report.temp_free_bytes,
+            report.estimated_temp_bytes,
+        );
+    }
+}
+
+fn push_space_check(report: &mut PreflightReport, id: &str, free: Option<u64>, needed: u64) {
+    match free {
+        Some(free) if free < needed.saturating_add(SPACE_MARGIN_BYTES) => report.push(
+            id,
+            CheckStatus::Error,
+            format!(
+                "Not enough free space: {} needed, {} available",
+                format_bytes(needed),
+                format_bytes(free)
+            ),
+        ),
+        Some(free) => report.push(
+            id,
+            CheckStatus::Ok,
+            format!("{} needed, {} available", format_bytes(needed), format_bytes(free)),
+        ),
+        None => report.push(id, CheckStatus::Warning, "Could not determine free space"),
+    }
+}
+
+/// 파일이 있는 폴더 (상대 경로의 파일명만 있으면 현재 폴더)
+fn parent_dir(path: &Path) -> &Path {
+    path.parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."))
+}
+
+/// 같은 드라이브인지 확인 (Windows 드라이브 문자/UNC 접두사 기준, 그 외는 같은 볼륨으로 간주)
+fn same_volume(a: &Path, b: &Path) -> bool {
+    fn prefix(path: &Path) -> Option<String> {
+        match path.components().next() {
+            Some(Component::Prefix(prefix)) => {
+                Some(prefix.as_os_str().to_string_lossy().to_uppercase())
+            }
+            _ => None,
+        }
+    }
+    prefix(a) == prefix(b)
+}
+
+/// 사람이 읽기 쉬운 크기 표시
+pub fn format_bytes(bytes: u64) -> String {
+    const MB: f64 = 1024.0 * 1024.0;
+    if bytes as f64 >= 1024.0 * MB {
+        format!("{:.2} GB", bytes as f64 / (1024.0 * MB))
+    } else {
+        format!("{:.1} MB", bytes as f64 / MB)
+    }
+}
@@ -0,0 +1,437 @@
+use crate::diagnostics::civil_from_days;
+use crate::embedded::{BuildManifest, MediaManifestEntry, PayloadEncoding};
+use crate::error::BuildError;
+use crate::preflight::format_bytes;
+use crate::video::CompressionSettings;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// 빌드 중 알게 된 원본 정보 (압축 전 크기, 영상 길이/코덱)
+#[derive(Debug, Clone, Default)]
+pub struct SourceInfo {
+    pub original_size: u64,
+    /// 영상 길이 (초, FFmpeg로 헤더를 읽어 확인)
+    pub duration_seconds: Option<f64>,
+    /// 저장된 영상의 코덱 (다시 인코딩했으면 h264)
+    pub codec: Option<String>,
+    /// FFmpeg로 다시 인코딩함
+    pub transcoded: bool,
+}
+
+/// 빌드 경고 종류
+/// 보고서에는 code와 필드로 기록되어 UI에서 메시지를 현지화함
+/// (code 값은 UI와 저장된 보고서가 의존하므로 변경하지 말 것)
+#[derive(Debug, Clone, Serialize)]
+#[serde(
+    tag = "code",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum BuildWarning {
+    /// 원본 경로와 데이터가 모두 없어 빈 데이터로 저장함
+    EmptySource,
+    /// FFmpeg가 없어 압축하지 않는 영상의 길이/코덱을 확인하지 못함
+    ProbeUnavailable,
+    /// 영상 헤더를 읽지 못함
+    ProbeFailed { detail: String },
+    /// 압축 후 크기가 원본보다 큼
+    LargerAfterCompression {
+        original_size: u64,
+        compressed_size: u64,
+    },
+    /// 영상 압축에 실패하여 원본을 저장함
+    CompressionFallback { detail: String },
+    /// 핫 폴더 업데이트용 데이터 팩 체크섬을 기록하지 못함
+    ChecksumsNotWritten { detail: String },
+}
+
+impl fmt::Display for BuildWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptySource => write!(f, "Source file is missing; stored as empty data"),
+            Self::ProbeUnavailable => {
+                write!(
+                    f,
+                    "FFmpeg not found; video duration and codec were not checked"
+                )
+            }
+            Self::ProbeFailed { detail } => write!(f, "Failed to read video header: {}", detail),
+            Self::LargerAfterCompression {
+                original_size,
+                compressed_size,
+            } => write!(
+                f,
+                "Compressed video is larger than the original ({} -> {})",
+                format_bytes(*original_size),
+                format_bytes(*compressed_size)
+            ),
+            Self::CompressionFallback { detail } => {
+                write!(
+                    f,
+                    "Video compression failed; using the original: {}",
+                    detail
+                )
+            }
+            Self::ChecksumsNotWritten { detail } => {
+                write!(f, "Failed to write data pack checksums: {}", detail)
+            }
+        }
+    }
+}
+
+/// 빌드 경고 (빌드는 완료되었지만 확인이 필요한 항목)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportWarning {
+    #[serde(flatten)]
+    pub warning: BuildWarning,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// 영어 설명 (로그와 HTML 보고서용)
+    pub message: String,
+}
+
+/// 보고서의 미디어/버튼 이미지 엔트리
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportEntry {
+    pub id: String,
+    pub name: String,
+    pub mime_type: String,
+    /// 변환 전 원본 크기
+    pub original_size: u64,
+    /// 저장된 크기 (압축/암호화 후)
+    pub final_size: u64,
+    /// 줄어든 비율 (0.25 = 25% 감소, 음수면 커짐)
+    pub compression_ratio: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub codec: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<f64>,
+    pub transcoded: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<PayloadEncoding>,
+    /// 원본 소스의 SHA-256
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    /// 데이터 파일(exe 또는 데이터 팩) 안의 위치
+    pub offset: u64,
+    /// 내용이 같은 앞의 엔트리와 영역을 공유 (추가로 저장하지 않음)
+    pub deduplicated: bool,
+}
+
+/// 빌드 보고서 (Export 결과로 반환하고, 요청하면 exe 옆에 JSON/HTML로 저장)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildReport {
+    pub maker_version: &'static str,
+    pub created_at_ms: u64,
+    /// 빌드에 걸린 시간
+    pub elapsed_ms: u64,
+    pub output_path: String,
+    pub pack_files: Vec<String>,
+    /// 최종 크기 (exe + 데이터 팩)
+    pub final_size: u64,
+    /// 미디어/버튼 이미지 원본 크기 합계
+    pub original_size: u64,
+    pub deduplicated_bytes: u64,
+    pub encrypted: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compression: Option<CompressionSettings>,
+    pub media: Vec<ReportEntry>,
+    pub buttons: Vec<ReportEntry>,
+    pub warnings: Vec<ReportWarning>,
+}
+
+/// 빌드 중 보고서 정보 수집
+pub struct ReportCollector {
+    started: Instant,
+    media: HashMap<String, SourceInfo>,
+    buttons: HashMap<String, SourceInfo>,
+    warnings: Vec<ReportWarning>,
+}
+
+impl Default for ReportCollector {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            media: HashMap::new(),
+            buttons: HashMap::new(),
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl ReportCollector {
+    /// 미디어 원본 정보 (처리하면서 채움)
+    pub fn media_source(&mut self, id: &str) -> &mut SourceInfo {
+        self.media.entry(id.to_string()).or_default()
+    }
+
+    /// 버튼 이미지 원본 크기
+    pub fn button_source(&mut self, id: &str, original_size: u64) {
+        self.buttons.insert(
+            id.to_string(),
+            SourceInfo {
+                original_size,
+                ..SourceInfo::default()
+            },
+        );
+    }
+
+    pub fn warn(&mut self, file: Option<&str>, warning: BuildWarning) {
+        self.warnings.push(ReportWarning {
+            message: warning.to_string(),
+            warning,
+            file: file.map(str::to_string),
+        });
+    }
+
+    /// 기록된 매니페스트와 출력 파일로 보고서 완성
+    pub fn finish(
+        self,
+        manifest: &BuildManifest,
+        output_path: &Path,
+        pack_files: &[PathBuf],
+        deduplicated_bytes: u64,
+        compression: Option<CompressionSettings>,
+        encrypted: bool,
+    ) -> BuildReport {
+        // 중복 제거된 엔트리는 앞의 엔트리와 같은 오프셋을 가리킴
+        let mut offsets = HashSet::new();
+        let media = report_entries(&manifest.media, &self.media, &mut offsets);
+        let buttons = report_entries(&manifest.buttons, &self.buttons, &mut offsets);
+
+        let final_size = std::iter::once(output_path)
+            .chain(pack_files.iter().map(PathBuf::as_path))
+            .filter_map(|path| fs::metadata(path).ok())
+            .map(|metadata| metadata.len())
+            .sum();
+
+        BuildReport {
+            maker_version: env!("CARGO_PKG_VERSION"),
+            created_at_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_millis() as u64)
+                .unwrap_or_default(),
+            elapsed_ms: self.started.elapsed().as_millis() as u64,
+            output_path: output_path.display().to_string(),
+            pack_files: pack_files
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect(),
+            final_size,
+            original_size: media
+                .iter()
+                .chain(buttons.iter())
+                .map(|entry| entry.original_size)
+                .sum(),
+            deduplicated_bytes,
+            encrypted,
+            compression,
+            media,
+            buttons,
+            warnings: self.warnings,
+        }
+    }
+}
+
+fn report_entries(
+    entries: &[MediaManifestEntry],
+    sources: &HashMap<String, SourceInfo>,
+    offsets: &mut HashSet<u64>,
+) -> Vec<ReportEntry> {
+    entries
+        .iter()
+        .map(|entry| {
+            let source = sources.get(&entry.id).cloned().unwrap_or_default();
+            // 원본 크기를 모르면 zstd 압축 전 크기 또는 저장된 크기로 대체
+            let original_size = match source.original_size {
+                0 => entry.original_size.unwrap_or(entry.size),
+                size => size,
+            };
+            let compression_ratio = if original_size > 0 {
+                1.0 - entry.size as f64 / original_size as f64
+            } else {
+                0.0
+            };
+
+            ReportEntry {
+                id: entry.id.clone(),
+                name: entry.name.clone(),
+                mime_type: entry.mime_type.clone(),
+                original_size,
+                final_size: entry.size,
+                compression_ratio,
+                codec: source.codec,
+                duration_seconds: source.duration_seconds,
+                transcoded: source.transcoded,
+                encoding: entry.encoding,
+                hash: entry.source_hash.clone(),
+                offset: entry.offset,
+                deduplicated: !offsets.insert(entry.offset),
+            }
+        })
+        .collect()
+}
+
+/// 보고서를 exe 옆에 JSON과 HTML로 저장 (<이름>.report.json, <이름>.report.html)
+pub fn write_report_files(
+    report: &BuildReport,
+    output_path: &Path,
+) -> Result<Vec<PathBuf>, BuildError> {
+    let json_path = output_path.with_extension("report.json");
+    let json = serde_json::to_vec_pretty(report).map_err(|e| BuildError::Io {
+        operation: "writeReport",
+        path: Some(json_path.display().to_string()),
+        detail: e.to_string(),
+    })?;
+    fs::write(&json_path, json).map_err(|e| BuildError::io("writeReport", Some(&json_path), e))?;
+
+    let html_path = output_path.with_extension("report.html");
+    fs::write(&html_path, render_html(report))
+        .map_err(|e| BuildError::io("writeReport", Some(&html_path), e))?;
+
+    Ok(vec![json_path, html_path])
+}
+
+/// HTML 보고서 (외부 리소스 없이 한 파일로 열람)
+fn render_html(report: &BuildReport) -> String {
+    let mut html = String::new();
+    html.push_str(
+        "<!DOCTYPE html>\n<html lang=\"ko\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>빌드 보고서</title>\n<style>\n\
+         body { font-family: sans-serif; margin: 2em; color: #1f2937; }\n\
+         table { border-collapse: collapse; margin-bottom: 2em; }\n\
+         th, td { border: 1px solid #d1d5db; padding: 4px 8px; text-align: left; }\n\
+         th { background: #f3f4f6; }\n\
+         td.number { text-align: right; }\n\
+         .warning { color: #b45309; }\n\
+         </style>\n</head>\n<body>\n",
+    );
+
+    let _ = writeln!(html, "<h1>빌드 보고서</h1>");
+    let _ = writeln!(html, "<table>");
+    let summary = [
+        ("출력 파일", escape_html(&report.output_path)),
+        ("데이터 팩", escape_html(&report.pack_files.join(", "))),
+        ("최종 크기", format_bytes(report.final_size)),
+        ("원본 크기", format_bytes(report.original_size)),
+        ("중복 제거", format_bytes(report.deduplicated_bytes)),
+        (
+            "암호화",
+            if report.encrypted { "예" } else { "아니오" }.to_string(),
+        ),
+        (
+            "빌드 시간",
+            format!("{:.1}초", report.elapsed_ms as f64 / 1000.0),
+        ),
+        ("빌드 시각 (UTC)", format_utc(report.created_at_ms)),
+        ("Tutorial Maker", report.maker_version.to_string()),
+    ];
+    for (label, value) in summary {
+        let _ = writeln!(html, "<tr><th>{}</th><td>{}</td></tr>", label, value);
+    }
+    let _ = writeln!(html, "</table>");
+
+    if !report.warnings.is_empty() {
+        let _ = writeln!(html, "<h2>경고</h2>\n<ul>");
+        for warning in &report.warnings {
+            let file = warning
+                .file
+                .as_deref()
+                .map(|file| format!("{}: ", escape_html(file)))
+                .unwrap_or_default();
+            let _ = writeln!(
+                html,
+                "<li class=\"warning\">{}{}</li>",
+                file,
+                escape_html(&warning.message)
+            );
+        }
+        let _ = writeln!(html, "</ul>");
+    }
+
+    for (title, entries) in [("미디어", &report.media), ("버튼 이미지", &report.buttons)] {
+        if entries.is_empty() {
+            continue;
+        }
+        let _ = writeln!(html, "<h2>{}</h2>\n<table>", title);
+        let _ = writeln!(
+            html,
+            "<tr><th>이름</th><th>형식</th><th>코덱</th><th>길이</th><th>원본</th>\
+             <th>저장</th><th>감소율</th><th>오프셋</th><th>SHA-256</th><th>비고</th></tr>"
+        );
+        for entry in entries {
+            let mut notes = Vec::new();
+            if entry.transcoded {
+                notes.push("재인코딩");
+            }
+            if entry.encoding.is_some() {
+                notes.push("zstd");
+            }
+            if entry.deduplicated {
+                notes.push("중복 제거");
+            }
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td class=\"number\">{}</td>\
+                 <td class=\"number\">{}</td><td class=\"number\">{}</td>\
+                 <td class=\"number\">{:.1}%</td><td class=\"number\">{}</td>\
+                 <td><code>{}</code></td><td>{}</td></tr>",
+                escape_html(&entry.name),
+                escape_html(&entry.mime_type),
+                escape_html(entry.codec.as_deref().unwrap_or("")),
+                entry
+                    .duration_seconds
+                    .map(|seconds| format!("{:.1}초", seconds))
+                    .unwrap_or_default(),
+                format_bytes(entry.original_size),
+                format_bytes(entry.final_size),
+                entry.compression_ratio * 100.0,
+                entry.offset,
+                escape_html(entry.hash.as_deref().unwrap_or("")),
+                notes.join(", ")
+            );
+        }
+        let _ = writeln!(html, "</table>");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 빌드 시각 표시 (YYYY-MM-DD HH:MM:SS, UTC)
+fn format_utc(timestamp_ms: u64) -> String {
+    let seconds = (timestamp_ms / 1000) as i64;
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    let of_day = seconds.rem_euclid(86_400);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        of_day / 3600,
+        of_day % 3600 / 60,
+        of_day % 60
+    )
+}
@@ -1,9 +1,14 @@
+use crate::error::BuildError;
 use serde::{Deserialize, Serialize};
-use std::io::{BufRead, BufReader};
+use std::ffi::OsStr;
+use std::io::{BufRead, BufReader, Read};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use tauri::Manager;
 
+/// 압축 시 오디오 비트레이트 (bps, -b:a 128k)
+const AUDIO_BITRATE: f64 = 128_000.0;
+
 /// 영상 압축 품질 설정
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -33,6 +38,15 @@ impl CompressionQuality {
         }
     }
 
+    /// 1080p 기준 예상 영상 비트레이트 (bps, 빌드 전 용량 예측용)
+    fn estimated_video_bitrate(&self) -> f64 {
+        match self {
+            CompressionQuality::Low => 2_000_000.0,
+            CompressionQuality::Medium => 4_000_000.0,
+            CompressionQuality::High => 8_000_000.0,
+        }
+    }
+
     /// 프리셋 반환 (인코딩 속도 vs 압축률)
     fn preset(&self) -> &'static str {
         match self {
@@ -75,8 +89,17 @@ pub struct CompressionResult {
     pub compression_ratio: f64,
 }
 
+/// 영상 정보 (ffmpeg -i 출력에서 읽음)
+#[derive(Debug, Clone, Default)]
+pub struct VideoInfo {
+    /// 길이 (초, 알 수 없으면 0)
+    pub duration_secs: f64,
+    /// 첫 번째 영상 스트림의 코덱 (예: h264, hevc)
+    pub codec: Option<String>,
+}
+
 /// FFmpeg 경로 찾기
-pub fn find_ffmpeg_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+pub fn find_ffmpeg_path(app: &tauri::AppHandle) -> Result<PathBuf, BuildError> {
     // 1. 번들된 리소스에서 찾기 (production)
     if let Ok(path) = app
         .path()
@@ -123,7 +146,51 @@ pub fn find_ffmpeg_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
         }
     }
 
-    Err("ffmpeg.exe를 찾을 수 없습니다. FFmpeg를 설치하거나 resources 폴더에 넣어주세요.\n\nFFmpeg 다운로드: https://ffmpeg.org/download.html".to_string())
+    Err(BuildError::FfmpegNotFound)
+}
+
+/// FFmpeg 버전 (ffmpeg -version의 첫 줄, 진단 정보용)
+pub fn get_ffmpeg_version(ffmpeg_path: &Path) -> Option<String> {
+    let output = Command::new(ffmpeg_path).arg("-version").output().ok()?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string())
+}
+
+/// 로그에 남길 명령줄 (공백이 있는 인자는 따옴표로 감쌈)
+fn command_line<S: AsRef<OsStr>>(program: &Path, args: &[S]) -> String {
+    std::iter::once(program.as_os_str())
+        .chain(args.iter().map(AsRef::as_ref))
+        .map(|arg| {
+            let arg = arg.to_string_lossy();
+            if arg.contains(' ') {
+                format!("\"{}\"", arg)
+            } else {
+                arg.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 에러에 표시할 파일 이름
+fn display_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or(path.as_os_str())
+        .to_string_lossy()
+        .to_string()
+}
+
+/// FFmpeg stderr의 마지막 메시지 (보통 실패 원인, 전체는 로그에 기록)
+fn last_error_line(stderr: &str) -> String {
+    stderr
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or("FFmpeg exited with an error")
+        .to_string()
 }
 
 /// 영상 파일인지 확인
@@ -132,37 +199,88 @@ pub fn is_video_file(mime_type: &str) -> bool {
 }
 
 /// 영상 길이(duration) 가져오기 (초 단위)
-pub fn get_video_duration(ffmpeg_path: &Path, input_path: &Path) -> Result<f64, String> {
-    // ffprobe 대신 ffmpeg -i 로 duration 얻기
+pub fn get_video_duration(ffmpeg_path: &Path, input_path: &Path) -> Result<f64, BuildError> {
+    probe_video(ffmpeg_path, input_path).map(|info| info.duration_secs)
+}
+
+/// 영상 길이와 코덱 가져오기
+/// 출력 없이 ffmpeg -i 로 헤더만 읽음 (전체를 디코딩하지 않으므로 큰 파일도 바로 끝남)
+pub fn probe_video(ffmpeg_path: &Path, input_path: &Path) -> Result<VideoInfo, BuildError> {
+    let input = input_path.to_string_lossy();
+    let args = ["-hide_banner", "-i", input.as_ref()];
+    log::debug!("Running FFmpeg: {}", command_line(ffmpeg_path, &args));
     let output = Command::new(ffmpeg_path)
-        .args(["-i", &input_path.to_string_lossy(), "-f", "null", "-"])
+        .args(args)
         .stderr(Stdio::piped())
         .output()
-        .map_err(|e| format!("Failed to get video duration: {}", e))?;
+        .map_err(|e| BuildError::ProbeFailed {
+            file: display_name(input_path),
+            detail: e.to_string(),
+        })?;
 
+    // 출력 파일이 없어 항상 실패 코드로 끝나므로 종료 코드 대신 입력 정보가 있는지로 판단
     let stderr = String::from_utf8_lossy(&output.stderr);
+    log::debug!("FFmpeg output:\n{}", stderr.trim_end());
+    if !stderr.lines().any(|line| line.starts_with("Input #")) {
+        return Err(BuildError::ProbeFailed {
+            file: display_name(input_path),
+            detail: last_error_line(&stderr),
+        });
+    }
 
-    // Duration: 00:00:10.50 형식 파싱
+    // Duration을 찾지 못하면 0
+    let mut info = VideoInfo::default();
     for line in stderr.lines() {
-        if line.contains("Duration:") {
-            if let Some(duration_str) = line.split("Duration:").nth(1) {
-                if let Some(time_str) = duration_str.split(',').next() {
-                    let time_str = time_str.trim();
-                    // HH:MM:SS.ms 파싱
-                    let parts: Vec<&str> = time_str.split(':').collect();
-                    if parts.len() == 3 {
-                        let hours: f64 = parts[0].parse().unwrap_or(0.0);
-                        let minutes: f64 = parts[1].parse().unwrap_or(0.0);
-                        let seconds: f64 = parts[2].parse().unwrap_or(0.0);
-                        return Ok(hours * 3600.0 + minutes * 60.0 + seconds);
-                    }
-                }
-            }
+        if info.duration_secs == 0.0 && line.contains("Duration:") {
+            info.duration_secs = parse_duration(line).unwrap_or(0.0);
+        }
+        // Stream #0:0(und): Video: h264 (High) (avc1 / 0x31637661), ... 형식
+        if info.codec.is_none() && line.trim_start().starts_with("Stream #") {
+            info.codec = line
+                .split("Video: ")
+                .nth(1)
+                .and_then(|rest| rest.split([' ', ',']).next())
+                .filter(|codec| !codec.is_empty())
+                .map(str::to_string);
         }
     }
 
-    // Duration을 찾지 못하면 기본값 반환
-    Ok(0.0)
+    Ok(info)
+}
+
+/// Duration: 00:00:10.50 형식 파싱
+fn parse_duration(line: &str) -> Option<f64> {
+    let duration_str = line.split("Duration:").nth(1)?;
+    let time_str = duration_str.split(',').next()?.trim();
+    // HH:MM:SS.ms 파싱
+    let parts: Vec<&str> = time_str.split(':').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    let hours: f64 = parts[0].parse().unwrap_or(0.0);
+    let minutes: f64 = parts[1].parse().unwrap_or(0.0);
+    let seconds: f64 = parts[2].parse().unwrap_or(0.0);
+    Some(hours * 3600.0 + minutes * 60.0 + seconds)
+}
+
+/// 압축 후 예상 크기 (길이를 알 수 없으면 원본 크기)
+/// 해상도 제한이 있으면 1080p 대비 화면 면적 비율로 비트레이트를 줄여서 계산
+pub fn estimate_compressed_size(
+    settings: &CompressionSettings,
+    duration_secs: f64,
+    original_size: u64,
+) -> u64 {
+    if duration_secs <= 0.0 {
+        return original_size;
+    }
+
+    let scale = settings
+        .max_height
+        .map(|height| (height as f64 / 1080.0).min(1.0).powi(2))
+        .unwrap_or(1.0);
+    let bitrate = settings.quality.estimated_video_bitrate() * scale + AUDIO_BITRATE;
+
+    (duration_secs * bitrate / 8.0) as u64
 }
 
 /// 영상 압축 실행 (진행률 콜백 포함)
@@ -173,16 +291,21 @@ pub fn compress_video_with_progress<F>(
     settings: &CompressionSettings,
     duration_secs: f64,
     mut on_progress: F,
-) -> Result<CompressionResult, String>
+) -> Result<CompressionResult, BuildError>
 where
     F: FnMut(f64), // 진행률 (0.0 ~ 100.0)
 {
     let original_size = std::fs::metadata(input_path)
-        .map_err(|e| format!("Failed to get input file size: {}", e))?
+        .map_err(|e| BuildError::source(&input_path.to_string_lossy(), e))?
         .len();
+    let encode_failed = |detail: String| BuildError::EncodeFailed {
+        file: display_name(input_path),
+        detail,
+    };
 
     let mut args = vec![
         "-y".to_string(),           // 덮어쓰기 허용
+        "-nostats".to_string(),     // stderr에는 경고/에러만 (빌드 로그용)
         "-progress".to_string(),    // 진행률 출력
         "pipe:1".to_string(),       // stdout으로 출력
         "-i".to_string(),           // 입력 파일
@@ -213,14 +336,24 @@ where
     // 출력 파일
     args.push(output_path.to_string_lossy().to_string());
 
-    log::info!("Running FFmpeg with progress: {:?}", ffmpeg_path);
+    let command = command_line(ffmpeg_path, &args);
+    log::info!("Running FFmpeg: {}", command);
 
     let mut child = Command::new(ffmpeg_path)
         .args(&args)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .spawn()
-        .map_err(|e| format!("Failed to spawn FFmpeg: {}", e))?;
+        .map_err(|e| encode_failed(format!("Failed to spawn FFmpeg: {}", e)))?;
+
+    // stderr는 별도 스레드에서 끝까지 읽음 (진행률을 읽는 동안 파이프가 가득 차 멈추지 않도록)
+    let stderr_reader = child.stderr.take().map(|mut stderr| {
+        std::thread::spawn(move || {
+            let mut output = Vec::new();
+            let _ = stderr.read_to_end(&mut output);
+            String::from_utf8_lossy(&output).to_string()
+        })
+    });
 
     // stdout에서 진행률 읽기
     if let Some(stdout) = child.stdout.take() {
@@ -248,17 +381,31 @@ where
         }
     }
 
-    let output = child
-        .wait_with_output()
-        .map_err(|e| format!("Failed to wait for FFmpeg: {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("FFmpeg failed: {}", stderr));
+    let status = child
+        .wait()
+        .map_err(|e| encode_failed(format!("Failed to wait for FFmpeg: {}", e)))?;
+    let stderr = stderr_reader
+        .and_then(|reader| reader.join().ok())
+        .unwrap_or_default();
+
+    if !status.success() {
+        log::error!("FFmpeg failed ({}): {}\n{}", status, command, stderr.trim_end());
+        let disk_full = ["No space left on device", "There is not enough space"]
+            .iter()
+            .any(|message| stderr.contains(message));
+        if disk_full {
+            return Err(BuildError::DiskFull {
+                path: Some(output_path.display().to_string()),
+            });
+        }
+        return Err(encode_failed(last_error_line(&stderr)));
+    }
+    if !stderr.trim().is_empty() {
+        log::debug!("FFmpeg output:\n{}", stderr.trim_end());
     }
 
     let compressed_size = std::fs::metadata(output_path)
-        .map_err(|e| format!("Failed to get output file size: {}", e))?
+        .map_err(|e| BuildError::io("readMetadata", Some(output_path), e))?
         .len();
 
     let compression_ratio = if original_size > 0 {
@@ -289,7 +436,7 @@ pub fn compress_video(
     input_path: &Path,
     output_path: &Path,
     settings: &CompressionSettings,
-) -> Result<CompressionResult, String> {
+) -> Result<CompressionResult, BuildError> {
     compress_video_with_progress(ffmpeg_path, input_path, output_path, settings, 0.0, |_| {})
 }
 
@@ -1,6 +1,12 @@
 import React, { useState, useEffect } from 'react'
 import { listen, type UnlistenFn } from '@tauri-apps/api/event'
-import type { CompressionSettings, CompressionQuality } from '@viswave/shared'
+import type {
+  CompressionSettings,
+  CompressionQuality,
+  EncryptionSettings,
+  LicenseSettings,
+  SplitSettings,
+} from '@viswave/shared'
 
 interface BuildProgress {
   current: number
@@ -14,9 +20,25 @@ interface BuildProgress {
 interface BuildDialogProps {
   isOpen: boolean
   onClose: () => void
-  onBuild: (compression: CompressionSettings) => void
+  onBuild: (
+    compression: CompressionSettings,
+    encryption: EncryptionSettings,
+    license: LicenseSettings,
+    split: SplitSettings,
+    verify: boolean,
+    saveReport: boolean,
+    bundledProjectIds: string[]
+  ) => void
+  onPatch: (
+    compression: CompressionSettings,
+    passphrase: string | undefined,
+    license: LicenseSettings | undefined,
+    bundledProjectIds: string[]
+  ) => void
   isBuilding: boolean
   videoCount: number
+  /** 함께 넣을 수 있는 다른 프로젝트 */
+  otherProjects?: { id: string; name: string }[]
 }
 
 const QUALITY_OPTIONS: {
@@ -40,17 +62,48 @@ const RESOLUTION_OPTIONS: { value: number | undefined; label: string }[] = [
   { value: 480, label: '480p (SD)' },
 ]
 
+const VOLUME_SIZE_OPTIONS: { value: number | undefined; label: string }[] = [
+  { value: undefined, label: '분할 안 함 (하나의 .pak 파일)' },
+  { value: 4000, label: '4000MB (FAT32 USB)' },
+  { value: 2048, label: '2GB' },
+  { value: 1024, label: '1GB' },
+]
+
+const DAY_MS = 24 * 60 * 60 * 1000
+
+/** 'YYYY-MM-DD' 입력값을 해당 날짜 0시(로컬)의 Unix ms로 변환 */
+const parseDateInput = (value: string): number | undefined => {
+  if (!value) return undefined
+  const [year, month, day] = value.split('-').map(Number)
+  return new Date(year, month - 1, day).getTime()
+}
+
 const BuildDialog: React.FC<BuildDialogProps> = ({
   isOpen,
   onClose,
   onBuild,
+  onPatch,
   isBuilding,
   videoCount,
+  otherProjects = [],
 }) => {
   const [enabled, setEnabled] = useState(false)
   const [quality, setQuality] = useState<CompressionQuality>('high')
   const [maxHeight, setMaxHeight] = useState<number | undefined>(undefined)
   const [progress, setProgress] = useState<BuildProgress | null>(null)
+  const [encryptionEnabled, setEncryptionEnabled] = useState(false)
+  const [passphrase, setPassphrase] = useState('')
+  const [licenseEnabled, setLicenseEnabled] = useState(false)
+  const [licenseStart, setLicenseStart] = useState('')
+  const [licenseEnd, setLicenseEnd] = useState('')
+  const [machineIdsText, setMachineIdsText] = useState('')
+  const [splitEnabled, setSplitEnabled] = useState(false)
+  const [verifyEnabled, setVerifyEnabled] = useState(true)
+  const [saveReportEnabled, setSaveReportEnabled] = useState(false)
+  const [volumeSizeMb, setVolumeSizeMb] = useState<number | undefined>(
+    undefined
+  )
+  const [bundledProjectIds, setBundledProjectIds] = useState<string[]>([])
 
   // 빌드 진행 상황 이벤트 리스너
   useEffect(() => {
@@ -77,13 +130,85 @@ const BuildDialog: React.FC<BuildDialogProps> = ({
 
   if (!isOpen) return null
 
+  const toggleBundledProject = (id: string, checked: boolean) => {
+    setBundledProjectIds((prev) =>
+      checked ? [...prev, id] : prev.filter((projectId) => projectId !== id)
+    )
+  }
+
+  // 목록에서 사라진 프로젝트는 제외
+  const selectedBundledIds = bundledProjectIds.filter((id) =>
+    otherProjects.some((project) => project.id === id)
+  )
+
+  /** 라이선스 입력값 검증 후 변환 (검증 실패 시 null) */
+  const buildLicense = (): LicenseSettings | null => {
+    const notBefore = parseDateInput(licenseStart)
+    const endDay = parseDateInput(licenseEnd)
+    // 종료일 당일까지 사용 가능하도록 다음 날 0시에 만료
+    const notAfter = endDay !== undefined ? endDay + DAY_MS : undefined
+    const machineIds = machineIdsText
+      .split('\n')
+      .map((id) => id.trim())
+      .filter((id) => id.length > 0)
+
+    if (
+      licenseEnabled &&
+      notBefore !== undefined &&
+      notAfter !== undefined &&
+      notBefore >= notAfter
+    ) {
+      alert('사용 시작일은 종료일보다 이전이어야 합니다.')
+      return null
+    }
+
+    return licenseEnabled
+      ? { notBefore, notAfter, machineIds }
+      : { machineIds: [] }
+  }
+
   const handleBuild = () => {
+    const license = buildLicense()
+    if (!license) return
+
     setProgress(null)
-    onBuild({
-      enabled,
-      quality,
-      maxHeight,
-    })
+    onBuild(
+      {
+        enabled,
+        quality,
+        maxHeight,
+      },
+      {
+        enabled: encryptionEnabled,
+        passphrase: passphrase || undefined,
+      },
+      license,
+      {
+        enabled: splitEnabled,
+        volumeSizeMb: splitEnabled ? volumeSizeMb : undefined,
+      },
+      verifyEnabled,
+      saveReportEnabled,
+      selectedBundledIds
+    )
+  }
+
+  // 기존 실행 파일 업데이트: 라이선스를 켜지 않으면 기존 라이선스 유지
+  const handlePatch = () => {
+    const license = buildLicense()
+    if (!license) return
+
+    setProgress(null)
+    onPatch(
+      {
+        enabled,
+        quality,
+        maxHeight,
+      },
+      passphrase || undefined,
+      licenseEnabled ? license : undefined,
+      selectedBundledIds
+    )
   }
 
   return (
@@ -249,6 +374,239 @@ const BuildDialog: React.FC<BuildDialogProps> = ({
               )}
             </div>
 
+            {/* 여러 튜토리얼 함께 넣기 */}
+            {otherProjects.length > 0 && (
+              <div className='mb-6'>
+                <h3 className='font-semibold text-gray-900'>
+                  함께 넣을 튜토리얼
+                </h3>
+                <p className='mb-3 text-sm text-gray-500'>
+                  선택하면 실행 시 튜토리얼 선택 화면이 표시됩니다. (--project
+                  인자로 바로 실행 가능)
+                </p>
+                <div className='max-h-40 space-y-2 overflow-y-auto rounded-lg border border-gray-200 bg-gray-50 p-3'>
+                  {otherProjects.map((project) => (
+                    <label
+                      key={project.id}
+                      className='flex cursor-pointer items-center gap-2 text-sm text-gray-700'
+                    >
+                      <input
+                        type='checkbox'
+                        checked={selectedBundledIds.includes(project.id)}
+                        onChange={(e) =>
+                          toggleBundledProject(project.id, e.target.checked)
+                        }
+                        className='h-4 w-4 rounded border-gray-300 text-purple-600 focus:ring-purple-500'
+                      />
+                      {project.name}
+                    </label>
+                  ))}
+                </div>
+              </div>
+            )}
+
+            {/* 콘텐츠 암호화 설정 */}
+            <div className='mb-6'>
+              <div className='mb-4 flex items-center justify-between'>
+                <div>
+                  <h3 className='font-semibold text-gray-900'>콘텐츠 암호화</h3>
+                  <p className='text-sm text-gray-500'>
+                    실행 파일에서 미디어를 추출할 수 없도록 암호화합니다.
+                  </p>
+                </div>
+                <label className='relative inline-flex cursor-pointer items-center'>
+                  <input
+                    type='checkbox'
+                    checked={encryptionEnabled}
+                    onChange={(e) => setEncryptionEnabled(e.target.checked)}
+                    className='peer sr-only'
+                  />
+                  <div className="peer h-6 w-11 rounded-full bg-gray-200 after:absolute after:left-[2px] after:top-[2px] after:h-5 after:w-5 after:rounded-full after:border after:border-gray-300 after:bg-white after:transition-all after:content-[''] peer-checked:bg-purple-600 peer-checked:after:translate-x-full peer-checked:after:border-white peer-focus:ring-2 peer-focus:ring-purple-300"></div>
+                </label>
+              </div>
+
+              {encryptionEnabled && (
+                <div className='rounded-lg border border-gray-200 bg-gray-50 p-4'>
+                  <label className='mb-2 block text-sm font-medium text-gray-700'>
+                    잠금 해제 암호 (선택)
+                  </label>
+                  <input
+                    type='password'
+                    value={passphrase}
+                    onChange={(e) => setPassphrase(e.target.value)}
+                    placeholder='비워두면 실행 파일에 키를 내장합니다'
+                    className='w-full rounded-lg border border-gray-300 p-2 focus:border-purple-500 focus:outline-none focus:ring-1 focus:ring-purple-500'
+                  />
+                  <p className='mt-1 text-xs text-gray-500'>
+                    암호를 설정하면 실행 시 암호를 입력하거나 --key 인자로
+                    전달해야 합니다.
+                  </p>
+                </div>
+              )}
+            </div>
+
+            {/* 라이선스 설정 */}
+            <div className='mb-6'>
+              <div className='mb-4 flex items-center justify-between'>
+                <div>
+                  <h3 className='font-semibold text-gray-900'>사용 기간 제한</h3>
+                  <p className='text-sm text-gray-500'>
+                    기간이 지나거나 허용되지 않은 PC에서는 실행되지 않습니다.
+                  </p>
+                </div>
+                <label className='relative inline-flex cursor-pointer items-center'>
+                  <input
+                    type='checkbox'
+                    checked={licenseEnabled}
+                    onChange={(e) => setLicenseEnabled(e.target.checked)}
+                    className='peer sr-only'
+                  />
+                  <div className="peer h-6 w-11 rounded-full bg-gray-200 after:absolute after:left-[2px] after:top-[2px] after:h-5 after:w-5 after:rounded-full after:border after:border-gray-300 after:bg-white after:transition-all after:content-[''] peer-checked:bg-purple-600 peer-checked:after:translate-x-full peer-checked:after:border-white peer-focus:ring-2 peer-focus:ring-purple-300"></div>
+                </label>
+              </div>
+
+              {licenseEnabled && (
+                <div className='space-y-4 rounded-lg border border-gray-200 bg-gray-50 p-4'>
+                  <div className='flex gap-3'>
+                    <div className='flex-1'>
+                      <label className='mb-2 block text-sm font-medium text-gray-700'>
+                        시작일
+                      </label>
+                      <input
+                        type='date'
+                        value={licenseStart}
+                        onChange={(e) => setLicenseStart(e.target.value)}
+                        className='w-full rounded-lg border border-gray-300 p-2 focus:border-purple-500 focus:outline-none focus:ring-1 focus:ring-purple-500'
+                      />
+                    </div>
+                    <div className='flex-1'>
+                      <label className='mb-2 block text-sm font-medium text-gray-700'>
+                        종료일
+                      </label>
+                      <input
+                        type='date'
+                        value={licenseEnd}
+                        onChange={(e) => setLicenseEnd(e.target.value)}
+                        className='w-full rounded-lg border border-gray-300 p-2 focus:border-purple-500 focus:outline-none focus:ring-1 focus:ring-purple-500'
+                      />
+                    </div>
+                  </div>
+                  <div>
+                    <label className='mb-2 block text-sm font-medium text-gray-700'>
+                      허용 PC ID (선택)
+                    </label>
+                    <textarea
+                      value={machineIdsText}
+                      onChange={(e) => setMachineIdsText(e.target.value)}
+                      rows={3}
+                      placeholder='한 줄에 하나씩 입력'
+                      className='w-full rounded-lg border border-gray-300 p-2 font-mono text-sm focus:border-purple-500 focus:outline-none focus:ring-1 focus:ring-purple-500'
+                    />
+                    <p className='mt-1 text-xs text-gray-500'>
+                      PC ID는 해당 PC에서 뷰어를 실행하면 확인할 수 있습니다.
+                    </p>
+                  </div>
+                  <p className='text-xs text-gray-500'>
+                    빌드 서명 키(VISTUT_LICENSE_SIGNING_KEY)가 설정된 PC에서만 사용할 수
+                    있습니다.
+                  </p>
+                </div>
+              )}
+            </div>
+
+            {/* 데이터 팩 분리 설정 */}
+            <div className='mb-6'>
+              <div className='mb-4 flex items-center justify-between'>
+                <div>
+                  <h3 className='font-semibold text-gray-900'>데이터 팩 분리</h3>
+                  <p className='text-sm text-gray-500'>
+                    실행 파일과 콘텐츠(.pak)를 나누어 저장합니다.
+                  </p>
+                </div>
+                <label className='relative inline-flex cursor-pointer items-center'>
+                  <input
+                    type='checkbox'
+                    checked={splitEnabled}
+                    onChange={(e) => setSplitEnabled(e.target.checked)}
+                    className='peer sr-only'
+                  />
+                  <div className="peer h-6 w-11 rounded-full bg-gray-200 after:absolute after:left-[2px] after:top-[2px] after:h-5 after:w-5 after:rounded-full after:border after:border-gray-300 after:bg-white after:transition-all after:content-[''] peer-checked:bg-purple-600 peer-checked:after:translate-x-full peer-checked:after:border-white peer-focus:ring-2 peer-focus:ring-purple-300"></div>
+                </label>
+              </div>
+
+              {splitEnabled && (
+                <div className='rounded-lg border border-gray-200 bg-gray-50 p-4'>
+                  <label className='mb-2 block text-sm font-medium text-gray-700'>
+                    볼륨 크기
+                  </label>
+                  <select
+                    value={volumeSizeMb ?? ''}
+                    onChange={(e) =>
+                      setVolumeSizeMb(
+                        e.target.value ? parseInt(e.target.value) : undefined
+                      )
+                    }
+                    className='w-full rounded-lg border border-gray-300 p-2 focus:border-purple-500 focus:outline-none focus:ring-1 focus:ring-purple-500'
+                  >
+                    {VOLUME_SIZE_OPTIONS.map((option) => (
+                      <option key={option.label} value={option.value ?? ''}>
+                        {option.label}
+                      </option>
+                    ))}
+                  </select>
+                  <p className='mt-1 text-xs text-gray-500'>
+                    생성된 .pak 파일을 실행 파일과 같은 폴더에 함께 배포해야
+                    합니다.
+                  </p>
+                </div>
+              )}
+            </div>
+
+            {/* 빌드 후 검증 설정 */}
+            <div className='mb-6'>
+              <div className='flex items-center justify-between'>
+                <div>
+                  <h3 className='font-semibold text-gray-900'>빌드 후 검증</h3>
+                  <p className='text-sm text-gray-500'>
+                    저장하기 전에 기록된 데이터를 다시 읽어 확인합니다.
+                  </p>
+                </div>
+                <label className='relative inline-flex cursor-pointer items-center'>
+                  <input
+                    type='checkbox'
+                    checked={verifyEnabled}
+                    onChange={(e) => setVerifyEnabled(e.target.checked)}
+                    className='peer sr-only'
+                  />
+                  <div className="peer h-6 w-11 rounded-full bg-gray-200 after:absolute after:left-[2px] after:top-[2px] after:h-5 after:w-5 after:rounded-full after:border after:border-gray-300 after:bg-white after:transition-all after:content-[''] peer-checked:bg-purple-600 peer-checked:after:translate-x-full peer-checked:after:border-white peer-focus:ring-2 peer-focus:ring-purple-300"></div>
+                </label>
+              </div>
+            </div>
+
+            {/* 빌드 보고서 저장 설정 */}
+            <div className='mb-6'>
+              <div className='flex items-center justify-between'>
+                <div>
+                  <h3 className='font-semibold text-gray-900'>
+                    빌드 보고서 저장
+                  </h3>
+                  <p className='text-sm text-gray-500'>
+                    파일별 크기, 코덱, 해시와 경고를 실행 파일 옆에
+                    JSON/HTML로 저장합니다.
+                  </p>
+                </div>
+                <label className='relative inline-flex cursor-pointer items-center'>
+                  <input
+                    type='checkbox'
+                    checked={saveReportEnabled}
+                    onChange={(e) => setSaveReportEnabled(e.target.checked)}
+                    className='peer sr-only'
+                  />
+                  <div className="peer h-6 w-11 rounded-full bg-gray-200 after:absolute after:left-[2px] after:top-[2px] after:h-5 after:w-5 after:rounded-full after:border after:border-gray-300 after:bg-white after:transition-all after:content-[''] peer-checked:bg-purple-600 peer-checked:after:translate-x-full peer-checked:after:border-white peer-focus:ring-2 peer-focus:ring-purple-300"></div>
+                </label>
+              </div>
+            </div>
+
             {/* 버튼 */}
             <div className='flex justify-end gap-3'>
               <button
@@ -257,6 +615,13 @@ const BuildDialog: React.FC<BuildDialogProps> = ({
               >
                 취소
               </button>
+              <button
+                onClick={handlePatch}
+                title='이미 내보낸 실행 파일에 변경된 내용만 반영합니다 (암호화된 파일은 잠금 해제 암호 필요)'
+                className='rounded-lg border border-purple-600 px-4 py-2 text-purple-600 hover:bg-purple-50'
+              >
+                기존 파일 업데이트
+              </button>
               <button
                 onClick={handleBuild}
                 className='rounded-lg bg-purple-600 px-4 py-2 text-white hover:bg-purple-700'
@@ -0,0 +1,214 @@
+import React, { useState } from 'react'
+import {
+  LOCALE_LABELS,
+  type Page,
+  type PageLocalization,
+} from '@viswave/shared'
+import { saveButtonImage, saveMediaFile } from '../../utils/mediaStorage'
+
+type LocalizationEditorProps = {
+  page: Page
+  /** 기본 언어를 제외한 제공 언어 */
+  locales: string[]
+  onUpdate: (updates: Partial<Page>) => void
+}
+
+/**
+ * 언어별 페이지 내용 편집 (제목, 미디어, 버튼 이미지)
+ * 비워 둔 항목은 기본 언어의 내용을 그대로 사용
+ */
+const LocalizationEditor: React.FC<LocalizationEditorProps> = ({
+  page,
+  locales,
+  onUpdate,
+}) => {
+  const [activeLocale, setActiveLocale] = useState(locales[0])
+  const [isUploading, setIsUploading] = useState(false)
+
+  const locale = locales.includes(activeLocale) ? activeLocale : locales[0]
+  const localization: PageLocalization = page.localized?.[locale] ?? {}
+
+  const updateLocalization = (updates: Partial<PageLocalization>) => {
+    onUpdate({
+      localized: {
+        ...page.localized,
+        [locale]: { ...localization, ...updates },
+      },
+    })
+  }
+
+  const handleMediaUpload = async (e: React.ChangeEvent<HTMLInputElement>) => {
+    const file = e.target.files?.[0]
+    e.target.value = ''
+    if (!file) return
+
+    const isVideo = file.type.startsWith('video/')
+    if (!isVideo && !file.type.startsWith('image/')) {
+      alert('비디오 또는 이미지 파일만 업로드할 수 있습니다.')
+      return
+    }
+
+    setIsUploading(true)
+    try {
+      const mediaType = isVideo ? 'video' : 'image'
+      const mediaId = await saveMediaFile(file, mediaType)
+      updateLocalization({ mediaId, mediaType })
+    } catch (error) {
+      console.error('Failed to upload localized media:', error)
+      alert('미디어 업로드에 실패했습니다.')
+    } finally {
+      setIsUploading(false)
+    }
+  }
+
+  const handleButtonImageUpload = async (
+    buttonId: string,
+    e: React.ChangeEvent<HTMLInputElement>
+  ) => {
+    const file = e.target.files?.[0]
+    e.target.value = ''
+    if (!file) return
+
+    setIsUploading(true)
+    try {
+      const imageId = await saveButtonImage(file)
+      updateLocalization({
+        buttonImages: { ...localization.buttonImages, [buttonId]: imageId },
+      })
+    } catch (error) {
+      console.error('Failed to upload localized button image:', error)
+      alert('버튼 이미지 업로드에 실패했습니다.')
+    } finally {
+      setIsUploading(false)
+    }
+  }
+
+  const clearButtonImage = (buttonId: string) => {
+    const buttonImages = { ...localization.buttonImages }
+    delete buttonImages[buttonId]
+    updateLocalization({ buttonImages })
+  }
+
+  const imageButtons = page.buttons.filter((button) => button.imageId)
+
+  return (
+    <div className='rounded-lg bg-white p-6 shadow'>
+      <h3 className='mb-4 text-lg font-semibold'>언어별 내용</h3>
+
+      <div className='mb-4 flex gap-2'>
+        {locales.map((code) => (
+          <button
+            key={code}
+            onClick={() => setActiveLocale(code)}
+            className={`rounded px-3 py-1 text-sm ${
+              code === locale
+                ? 'bg-blue-600 text-white'
+                : 'bg-gray-100 text-gray-700 hover:bg-gray-200'
+            }`}
+          >
+            {LOCALE_LABELS[code] ?? code}
+          </button>
+        ))}
+      </div>
+
+      <div className='space-y-4'>
+        <div>
+          <label className='mb-2 block text-sm font-medium text-gray-700'>
+            페이지 제목
+          </label>
+          <input
+            type='text'
+            value={localization.title ?? ''}
+            onChange={(e) =>
+              updateLocalization({ title: e.target.value || undefined })
+            }
+            placeholder={page.title}
+            className='w-full rounded border border-gray-300 px-3 py-2 text-sm focus:border-blue-500 focus:outline-none focus:ring-1 focus:ring-blue-500'
+          />
+        </div>
+
+        <div>
+          <label className='mb-2 block text-sm font-medium text-gray-700'>
+            미디어
+          </label>
+          <div className='flex items-center gap-2'>
+            <span className='flex-1 text-sm text-gray-600'>
+              {localization.mediaId
+                ? `${localization.mediaType === 'video' ? '🎥' : '🖼️'} 언어별 미디어 사용`
+                : '기본 미디어 사용'}
+            </span>
+            <label className='cursor-pointer rounded bg-gray-100 px-3 py-1 text-sm text-gray-700 hover:bg-gray-200'>
+              {localization.mediaId ? '변경' : '업로드'}
+              <input
+                type='file'
+                accept='video/mp4,video/webm,image/png,image/jpeg,image/jpg'
+                onChange={handleMediaUpload}
+                disabled={isUploading}
+                className='hidden'
+              />
+            </label>
+            {localization.mediaId && (
+              <button
+                onClick={() =>
+                  updateLocalization({
+                    mediaId: undefined,
+                    mediaType: undefined,
+                  })
+                }
+                className='rounded px-3 py-1 text-sm text-red-600 hover:bg-red-50'
+              >
+                기본값
+              </button>
+            )}
+          </div>
+          <p className='mt-1 text-xs text-gray-500'>
+            버튼과 터치 영역의 위치는 모든 언어가 같이 사용합니다
+          </p>
+        </div>
+
+        {imageButtons.length > 0 && (
+          <div>
+            <label className='mb-2 block text-sm font-medium text-gray-700'>
+              버튼 이미지
+            </label>
+            <div className='space-y-2'>
+              {imageButtons.map((button, index) => {
+                const localizedImage = localization.buttonImages?.[button.id]
+                return (
+                  <div key={button.id} className='flex items-center gap-2'>
+                    <span className='flex-1 text-sm text-gray-600'>
+                      버튼 {index + 1}:{' '}
+                      {localizedImage ? '언어별 이미지' : '기본 이미지'}
+                    </span>
+                    <label className='cursor-pointer rounded bg-gray-100 px-3 py-1 text-sm text-gray-700 hover:bg-gray-200'>
+                      {localizedImage ? '변경' : '업로드'}
+                      <input
+                        type='file'
+                        accept='image/png,image/jpeg,image/jpg'
+                        onChange={(e) => handleButtonImageUpload(button.id, e)}
+                        disabled={isUploading}
+                        className='hidden'
+                      />
+                    </label>
+                    {localizedImage && (
+                      <button
+                        onClick={() => clearButtonImage(button.id)}
+                        className='rounded px-3 py-1 text-sm text-red-600 hover:bg-red-50'
+                      >
+                        기본값
+                      </button>
+                    )}
+                  </div>
+                )
+              })}
+            </div>
+          </div>
+        )}
+
+        {isUploading && <p className='text-sm text-gray-500'>업로드 중...</p>}
+      </div>
+    </div>
+  )
+}
+
+export default LocalizationEditor
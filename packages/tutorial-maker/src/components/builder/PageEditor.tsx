@@ -6,15 +6,23 @@ import {
   revokeBlobURL,
 } from '../../utils/mediaStorage'
 import InteractionEditor from './InteractionEditor'
+import LocalizationEditor from './LocalizationEditor'
 import MediaUploader from './MediaUploader'
 
 type PageEditorProps = {
   page: Page | null
   onUpdate: (updates: Partial<Page>) => void
   pages: Page[]
+  /** 기본 언어를 제외한 제공 언어 */
+  locales?: string[]
 }
 
-const PageEditor: React.FC<PageEditorProps> = ({ page, onUpdate, pages }) => {
+const PageEditor: React.FC<PageEditorProps> = ({
+  page,
+  onUpdate,
+  pages,
+  locales = [],
+}) => {
   const [mediaPreview, setMediaPreview] = useState<string | null>(null)
   const [mediaFileName, setMediaFileName] = useState<string | null>(null)
   const [showMediaUploader, setShowMediaUploader] = useState(false)
@@ -229,6 +237,15 @@ const PageEditor: React.FC<PageEditorProps> = ({ page, onUpdate, pages }) => {
         />
       )}
 
+      {/* 언어별 내용 (다국어 프로젝트만) */}
+      {page.mediaId && locales.length > 0 && (
+        <LocalizationEditor
+          page={page}
+          locales={locales}
+          onUpdate={onUpdate}
+        />
+      )}
+
       {!page.mediaId && (
         <div className='rounded-lg bg-white p-6 shadow'>
           <p className='text-center text-gray-500'>
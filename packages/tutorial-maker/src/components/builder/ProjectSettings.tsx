@@ -1,7 +1,18 @@
 import React, { useState, useEffect } from 'react'
-import type {
-  Project,
-  ProjectSettings as ProjectSettingsType,
+import { invoke } from '@tauri-apps/api/core'
+import {
+  LOCALE_LABELS,
+  getDefaultLocale,
+  getProjectLocales,
+  type ControlApiSettings,
+  type HeartbeatSettings,
+  type HotFolderSettings,
+  type KioskSettings,
+  type OscSettings,
+  type Project,
+  type ProjectSettings as ProjectSettingsType,
+  type ScheduleRule,
+  type WatchdogSettings,
 } from '@viswave/shared'
 import {
   saveAppIcon,
@@ -9,6 +20,20 @@ import {
   createBlobURL,
 } from '../../utils/mediaStorage'
 
+// 관리자 PIN은 솔트가 포함된 Argon2 해시로만 저장 (백엔드에서 생성)
+const hashPin = (pin: string) => invoke<string>('hash_admin_pin', { pin })
+
+// 이전 버전에서 저장한 솔트 없는 SHA-256 해시인지 확인 (player가 받아들이지 않으므로 다시 설정 필요)
+const isLegacyPinHash = (hash: string) => !hash.startsWith('$argon2')
+
+const WEEKDAY_LABELS = ['일', '월', '화', '수', '목', '금', '토']
+
+// 원격 제어 접근 토큰 (URL에 그대로 쓸 수 있는 hex)
+const generateToken = () =>
+  Array.from(crypto.getRandomValues(new Uint8Array(16)))
+    .map((byte) => byte.toString(16).padStart(2, '0'))
+    .join('')
+
 type ProjectSettingsProps = {
   project: Project
   onUpdate: (updates: Partial<Project>) => void
@@ -24,6 +49,7 @@ const ProjectSettings: React.FC<ProjectSettingsProps> = ({
 }) => {
   const [iconPreview, setIconPreview] = useState<string | null>(null)
   const [isUploading, setIsUploading] = useState(false)
+  const [adminPin, setAdminPin] = useState('')
 
   useEffect(() => {
     loadIconPreview()
@@ -63,6 +89,128 @@ const ProjectSettings: React.FC<ProjectSettingsProps> = ({
     })
   }
 
+  const kiosk: KioskSettings = project.settings.kiosk ?? { enabled: false }
+
+  const handleKioskChange = (updates: Partial<KioskSettings>) => {
+    handleSettingsChange('kiosk', { ...kiosk, ...updates })
+  }
+
+  const watchdog: WatchdogSettings = project.settings.watchdog ?? {
+    enabled: false,
+  }
+
+  const handleWatchdogChange = (updates: Partial<WatchdogSettings>) => {
+    handleSettingsChange('watchdog', { ...watchdog, ...updates })
+  }
+
+  const controlApi: ControlApiSettings = project.settings.controlApi ?? {
+    enabled: false,
+  }
+
+  const handleControlApiChange = (updates: Partial<ControlApiSettings>) => {
+    handleSettingsChange('controlApi', { ...controlApi, ...updates })
+  }
+
+  const osc: OscSettings = project.settings.osc ?? { enabled: false }
+
+  const handleOscChange = (updates: Partial<OscSettings>) => {
+    handleSettingsChange('osc', { ...osc, ...updates })
+  }
+
+  // 입력 중인 쉼표가 사라지지 않도록 원문은 따로 보관
+  const [oscAllowFromText, setOscAllowFromText] = useState(
+    (osc.allowFrom ?? []).join(', ')
+  )
+
+  const handleOscAllowFromChange = (text: string) => {
+    setOscAllowFromText(text)
+    const allowFrom = text
+      .split(',')
+      .map((ip) => ip.trim())
+      .filter((ip) => ip.length > 0)
+    handleOscChange({
+      allowFrom: allowFrom.length > 0 ? allowFrom : undefined,
+    })
+  }
+
+  const hotFolder: HotFolderSettings = project.settings.hotFolder ?? {
+    enabled: false,
+    path: '',
+  }
+
+  const handleHotFolderChange = (updates: Partial<HotFolderSettings>) => {
+    handleSettingsChange('hotFolder', { ...hotFolder, ...updates })
+  }
+
+  const heartbeat: HeartbeatSettings = project.settings.heartbeat ?? {
+    enabled: false,
+  }
+
+  const handleHeartbeatChange = (updates: Partial<HeartbeatSettings>) => {
+    handleSettingsChange('heartbeat', { ...heartbeat, ...updates })
+  }
+
+  const schedule: ScheduleRule[] = project.settings.schedule ?? []
+
+  const handleScheduleChange = (
+    index: number,
+    updates: Partial<ScheduleRule>
+  ) => {
+    handleSettingsChange(
+      'schedule',
+      schedule.map((rule, i) => (i === index ? { ...rule, ...updates } : rule))
+    )
+  }
+
+  const toggleScheduleWeekday = (index: number, weekday: number) => {
+    const weekdays = schedule[index].weekdays ?? []
+    handleScheduleChange(index, {
+      weekdays: weekdays.includes(weekday)
+        ? weekdays.filter((day) => day !== weekday)
+        : [...weekdays, weekday].sort((a, b) => a - b),
+    })
+  }
+
+  const selectedLocales = project.settings.locales ?? []
+  const defaultLocale = getDefaultLocale(project)
+
+  const toggleLocale = (locale: string) => {
+    const locales = selectedLocales.includes(locale)
+      ? selectedLocales.filter((code) => code !== locale)
+      : [...selectedLocales, locale]
+    onUpdate({
+      settings: {
+        ...project.settings,
+        locales: locales.length > 0 ? locales : undefined,
+        defaultLocale:
+          defaultLocale && locales.includes(defaultLocale)
+            ? defaultLocale
+            : locales[0],
+      },
+    })
+  }
+
+  const handleLocalizedTitleChange = (locale: string, title: string) => {
+    const localizedAppTitle = { ...project.localizedAppTitle }
+    if (title) {
+      localizedAppTitle[locale] = title
+    } else {
+      delete localizedAppTitle[locale]
+    }
+    onUpdate({ localizedAppTitle })
+  }
+
+  const handleAdminPinSave = async () => {
+    if (!adminPin) return
+    try {
+      handleKioskChange({ adminPinHash: await hashPin(adminPin) })
+      setAdminPin('')
+    } catch (error) {
+      console.error('Failed to hash admin PIN:', error)
+      alert('관리자 PIN을 설정하지 못했습니다.')
+    }
+  }
+
   return (
     <div className='mx-auto max-w-4xl rounded-lg bg-white p-6 shadow-lg'>
       <div className='mb-6 flex items-center justify-between'>
@@ -173,6 +321,35 @@ const ProjectSettings: React.FC<ProjectSettingsProps> = ({
               </label>
             </div>
 
+            <div>
+              <label className='mb-1 block text-sm font-medium text-gray-700'>
+                표시할 모니터
+              </label>
+              <input
+                type='text'
+                list='display-presets'
+                value={project.settings.display ?? ''}
+                onChange={(e) =>
+                  handleSettingsChange(
+                    'display',
+                    e.target.value.trim() === '' ? undefined : e.target.value
+                  )
+                }
+                className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                placeholder='primary'
+              />
+              <datalist id='display-presets'>
+                <option value='primary'>주 모니터</option>
+                <option value='largest'>가장 큰 모니터</option>
+                <option value='largest-touch'>가장 큰 보조(터치) 모니터</option>
+                <option value='1'>두 번째 모니터</option>
+              </datalist>
+              <p className='mt-1 text-xs text-gray-500'>
+                primary, largest, largest-touch, 모니터 번호(0부터) 또는 이름
+                일부. 실행 시 --display 인자로 바꿀 수 있습니다
+              </p>
+            </div>
+
             {!project.settings.fullscreen && (
               <div className='grid grid-cols-2 gap-4'>
                 <div>
@@ -292,6 +469,817 @@ const ProjectSettings: React.FC<ProjectSettingsProps> = ({
             </label>
           </div>
         </section>
+
+        {/* 대기 화면 복귀 */}
+        <section className='border-t border-gray-200 pt-6'>
+          <h3 className='mb-4 text-lg font-semibold text-gray-800'>
+            대기 화면 복귀
+          </h3>
+
+          <div className='grid grid-cols-2 gap-4'>
+            <div>
+              <label className='mb-1 block text-sm font-medium text-gray-700'>
+                입력 없음 대기 시간 (초)
+              </label>
+              <input
+                type='number'
+                value={project.settings.idleTimeoutSeconds ?? 0}
+                onChange={(e) =>
+                  handleSettingsChange(
+                    'idleTimeoutSeconds',
+                    Math.max(0, parseInt(e.target.value) || 0)
+                  )
+                }
+                className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                min='0'
+              />
+              <p className='mt-1 text-xs text-gray-500'>0이면 사용 안 함</p>
+            </div>
+            <div>
+              <label className='mb-1 block text-sm font-medium text-gray-700'>
+                복귀할 화면
+              </label>
+              <select
+                value={project.settings.attractPageId ?? ''}
+                onChange={(e) =>
+                  handleSettingsChange(
+                    'attractPageId',
+                    e.target.value === '' ? undefined : e.target.value
+                  )
+                }
+                disabled={!project.settings.idleTimeoutSeconds}
+                className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500 disabled:bg-gray-100'
+              >
+                <option value=''>대문페이지</option>
+                {project.pages.map((page, index) => (
+                  <option key={page.id} value={String(index)}>
+                    {index + 1}. {page.title || `페이지 ${index + 1}`}
+                  </option>
+                ))}
+              </select>
+            </div>
+          </div>
+        </section>
+
+        {/* 키오스크 잠금 */}
+        <section className='border-t border-gray-200 pt-6'>
+          <h3 className='mb-4 text-lg font-semibold text-gray-800'>
+            키오스크 잠금
+          </h3>
+
+          <div className='space-y-4'>
+            <label className='flex cursor-pointer items-center'>
+              <input
+                type='checkbox'
+                checked={kiosk.enabled}
+                onChange={(e) =>
+                  handleKioskChange({ enabled: e.target.checked })
+                }
+                className='h-4 w-4 rounded text-blue-600 focus:ring-2 focus:ring-blue-500'
+              />
+              <span className='ml-2 text-sm text-gray-700'>
+                항상 위 전체화면 고정, 창 닫기/우클릭/확대 차단
+              </span>
+            </label>
+
+            {kiosk.enabled && (
+              <div className='grid grid-cols-2 gap-4'>
+                <div>
+                  <label className='mb-1 block text-sm font-medium text-gray-700'>
+                    관리자 PIN{' '}
+                    {kiosk.adminPinHash
+                      ? isLegacyPinHash(kiosk.adminPinHash)
+                        ? '(이전 형식, 다시 설정 필요)'
+                        : '(설정됨)'
+                      : '(없으면 종료 키로 종료)'}
+                  </label>
+                  <div className='flex gap-2'>
+                    <input
+                      type='password'
+                      inputMode='numeric'
+                      value={adminPin}
+                      onChange={(e) => setAdminPin(e.target.value)}
+                      placeholder={kiosk.adminPinHash ? '새 PIN' : 'PIN'}
+                      className='min-w-0 flex-1 rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                    />
+                    <button
+                      type='button'
+                      onClick={handleAdminPinSave}
+                      disabled={!adminPin}
+                      className='rounded-lg bg-gray-100 px-3 py-2 text-sm text-gray-700 hover:bg-gray-200 disabled:opacity-50'
+                    >
+                      설정
+                    </button>
+                    {kiosk.adminPinHash && (
+                      <button
+                        type='button'
+                        onClick={() =>
+                          handleKioskChange({ adminPinHash: undefined })
+                        }
+                        className='rounded-lg bg-gray-100 px-3 py-2 text-sm text-red-600 hover:bg-gray-200'
+                      >
+                        해제
+                      </button>
+                    )}
+                  </div>
+                </div>
+                <div>
+                  <label className='mb-1 block text-sm font-medium text-gray-700'>
+                    커서 숨김 대기 시간 (초)
+                  </label>
+                  <input
+                    type='number'
+                    value={kiosk.hideCursorSeconds ?? 0}
+                    onChange={(e) =>
+                      handleKioskChange({
+                        hideCursorSeconds: Math.max(
+                          0,
+                          parseInt(e.target.value) || 0
+                        ),
+                      })
+                    }
+                    className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                    min='0'
+                  />
+                  <p className='mt-1 text-xs text-gray-500'>0이면 항상 표시</p>
+                </div>
+              </div>
+            )}
+          </div>
+        </section>
+
+        {/* 자동 재시작 */}
+        <section className='border-t border-gray-200 pt-6'>
+          <h3 className='mb-4 text-lg font-semibold text-gray-800'>
+            자동 재시작
+          </h3>
+
+          <div className='space-y-4'>
+            <label className='flex cursor-pointer items-center'>
+              <input
+                type='checkbox'
+                checked={watchdog.enabled}
+                onChange={(e) =>
+                  handleWatchdogChange({ enabled: e.target.checked })
+                }
+                className='h-4 w-4 rounded text-blue-600 focus:ring-2 focus:ring-blue-500'
+              />
+              <span className='ml-2 text-sm text-gray-700'>
+                비정상 종료하거나 화면이 멈추면 플레이어 다시 실행
+              </span>
+            </label>
+
+            {watchdog.enabled && (
+              <div className='grid grid-cols-3 gap-4'>
+                <div>
+                  <label className='mb-1 block text-sm font-medium text-gray-700'>
+                    멈춤 판단 시간 (초)
+                  </label>
+                  <input
+                    type='number'
+                    value={watchdog.heartbeatTimeoutSeconds ?? 30}
+                    onChange={(e) =>
+                      handleWatchdogChange({
+                        heartbeatTimeoutSeconds: Math.max(
+                          15,
+                          parseInt(e.target.value) || 15
+                        ),
+                      })
+                    }
+                    className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                    min='15'
+                  />
+                </div>
+                <div>
+                  <label className='mb-1 block text-sm font-medium text-gray-700'>
+                    최대 재시작 횟수
+                  </label>
+                  <input
+                    type='number'
+                    value={watchdog.maxRestarts ?? 5}
+                    onChange={(e) =>
+                      handleWatchdogChange({
+                        maxRestarts: Math.max(0, parseInt(e.target.value) || 0),
+                      })
+                    }
+                    className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                    min='0'
+                  />
+                </div>
+                <div>
+                  <label className='mb-1 block text-sm font-medium text-gray-700'>
+                    횟수 집계 기간 (초)
+                  </label>
+                  <input
+                    type='number'
+                    value={watchdog.restartWindowSeconds ?? 600}
+                    onChange={(e) =>
+                      handleWatchdogChange({
+                        restartWindowSeconds: Math.max(
+                          60,
+                          parseInt(e.target.value) || 60
+                        ),
+                      })
+                    }
+                    className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                    min='60'
+                  />
+                </div>
+                <p className='col-span-3 text-xs text-gray-500'>
+                  집계 기간 안에 최대 횟수를 넘게 재시작하면 중단하고 오류
+                  화면을 표시합니다
+                </p>
+              </div>
+            )}
+          </div>
+        </section>
+
+        {/* 원격 제어 */}
+        <section className='border-t border-gray-200 pt-6'>
+          <h3 className='mb-4 text-lg font-semibold text-gray-800'>
+            원격 제어
+          </h3>
+
+          <div className='space-y-4'>
+            <label className='flex cursor-pointer items-center'>
+              <input
+                type='checkbox'
+                checked={controlApi.enabled}
+                onChange={(e) =>
+                  handleControlApiChange({
+                    enabled: e.target.checked,
+                    token: controlApi.token ?? generateToken(),
+                  })
+                }
+                className='h-4 w-4 rounded text-blue-600 focus:ring-2 focus:ring-blue-500'
+              />
+              <span className='ml-2 text-sm text-gray-700'>
+                HTTP/WebSocket으로 페이지 이동, 다시 로드, 음소거 제어
+              </span>
+            </label>
+
+            {controlApi.enabled && (
+              <div className='space-y-4'>
+                <div className='grid grid-cols-2 gap-4'>
+                  <div>
+                    <label className='mb-1 block text-sm font-medium text-gray-700'>
+                      수신 주소
+                    </label>
+                    <input
+                      type='text'
+                      value={controlApi.bind ?? ''}
+                      onChange={(e) =>
+                        handleControlApiChange({
+                          bind: e.target.value || undefined,
+                        })
+                      }
+                      placeholder='127.0.0.1'
+                      className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                    />
+                    <p className='mt-1 text-xs text-gray-500'>
+                      다른 PC에서 제어하려면 0.0.0.0 또는 이 PC의 IP
+                    </p>
+                  </div>
+                  <div>
+                    <label className='mb-1 block text-sm font-medium text-gray-700'>
+                      포트
+                    </label>
+                    <input
+                      type='number'
+                      value={controlApi.port ?? 8765}
+                      onChange={(e) =>
+                        handleControlApiChange({
+                          port: Math.min(
+                            65535,
+                            Math.max(1, parseInt(e.target.value) || 8765)
+                          ),
+                        })
+                      }
+                      className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                      min='1'
+                      max='65535'
+                    />
+                  </div>
+                </div>
+                <div>
+                  <label className='mb-1 block text-sm font-medium text-gray-700'>
+                    접근 토큰
+                  </label>
+                  <div className='flex gap-2'>
+                    <input
+                      type='text'
+                      value={controlApi.token ?? ''}
+                      onChange={(e) =>
+                        handleControlApiChange({
+                          token: e.target.value || undefined,
+                        })
+                      }
+                      className='flex-1 rounded-lg border border-gray-300 px-3 py-2 font-mono text-sm focus:ring-2 focus:ring-blue-500'
+                    />
+                    <button
+                      type='button'
+                      onClick={() =>
+                        handleControlApiChange({ token: generateToken() })
+                      }
+                      className='rounded-lg border border-gray-300 px-3 py-2 text-sm text-gray-700 hover:bg-gray-50'
+                    >
+                      새로 생성
+                    </button>
+                  </div>
+                  <p className='mt-1 text-xs text-gray-500'>
+                    Authorization: Bearer 헤더로 전달합니다. (?token= 은
+                    127.0.0.1에서만 허용)
+                    GET /status, POST /goto/페이지ID, /next, /home, /reload,
+                    /rollback, /mute, /unmute, WebSocket /events
+                  </p>
+                </div>
+              </div>
+            )}
+          </div>
+        </section>
+
+        {/* OSC 연동 */}
+        <section className='border-t border-gray-200 pt-6'>
+          <h3 className='mb-4 text-lg font-semibold text-gray-800'>
+            OSC 연동
+          </h3>
+
+          <div className='space-y-4'>
+            <label className='flex cursor-pointer items-center'>
+              <input
+                type='checkbox'
+                checked={osc.enabled}
+                onChange={(e) => handleOscChange({ enabled: e.target.checked })}
+                className='h-4 w-4 rounded text-blue-600 focus:ring-2 focus:ring-blue-500'
+              />
+              <span className='ml-2 text-sm text-gray-700'>
+                쇼 컨트롤 시스템의 OSC(UDP) 메시지로 페이지 이동
+              </span>
+            </label>
+
+            {osc.enabled && (
+              <div className='space-y-4'>
+                <div className='grid grid-cols-3 gap-4'>
+                  <div>
+                    <label className='mb-1 block text-sm font-medium text-gray-700'>
+                      수신 주소
+                    </label>
+                    <input
+                      type='text'
+                      value={osc.bind ?? ''}
+                      onChange={(e) =>
+                        handleOscChange({ bind: e.target.value || undefined })
+                      }
+                      placeholder='127.0.0.1'
+                      className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                    />
+                    <p className='mt-1 text-xs text-gray-500'>
+                      다른 PC에서 보내려면 0.0.0.0 또는 이 PC의 IP
+                    </p>
+                  </div>
+                  <div>
+                    <label className='mb-1 block text-sm font-medium text-gray-700'>
+                      수신 포트
+                    </label>
+                    <input
+                      type='number'
+                      value={osc.port ?? 9000}
+                      onChange={(e) =>
+                        handleOscChange({
+                          port: Math.min(
+                            65535,
+                            Math.max(1, parseInt(e.target.value) || 9000)
+                          ),
+                        })
+                      }
+                      className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                      min='1'
+                      max='65535'
+                    />
+                  </div>
+                  <div>
+                    <label className='mb-1 block text-sm font-medium text-gray-700'>
+                      주소 접두사
+                    </label>
+                    <input
+                      type='text'
+                      value={osc.prefix ?? ''}
+                      onChange={(e) =>
+                        handleOscChange({ prefix: e.target.value || undefined })
+                      }
+                      placeholder='/tutorial'
+                      className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                    />
+                  </div>
+                </div>
+                <div>
+                  <label className='mb-1 block text-sm font-medium text-gray-700'>
+                    페이지 변경 알림 대상
+                  </label>
+                  <input
+                    type='text'
+                    value={osc.sendTo ?? ''}
+                    onChange={(e) =>
+                      handleOscChange({ sendTo: e.target.value || undefined })
+                    }
+                    placeholder='192.168.0.10:8000'
+                    className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                  />
+                  <p className='mt-1 text-xs text-gray-500'>
+                    수신: /goto 페이지ID(또는 번호), /next, /home. 송신: /page
+                    번호 페이지ID (비워 두면 보내지 않음)
+                  </p>
+                </div>
+                <div>
+                  <label className='mb-1 block text-sm font-medium text-gray-700'>
+                    허용할 송신 IP
+                  </label>
+                  <input
+                    type='text'
+                    value={oscAllowFromText}
+                    onChange={(e) => handleOscAllowFromChange(e.target.value)}
+                    placeholder='192.168.0.10, 192.168.0.11'
+                    className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                  />
+                  <p className='mt-1 text-xs text-gray-500'>
+                    쉼표로 구분 (비워 두면 모든 주소에서 받음)
+                  </p>
+                </div>
+              </div>
+            )}
+          </div>
+        </section>
+
+        {/* 콘텐츠 업데이트 */}
+        <section className='border-t border-gray-200 pt-6'>
+          <h3 className='mb-4 text-lg font-semibold text-gray-800'>
+            콘텐츠 업데이트
+          </h3>
+
+          <div className='space-y-4'>
+            <label className='flex cursor-pointer items-center'>
+              <input
+                type='checkbox'
+                checked={hotFolder.enabled}
+                onChange={(e) =>
+                  handleHotFolderChange({ enabled: e.target.checked })
+                }
+                className='h-4 w-4 rounded text-blue-600 focus:ring-2 focus:ring-blue-500'
+              />
+              <span className='ml-2 text-sm text-gray-700'>
+                폴더의 새 데이터 팩으로 자동 업데이트
+              </span>
+            </label>
+
+            {hotFolder.enabled && (
+              <div className='space-y-4'>
+                <div className='grid grid-cols-3 gap-4'>
+                  <div className='col-span-2'>
+                    <label className='mb-1 block text-sm font-medium text-gray-700'>
+                      감시할 폴더
+                    </label>
+                    <input
+                      type='text'
+                      value={hotFolder.path}
+                      onChange={(e) =>
+                        handleHotFolderChange({ path: e.target.value })
+                      }
+                      placeholder='\\server\tutorial'
+                      className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                    />
+                  </div>
+                  <div>
+                    <label className='mb-1 block text-sm font-medium text-gray-700'>
+                      확인 주기 (초)
+                    </label>
+                    <input
+                      type='number'
+                      value={hotFolder.checkIntervalSeconds ?? 60}
+                      onChange={(e) =>
+                        handleHotFolderChange({
+                          checkIntervalSeconds: Math.max(
+                            10,
+                            parseInt(e.target.value) || 60
+                          ),
+                        })
+                      }
+                      className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                      min='10'
+                    />
+                  </div>
+                </div>
+                <p className='text-xs text-gray-500'>
+                  데이터 팩 분리로 빌드한 exe와 같은 이름의 .pak과 .pak.sha256을
+                  폴더에 넣으면 버전과 체크섬을 확인한 뒤 다음 대기 화면 복귀 때
+                  교체합니다. 빌드 서명 키(VISTUT_LICENSE_SIGNING_KEY)로 서명된
+                  같은 프로젝트의 데이터 팩만 받습니다. 이전 콘텐츠는 보관되어
+                  원격 제어의 /rollback으로 되돌릴 수 있습니다.
+                </p>
+              </div>
+            )}
+          </div>
+        </section>
+
+        {/* 상태 보고 및 로그 */}
+        <section className='border-t border-gray-200 pt-6'>
+          <h3 className='mb-4 text-lg font-semibold text-gray-800'>
+            상태 보고 및 로그
+          </h3>
+
+          <div className='space-y-4'>
+            <label className='flex cursor-pointer items-center'>
+              <input
+                type='checkbox'
+                checked={heartbeat.enabled}
+                onChange={(e) =>
+                  handleHeartbeatChange({ enabled: e.target.checked })
+                }
+                className='h-4 w-4 rounded text-blue-600 focus:ring-2 focus:ring-blue-500'
+              />
+              <span className='ml-2 text-sm text-gray-700'>
+                플레이어 상태를 주기적으로 기록
+              </span>
+            </label>
+
+            {heartbeat.enabled && (
+              <div className='space-y-4'>
+                <div className='grid grid-cols-3 gap-4'>
+                  <div className='col-span-2'>
+                    <label className='mb-1 block text-sm font-medium text-gray-700'>
+                      모니터링 서버 주소
+                    </label>
+                    <input
+                      type='text'
+                      value={heartbeat.endpoint ?? ''}
+                      onChange={(e) =>
+                        handleHeartbeatChange({
+                          endpoint: e.target.value || undefined,
+                        })
+                      }
+                      placeholder='http://monitor.local:8080/heartbeat'
+                      className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                    />
+                  </div>
+                  <div>
+                    <label className='mb-1 block text-sm font-medium text-gray-700'>
+                      기록 주기 (초)
+                    </label>
+                    <input
+                      type='number'
+                      value={heartbeat.intervalSeconds ?? 60}
+                      onChange={(e) =>
+                        handleHeartbeatChange({
+                          intervalSeconds: Math.max(
+                            5,
+                            parseInt(e.target.value) || 60
+                          ),
+                        })
+                      }
+                      className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                      min='5'
+                    />
+                  </div>
+                </div>
+                {heartbeat.endpoint && (
+                  <div>
+                    <label className='mb-1 block text-sm font-medium text-gray-700'>
+                      인증 토큰 (선택)
+                    </label>
+                    <input
+                      type='text'
+                      value={heartbeat.token ?? ''}
+                      onChange={(e) =>
+                        handleHeartbeatChange({
+                          token: e.target.value || undefined,
+                        })
+                      }
+                      className='w-full rounded-lg border border-gray-300 px-3 py-2 font-mono focus:ring-2 focus:ring-blue-500'
+                    />
+                    <p className='mt-1 text-xs text-gray-500'>
+                      암호화되지 않은 HTTP로 전송되므로 토큰은 같은 PC의 주소
+                      (127.0.0.1)로만 보냅니다.
+                    </p>
+                  </div>
+                )}
+                <p className='text-xs text-gray-500'>
+                  빌드 해시, 현재 페이지, 실행 시간, 마지막 에러, 콘텐츠 무결성
+                  확인 결과, 남은 메모리를 앱 데이터 폴더의 status.json에
+                  기록하고 서버 주소가 있으면 JSON으로 POST합니다. 기록 시각이
+                  주기보다 오래 갱신되지 않으면 멈춘 것으로 판단할 수 있습니다.
+                </p>
+              </div>
+            )}
+
+            <div>
+              <label className='mb-1 block text-sm font-medium text-gray-700'>
+                로그 수준
+              </label>
+              <select
+                value={project.settings.logLevel ?? 'info'}
+                onChange={(e) =>
+                  handleSettingsChange(
+                    'logLevel',
+                    e.target.value === 'info' ? undefined : e.target.value
+                  )
+                }
+                className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+              >
+                <option value='error'>에러만</option>
+                <option value='warn'>경고 이상</option>
+                <option value='info'>일반 (기본)</option>
+                <option value='debug'>디버그</option>
+                <option value='trace'>전체 추적</option>
+              </select>
+              <p className='mt-1 text-xs text-gray-500'>
+                플레이어는 앱 로그 폴더에 player.log를 기록합니다 (5MB마다
+                교체, 최근 5개 보관). 현장에서는 --log-level debug 실행 인자로
+                수준을 바꾸고 --export-diagnostics 진단.zip으로 로그와 환경
+                정보를 묶을 수 있습니다.
+              </p>
+            </div>
+          </div>
+        </section>
+
+        {/* 재생 일정 */}
+        <section className='border-t border-gray-200 pt-6'>
+          <h3 className='mb-1 text-lg font-semibold text-gray-800'>
+            재생 일정
+          </h3>
+          <p className='mb-4 text-xs text-gray-500'>
+            여러 튜토리얼을 함께 빌드하면 일정에 맞는 튜토리얼로 자동
+            전환합니다 (대기 화면 복귀 시). 날짜를 지정한 일정이 우선합니다.
+          </p>
+
+          <div className='space-y-3'>
+            {schedule.map((rule, index) => (
+              <div
+                key={index}
+                className='space-y-3 rounded-lg border border-gray-200 p-3'
+              >
+                <div className='flex items-center justify-between'>
+                  <div className='flex gap-1'>
+                    {WEEKDAY_LABELS.map((label, weekday) => (
+                      <button
+                        key={weekday}
+                        type='button'
+                        onClick={() => toggleScheduleWeekday(index, weekday)}
+                        className={`h-8 w-8 rounded-full text-sm ${
+                          rule.weekdays?.includes(weekday)
+                            ? 'bg-blue-600 text-white'
+                            : 'bg-gray-100 text-gray-700 hover:bg-gray-200'
+                        }`}
+                      >
+                        {label}
+                      </button>
+                    ))}
+                  </div>
+                  <button
+                    type='button'
+                    onClick={() =>
+                      handleSettingsChange(
+                        'schedule',
+                        schedule.filter((_, i) => i !== index)
+                      )
+                    }
+                    className='text-sm text-red-600 hover:text-red-700'
+                  >
+                    삭제
+                  </button>
+                </div>
+                <div className='grid grid-cols-2 gap-3'>
+                  <div>
+                    <label className='mb-1 block text-sm font-medium text-gray-700'>
+                      시간
+                    </label>
+                    <div className='flex items-center gap-2'>
+                      <input
+                        type='time'
+                        value={rule.startTime ?? ''}
+                        onChange={(e) =>
+                          handleScheduleChange(index, {
+                            startTime: e.target.value || undefined,
+                          })
+                        }
+                        className='w-full rounded-lg border border-gray-300 px-2 py-1 focus:ring-2 focus:ring-blue-500'
+                      />
+                      <span className='text-gray-500'>~</span>
+                      <input
+                        type='time'
+                        value={rule.endTime ?? ''}
+                        onChange={(e) =>
+                          handleScheduleChange(index, {
+                            endTime: e.target.value || undefined,
+                          })
+                        }
+                        className='w-full rounded-lg border border-gray-300 px-2 py-1 focus:ring-2 focus:ring-blue-500'
+                      />
+                    </div>
+                  </div>
+                  <div>
+                    <label className='mb-1 block text-sm font-medium text-gray-700'>
+                      기간
+                    </label>
+                    <div className='flex items-center gap-2'>
+                      <input
+                        type='date'
+                        value={rule.startDate ?? ''}
+                        onChange={(e) =>
+                          handleScheduleChange(index, {
+                            startDate: e.target.value || undefined,
+                          })
+                        }
+                        className='w-full rounded-lg border border-gray-300 px-2 py-1 focus:ring-2 focus:ring-blue-500'
+                      />
+                      <span className='text-gray-500'>~</span>
+                      <input
+                        type='date'
+                        value={rule.endDate ?? ''}
+                        onChange={(e) =>
+                          handleScheduleChange(index, {
+                            endDate: e.target.value || undefined,
+                          })
+                        }
+                        className='w-full rounded-lg border border-gray-300 px-2 py-1 focus:ring-2 focus:ring-blue-500'
+                      />
+                    </div>
+                  </div>
+                </div>
+              </div>
+            ))}
+            <button
+              type='button'
+              onClick={() => handleSettingsChange('schedule', [...schedule, {}])}
+              className='rounded-lg border border-gray-300 px-3 py-2 text-sm text-gray-700 hover:bg-gray-50'
+            >
+              일정 추가
+            </button>
+          </div>
+        </section>
+
+        {/* 언어 */}
+        <section className='border-t border-gray-200 pt-6'>
+          <h3 className='mb-1 text-lg font-semibold text-gray-800'>언어</h3>
+          <p className='mb-4 text-xs text-gray-500'>
+            두 개 이상 선택하면 플레이어에 언어 선택 버튼이 표시됩니다. 기본
+            언어 외의 제목, 미디어, 버튼 이미지는 페이지 편집에서 지정합니다.
+          </p>
+
+          <div className='space-y-4'>
+            <div className='flex flex-wrap gap-4'>
+              {Object.entries(LOCALE_LABELS).map(([locale, label]) => (
+                <label key={locale} className='flex items-center'>
+                  <input
+                    type='checkbox'
+                    checked={selectedLocales.includes(locale)}
+                    onChange={() => toggleLocale(locale)}
+                    className='mr-2 h-4 w-4 rounded text-blue-600 focus:ring-2 focus:ring-blue-500'
+                  />
+                  <span className='text-sm text-gray-700'>{label}</span>
+                </label>
+              ))}
+            </div>
+
+            {selectedLocales.length > 1 && (
+              <div>
+                <label className='mb-2 block text-sm font-medium text-gray-700'>
+                  기본 언어
+                </label>
+                <select
+                  value={defaultLocale}
+                  onChange={(e) =>
+                    handleSettingsChange('defaultLocale', e.target.value)
+                  }
+                  className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                >
+                  {selectedLocales.map((locale) => (
+                    <option key={locale} value={locale}>
+                      {LOCALE_LABELS[locale] ?? locale}
+                    </option>
+                  ))}
+                </select>
+              </div>
+            )}
+
+            {getProjectLocales(project)
+              .slice(1)
+              .map((locale) => (
+                <div key={locale}>
+                  <label className='mb-2 block text-sm font-medium text-gray-700'>
+                    앱 타이틀 ({LOCALE_LABELS[locale] ?? locale})
+                  </label>
+                  <input
+                    type='text'
+                    value={project.localizedAppTitle?.[locale] ?? ''}
+                    onChange={(e) =>
+                      handleLocalizedTitleChange(locale, e.target.value)
+                    }
+                    placeholder={project.appTitle}
+                    className='w-full rounded-lg border border-gray-300 px-3 py-2 focus:ring-2 focus:ring-blue-500'
+                  />
+                </div>
+              ))}
+          </div>
+        </section>
       </div>
     </div>
   )
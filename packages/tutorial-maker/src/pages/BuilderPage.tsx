@@ -1,11 +1,16 @@
 import React, { useState, useEffect } from 'react'
 import { invoke } from '@tauri-apps/api/core'
-import { save } from '@tauri-apps/plugin-dialog'
+import { open, save } from '@tauri-apps/plugin-dialog'
 import {
   ConfirmDialog,
+  collectProjectAssetIds,
+  getProjectLocales,
   type Project,
   type Page,
   type CompressionSettings,
+  type EncryptionSettings,
+  type LicenseSettings,
+  type SplitSettings,
 } from '@viswave/shared'
 import BuildDialog from '../components/builder/BuildDialog'
 import FlowMap from '../components/builder/FlowMap'
@@ -21,6 +26,11 @@ import {
   getButtonImage,
   createBlobURL,
 } from '../utils/mediaStorage'
+import {
+  formatBuildError,
+  formatBuildWarning,
+  type BuildWarning,
+} from '../utils/buildError'
 import { validateAllPages } from '../utils/pageValidation'
 import { saveBlobToTempFile } from '../utils/tempFile'
 
@@ -51,10 +61,182 @@ interface ExportMediaFile {
 interface ExportRequest {
   outputPath: string
   projectJson: string
+  /** 함께 넣을 다른 프로젝트 (실행 시 선택 화면 표시, 미디어는 공유) */
+  additionalProjects?: string[]
+  mediaFiles: ExportMediaFile[]
+  buttonFiles: ExportMediaFile[]
+  appIcon: number[] | null
+  compression?: CompressionSettings
+  encryption?: EncryptionSettings
+  license?: LicenseSettings
+  split?: SplitSettings
+  verify?: boolean
+  /** 빌드 보고서를 실행 파일 옆에 JSON/HTML로 저장 */
+  saveReport?: boolean
+}
+
+interface PatchRequest {
+  targetPath: string
+  projectJson: string
+  additionalProjects?: string[]
   mediaFiles: ExportMediaFile[]
   buttonFiles: ExportMediaFile[]
   appIcon: number[] | null
   compression?: CompressionSettings
+  passphrase?: string
+  license?: LicenseSettings
+}
+
+interface EmbedResult {
+  /** 내용이 같은 파일을 한 번만 저장하여 절약한 바이트 수 */
+  deduplicatedBytes: number
+  /** 실행 파일과 함께 배포해야 하는 데이터 팩 파일 */
+  packFiles?: string[]
+}
+
+/** 빌드 보고서 (src-tauri/src/report.rs의 BuildReport) */
+interface BuildReport {
+  makerVersion: string
+  createdAtMs: number
+  elapsedMs: number
+  outputPath: string
+  packFiles: string[]
+  /** 최종 크기 (exe + 데이터 팩) */
+  finalSize: number
+  originalSize: number
+  deduplicatedBytes: number
+  encrypted: boolean
+  compression?: CompressionSettings
+  media: BuildReportEntry[]
+  buttons: BuildReportEntry[]
+  warnings: BuildWarning[]
+}
+
+interface BuildReportEntry {
+  id: string
+  name: string
+  mimeType: string
+  originalSize: number
+  finalSize: number
+  compressionRatio: number
+  codec?: string
+  durationSeconds?: number
+  transcoded: boolean
+  encoding?: 'zstd'
+  hash?: string
+  offset: number
+  deduplicated: boolean
+}
+
+interface ExportResult extends EmbedResult {
+  report: BuildReport
+  /** 저장된 보고서 파일 (saveReport일 때만) */
+  reportFiles: string[]
+}
+
+interface PatchResult extends EmbedResult {
+  writtenEntries: number
+  reusedEntries: number
+  appendedBytes: number
+}
+
+interface PreflightCheck {
+  id: string
+  status: 'ok' | 'warning' | 'error'
+  message: string
+}
+
+/** 빌드 전 점검 결과 */
+interface PreflightReport {
+  ok: boolean
+  sourceBytes: number
+  estimatedOutputBytes: number
+  estimatedTempBytes: number
+  outputFreeBytes: number | null
+  tempFreeBytes: number | null
+  checks: PreflightCheck[]
+}
+
+const formatMegabytes = (bytes: number): string =>
+  (bytes / (1024 * 1024)).toFixed(1)
+
+interface ExportFiles {
+  mediaFiles: ExportMediaFile[]
+  buttonFiles: ExportMediaFile[]
+  appIcon: number[] | null
+}
+
+/** 백엔드로 전달할 파일 항목 생성 (대용량은 임시 파일로 저장) */
+const toExportMediaFile = async (file: {
+  id: string
+  name: string
+  blob: Blob
+}): Promise<ExportMediaFile> => {
+  if (file.blob.size >= LARGE_FILE_THRESHOLD) {
+    // 대용량 파일: 임시 파일로 저장하고 경로 전달
+    const tempPath = await saveBlobToTempFile(file.blob, file.name)
+    return {
+      id: file.id,
+      name: file.name,
+      mimeType: file.blob.type,
+      path: tempPath,
+    }
+  }
+
+  // 소용량 파일: 메모리에서 직접 전달
+  const arrayBuffer = await file.blob.arrayBuffer()
+  return {
+    id: file.id,
+    name: file.name,
+    mimeType: file.blob.type,
+    data: Array.from(new Uint8Array(arrayBuffer)),
+  }
+}
+
+/**
+ * 프로젝트에서 사용하는 미디어, 버튼 이미지, 앱 아이콘 수집
+ * 여러 프로젝트를 함께 넣으면 미디어는 한 번만 수집하고 앱 아이콘은 첫 번째 프로젝트 것을 사용
+ */
+const collectExportFiles = async (
+  projects: Project[]
+): Promise<ExportFiles> => {
+  // 모든 언어의 미디어와 버튼 이미지 ID (프로젝트 간 중복 제거)
+  const mediaIds = new Set<string>()
+  const buttonImageIds = new Set<string>()
+  for (const project of projects) {
+    const assets = collectProjectAssetIds(project)
+    assets.mediaIds.forEach((id) => mediaIds.add(id))
+    assets.buttonImageIds.forEach((id) => buttonImageIds.add(id))
+  }
+
+  const mediaFiles: ExportMediaFile[] = []
+  for (const mediaId of mediaIds) {
+    const media = await getMediaFile(mediaId)
+    if (media) {
+      mediaFiles.push(await toExportMediaFile(media))
+    }
+  }
+
+  // 버튼 이미지 수집 (일반적으로 소용량)
+  const buttonFiles: ExportMediaFile[] = []
+  for (const imageId of buttonImageIds) {
+    const image = await getButtonImage(imageId)
+    if (image) {
+      buttonFiles.push(await toExportMediaFile(image))
+    }
+  }
+
+  let appIcon: number[] | null = null
+  const project = projects[0]
+  if (project?.appIcon) {
+    const icon = await getAppIcon(project.appIcon)
+    if (icon) {
+      const arrayBuffer = await icon.blob.arrayBuffer()
+      appIcon = Array.from(new Uint8Array(arrayBuffer))
+    }
+  }
+
+  return { mediaFiles, buttonFiles, appIcon }
 }
 
 type View = 'list' | 'settings' | 'pages'
@@ -268,9 +450,41 @@ const BuilderPage: React.FC<BuilderPageProps> = ({ onPreview }) => {
     setBuildDialogOpen(true)
   }
 
+  // 함께 넣을 프로젝트 확인 (빌드할 수 없는 프로젝트가 있으면 null)
+  const resolveBundledProjects = (ids: string[]): Project[] | null => {
+    const bundled = projects.filter(
+      (project) =>
+        project.id !== selectedProject?.id && ids.includes(project.id)
+    )
+    for (const project of bundled) {
+      const title = project.appTitle || project.name
+      if (project.pages.length === 0) {
+        alert(`빌드할 수 없습니다. ${title}: 페이지가 없습니다.`)
+        return null
+      }
+      if (!validateAllPages(project.pages).isValid) {
+        alert(
+          `빌드할 수 없습니다. ${title}: 완성되지 않은 페이지가 있습니다.`
+        )
+        return null
+      }
+    }
+    return bundled
+  }
+
   // 실행 파일 빌드
-  const handleBuild = async (compression: CompressionSettings) => {
+  const handleBuild = async (
+    compression: CompressionSettings,
+    encryption: EncryptionSettings,
+    license: LicenseSettings,
+    split: SplitSettings,
+    verify: boolean,
+    saveReport: boolean,
+    bundledProjectIds: string[]
+  ) => {
     if (!selectedProject) return
+    const bundledProjects = resolveBundledProjects(bundledProjectIds)
+    if (!bundledProjects) return
     setIsBuilding(true)
 
     try {
@@ -284,99 +498,182 @@ const BuilderPage: React.FC<BuilderPageProps> = ({ onPreview }) => {
         return
       }
 
-      // 미디어 파일 수집 (대용량은 임시 파일로 저장)
-      const mediaFiles: ExportMediaFile[] = []
-      for (const page of selectedProject.pages) {
-        if (page.mediaId) {
-          const media = await getMediaFile(page.mediaId)
-          if (media) {
-            if (media.blob.size >= LARGE_FILE_THRESHOLD) {
-              // 대용량 파일: 임시 파일로 저장하고 경로 전달
-              const tempPath = await saveBlobToTempFile(media.blob, media.name)
-              mediaFiles.push({
-                id: media.id,
-                name: media.name,
-                mimeType: media.blob.type,
-                path: tempPath,
-              })
-            } else {
-              // 소용량 파일: 메모리에서 직접 전달
-              const arrayBuffer = await media.blob.arrayBuffer()
-              mediaFiles.push({
-                id: media.id,
-                name: media.name,
-                mimeType: media.blob.type,
-                data: Array.from(new Uint8Array(arrayBuffer)),
-              })
-            }
-          }
-        }
+      const { mediaFiles, buttonFiles, appIcon } = await collectExportFiles([
+        selectedProject,
+        ...bundledProjects,
+      ])
+
+      const request: ExportRequest = {
+        outputPath,
+        projectJson: JSON.stringify(selectedProject),
+        additionalProjects: bundledProjects.map((project) =>
+          JSON.stringify(project)
+        ),
+        mediaFiles,
+        buttonFiles,
+        appIcon,
+        compression,
+        encryption,
+        license,
+        split,
+        verify,
+        saveReport,
       }
 
-      // 버튼 이미지 수집 (일반적으로 소용량)
-      const buttonFiles: ExportMediaFile[] = []
-      const processedButtonIds = new Set<string>()
-      for (const page of selectedProject.pages) {
-        for (const button of page.buttons) {
-          if (button.imageId && !processedButtonIds.has(button.imageId)) {
-            processedButtonIds.add(button.imageId)
-            const image = await getButtonImage(button.imageId)
-            if (image) {
-              if (image.blob.size >= LARGE_FILE_THRESHOLD) {
-                const tempPath = await saveBlobToTempFile(
-                  image.blob,
-                  image.name
-                )
-                buttonFiles.push({
-                  id: image.id,
-                  name: image.name,
-                  mimeType: image.blob.type,
-                  path: tempPath,
-                })
-              } else {
-                const arrayBuffer = await image.blob.arrayBuffer()
-                buttonFiles.push({
-                  id: image.id,
-                  name: image.name,
-                  mimeType: image.blob.type,
-                  data: Array.from(new Uint8Array(arrayBuffer)),
-                })
-              }
-            }
-          }
-        }
+      // 빌드 전 점검 (도구, 쓰기 권한, 여유 공간)
+      const report = await invoke<PreflightReport>('preflight_export', {
+        request,
+      })
+      const estimate = `예상 크기: ${formatMegabytes(report.estimatedOutputBytes)}MB`
+      const errors = report.checks.filter((check) => check.status === 'error')
+      if (!report.ok) {
+        alert(
+          `빌드할 수 없습니다.\n\n${errors.map((check) => check.message).join('\n')}\n\n${estimate}`
+        )
+        return
+      }
+      const warnings = report.checks.filter(
+        (check) => check.status === 'warning'
+      )
+      if (
+        warnings.length > 0 &&
+        !confirm(
+          `${warnings.map((check) => check.message).join('\n')}\n\n${estimate}\n계속 빌드하시겠습니까?`
+        )
+      ) {
+        return
       }
 
-      let appIcon: number[] | null = null
-      if (selectedProject.appIcon) {
-        const icon = await getAppIcon(selectedProject.appIcon)
-        if (icon) {
-          const arrayBuffer = await icon.blob.arrayBuffer()
-          appIcon = Array.from(new Uint8Array(arrayBuffer))
-        }
+      const result = await invoke<ExportResult>('export_as_executable', {
+        request,
+      })
+      setBuildDialogOpen(false)
+      const dedupMessage =
+        result.deduplicatedBytes > 0
+          ? `\n중복 파일 제거로 ${formatMegabytes(result.deduplicatedBytes)}MB 절약`
+          : ''
+      const packMessage =
+        result.packFiles && result.packFiles.length > 0
+          ? `\n\n함께 배포할 데이터 팩:\n${result.packFiles.join('\n')}`
+          : ''
+      const { report: buildReport } = result
+      const summaryMessage = `\n최종 크기 ${formatMegabytes(buildReport.finalSize)}MB, 빌드 시간 ${(buildReport.elapsedMs / 1000).toFixed(1)}초`
+      const warningMessage =
+        buildReport.warnings.length > 0
+          ? `\n\n경고 ${buildReport.warnings.length}건:\n${buildReport.warnings
+              .map(formatBuildWarning)
+              .join('\n')}`
+          : ''
+      const reportMessage =
+        result.reportFiles.length > 0
+          ? `\n\n빌드 보고서:\n${result.reportFiles.join('\n')}`
+          : ''
+      alert(
+        `실행파일이 생성되었습니다: ${outputPath}${summaryMessage}${dedupMessage}${packMessage}${warningMessage}${reportMessage}`
+      )
+    } catch (error) {
+      console.error('Build failed:', error)
+      await offerDiagnosticsExport(
+        '빌드에 실패했습니다: ' + formatBuildError(error)
+      )
+    } finally {
+      setIsBuilding(false)
+    }
+  }
+
+  // 빌드 실패 시 문제 보고용 진단 번들 (로그, 환경 정보) 저장 제안
+  const offerDiagnosticsExport = async (message: string) => {
+    if (
+      !confirm(
+        `${message}\n\n문제 보고용 진단 정보(빌드 로그, 환경 정보)를 저장하시겠습니까?`
+      )
+    ) {
+      return
+    }
+
+    try {
+      const outputPath = await save({
+        defaultPath: 'tutorial-maker-diagnostics.zip',
+        filters: [{ name: 'Zip', extensions: ['zip'] }],
+      })
+      if (!outputPath) return
+
+      await invoke<number>('export_diagnostics', { outputPath })
+      alert(`진단 정보를 저장했습니다: ${outputPath}`)
+    } catch (error) {
+      console.error('Diagnostics export failed:', error)
+      alert('진단 정보 저장에 실패했습니다: ' + formatBuildError(error))
+    }
+  }
+
+  // 기존 실행 파일 업데이트 (변경된 파일만 추가)
+  const handlePatch = async (
+    compression: CompressionSettings,
+    passphrase: string | undefined,
+    license: LicenseSettings | undefined,
+    bundledProjectIds: string[]
+  ) => {
+    if (!selectedProject) return
+    const bundledProjects = resolveBundledProjects(bundledProjectIds)
+    if (!bundledProjects) return
+    setIsBuilding(true)
+
+    try {
+      const selected = await open({
+        multiple: false,
+        filters: [{ name: 'Executable', extensions: ['exe', 'pak'] }],
+      })
+
+      if (!selected || Array.isArray(selected)) {
+        setIsBuilding(false)
+        return
       }
 
-      const request: ExportRequest = {
-        outputPath,
+      const { mediaFiles, buttonFiles, appIcon } = await collectExportFiles([
+        selectedProject,
+        ...bundledProjects,
+      ])
+
+      const request: PatchRequest = {
+        targetPath: selected,
         projectJson: JSON.stringify(selectedProject),
+        additionalProjects: bundledProjects.map((project) =>
+          JSON.stringify(project)
+        ),
         mediaFiles,
         buttonFiles,
         appIcon,
         compression,
+        passphrase,
+        license,
       }
 
-      await invoke('export_as_executable', { request })
+      const result = await invoke<PatchResult>('patch_executable', {
+        request,
+      })
       setBuildDialogOpen(false)
-      alert(`실행파일이 생성되었습니다: ${outputPath}`)
+
+      const appendedMb = formatMegabytes(result.appendedBytes)
+      const dedupMessage =
+        result.deduplicatedBytes > 0
+          ? `, 중복 제거 ${formatMegabytes(result.deduplicatedBytes)}MB`
+          : ''
+      const shouldCompact = confirm(
+        `실행파일이 업데이트되었습니다: ${selected}\n` +
+          `변경 ${result.writtenEntries}개, 유지 ${result.reusedEntries}개 (${appendedMb}MB 추가${dedupMessage})\n\n` +
+          '사용되지 않는 이전 데이터를 정리하여 파일 크기를 줄이시겠습니까?'
+      )
+
+      if (shouldCompact) {
+        await invoke<number>('compact_executable', {
+          request: { targetPath: selected, passphrase },
+        })
+      }
     } catch (error) {
-      console.error('Build failed:', error)
-      const errorMessage =
-        typeof error === 'string'
-          ? error
-          : error instanceof Error
-            ? error.message
-            : String(error)
-      alert('빌드에 실패했습니다: ' + errorMessage)
+      console.error('Patch failed:', error)
+      await offerDiagnosticsExport(
+        '업데이트에 실패했습니다: ' + formatBuildError(error)
+      )
     } finally {
       setIsBuilding(false)
     }
@@ -551,8 +848,15 @@ const BuilderPage: React.FC<BuilderPageProps> = ({ onPreview }) => {
         isOpen={buildDialogOpen}
         onClose={() => setBuildDialogOpen(false)}
         onBuild={handleBuild}
+        onPatch={handlePatch}
         isBuilding={isBuilding}
         videoCount={videoCount}
+        otherProjects={projects
+          .filter((project) => project.id !== selectedProject?.id)
+          .map((project) => ({
+            id: project.id,
+            name: project.appTitle || project.name,
+          }))}
       />
 
       {/* 메인 콘텐츠 */}
@@ -774,6 +1078,7 @@ const BuilderPage: React.FC<BuilderPageProps> = ({ onPreview }) => {
                     page={selectedPage}
                     onUpdate={handlePageUpdate}
                     pages={selectedProject.pages}
+                    locales={getProjectLocales(selectedProject).slice(1)}
                   />
                 </div>
               </div>
@@ -9,6 +9,7 @@ import {
   getButtonImage,
   getAppIcon,
 } from '../utils/mediaStorage'
+import { formatBuildError } from '../utils/buildError'
 import { saveBlobToTempFile } from '../utils/tempFile'
 
 /**
@@ -170,8 +171,7 @@ const ProductPage: React.FC<ProductPageProps> = ({ projectId }) => {
       alert(`실행파일이 생성되었습니다:\n${outputPath}`)
     } catch (err) {
       console.error('Export failed:', err)
-      const message = err instanceof Error ? err.message : 'Export failed'
-      alert(`내보내기 실패: ${message}`)
+      alert(`내보내기 실패: ${formatBuildError(err)}`)
     } finally {
       setIsExporting(false)
     }
@@ -0,0 +1,117 @@
+import { describe, it, expect } from 'vitest'
+import {
+  describeBuildError,
+  formatBuildError,
+  formatBuildWarning,
+  isBuildError,
+  type BuildError,
+  type BuildWarning,
+} from './buildError'
+
+describe('isBuildError', () => {
+  it('should accept objects with a code', () => {
+    expect(isBuildError({ code: 'FfmpegNotFound' })).toBe(true)
+  })
+
+  it('should reject strings, errors and plain objects', () => {
+    expect(isBuildError('FFmpeg failed')).toBe(false)
+    expect(isBuildError(new Error('boom'))).toBe(false)
+    expect(isBuildError({ message: 'boom' })).toBe(false)
+    expect(isBuildError(null)).toBe(false)
+  })
+})
+
+describe('describeBuildError', () => {
+  it('should include the missing source path and a fix', () => {
+    const description = describeBuildError({
+      code: 'SourceMissing',
+      path: 'C:\\media\\intro.mp4',
+    })
+    expect(description.message).toContain('C:\\media\\intro.mp4')
+    expect(description.hint).toBeDefined()
+  })
+
+  it('should handle disk full without a path', () => {
+    const description = describeBuildError({ code: 'DiskFull', path: null })
+    expect(description.message).toBe('디스크 공간이 부족합니다.')
+  })
+
+  it('should keep the encoder message as detail', () => {
+    const description = describeBuildError({
+      code: 'EncodeFailed',
+      file: 'intro.mp4',
+      detail: 'Invalid data found when processing input',
+    })
+    expect(description.message).toContain('intro.mp4')
+    expect(description.detail).toBe('Invalid data found when processing input')
+  })
+
+  it('should describe the cause of a failed verification', () => {
+    const cause: BuildError = {
+      code: 'CorruptData',
+      offset: 1024,
+      detail: 'Region is truncated',
+    }
+    const description = describeBuildError({
+      code: 'VerificationFailed',
+      cause,
+    })
+    expect(description.message).toContain('빌드 검증에 실패했습니다')
+    expect(description.message).toContain('1024')
+    expect(description.detail).toBe('Region is truncated')
+    expect(description.hint).toBe(describeBuildError(cause).hint)
+  })
+
+  it('should fall back to the raw error for unknown codes', () => {
+    const future = { code: 'SomethingNew', value: 1 } as unknown as BuildError
+    expect(describeBuildError(future).message).toContain('SomethingNew')
+  })
+})
+
+describe('formatBuildError', () => {
+  it('should pass through strings and Error messages', () => {
+    expect(formatBuildError('License expired')).toBe('License expired')
+    expect(formatBuildError(new Error('boom'))).toBe('boom')
+  })
+
+  it('should join message, detail and hint', () => {
+    const text = formatBuildError({
+      code: 'DecryptFailed',
+      detail: 'Invalid content key',
+    })
+    const [message, detail] = text.split('\n')
+    expect(message).toBe('암호화된 콘텐츠를 열 수 없습니다.')
+    expect(detail).toBe('Invalid content key')
+    expect(text).toContain('\n\n')
+  })
+
+  it('should describe license errors with the original message', () => {
+    const text = formatBuildError({
+      code: 'LicenseInvalid',
+      detail: 'Invalid expiry date',
+    })
+    expect(text.split('\n')[0]).toBe('라이선스 설정이 올바르지 않습니다.')
+    expect(text).toContain('Invalid expiry date')
+  })
+})
+
+describe('formatBuildWarning', () => {
+  it('should localize by code and prefix the file name', () => {
+    const text = formatBuildWarning({
+      code: 'largerAfterCompression',
+      file: 'intro.mp4',
+      originalSize: 1024 * 1024,
+      compressedSize: 2 * 1024 * 1024,
+      message: 'Compressed video is larger than the original (1.0 MB -> 2.0 MB)',
+    })
+    expect(text).toBe('intro.mp4: 압축 후 크기가 더 큽니다 (1.0MB → 2.0MB).')
+  })
+
+  it('should fall back to the English message for unknown codes', () => {
+    const future = {
+      code: 'somethingNew',
+      message: 'Something new happened',
+    } as unknown as BuildWarning
+    expect(formatBuildWarning(future)).toBe('Something new happened')
+  })
+})
@@ -0,0 +1,251 @@
+/** 빌드 명령이 반환하는 에러 (src-tauri/src/error.rs의 BuildError) */
+export type BuildError =
+  | { code: 'FfmpegNotFound' }
+  | { code: 'RceditNotFound' }
+  | { code: 'SourceMissing'; path: string }
+  | { code: 'DiskFull'; path: string | null }
+  | { code: 'PermissionDenied'; path: string | null }
+  | { code: 'NotExported' }
+  | { code: 'CorruptTrailer'; detail: string }
+  | { code: 'ManifestTooLarge'; size: number; available: number }
+  | { code: 'CorruptData'; offset: number; detail: string }
+  | { code: 'DecryptFailed'; detail: string }
+  | { code: 'InvalidProject'; detail: string }
+  | { code: 'InvalidSchedule'; project: string; detail: string }
+  | { code: 'DuplicateProject'; project: string }
+  | { code: 'NoProject' }
+  | { code: 'InvalidVolumeSize' }
+  | { code: 'ProbeFailed'; file: string; detail: string }
+  | { code: 'EncodeFailed'; file: string; detail: string }
+  | { code: 'InvalidIcon'; detail: string }
+  | { code: 'IconFailed'; detail: string }
+  | { code: 'EncryptFailed'; detail: string }
+  | { code: 'CompressFailed'; detail: string }
+  | { code: 'LicenseInvalid'; detail: string }
+  | { code: 'ManifestSerialize'; detail: string }
+  | { code: 'VerificationFailed'; cause: BuildError }
+  | { code: 'Io'; operation: string; path: string | null; detail: string }
+
+/** 사용자에게 보여줄 에러 설명 */
+export interface BuildErrorDescription {
+  message: string
+  /** 원인 (FFmpeg/OS 메시지 등 원문) */
+  detail?: string
+  /** 해결 방법 안내 */
+  hint?: string
+}
+
+export function isBuildError(error: unknown): error is BuildError {
+  return (
+    typeof error === 'object' &&
+    error !== null &&
+    typeof (error as { code?: unknown }).code === 'string'
+  )
+}
+
+const REBUILD_HINT = '프로젝트를 새로 빌드해주세요.'
+
+export function describeBuildError(error: BuildError): BuildErrorDescription {
+  switch (error.code) {
+    case 'FfmpegNotFound':
+      return {
+        message: 'FFmpeg를 찾을 수 없습니다.',
+        hint: 'FFmpeg를 설치하거나 resources 폴더에 ffmpeg.exe를 넣어주세요. (https://ffmpeg.org/download.html) 영상 압축을 끄면 FFmpeg 없이 빌드할 수 있습니다.',
+      }
+    case 'RceditNotFound':
+      return {
+        message: 'rcedit-x64.exe를 찾을 수 없습니다.',
+        hint: 'resources 폴더에 rcedit-x64.exe를 넣거나 앱 아이콘 없이 빌드해주세요.',
+      }
+    case 'SourceMissing':
+      return {
+        message: `원본 파일을 찾을 수 없습니다: ${error.path}`,
+        hint: '파일이 이동되거나 삭제되지 않았는지 확인하고 미디어를 다시 등록해주세요.',
+      }
+    case 'DiskFull':
+      return {
+        message: error.path
+          ? `디스크 공간이 부족합니다: ${error.path}`
+          : '디스크 공간이 부족합니다.',
+        hint: '출력 폴더와 임시 폴더 드라이브의 공간을 확보하거나 다른 드라이브에 저장해주세요.',
+      }
+    case 'PermissionDenied':
+      return {
+        message: error.path
+          ? `파일에 접근할 권한이 없습니다: ${error.path}`
+          : '파일에 접근할 권한이 없습니다.',
+        hint: '대상 실행 파일이 실행 중이면 종료하고, 읽기 전용 폴더가 아닌지 확인해주세요.',
+      }
+    case 'NotExported':
+      return {
+        message: 'Tutorial Maker로 내보낸 실행 파일이 아닙니다.',
+        hint: '업데이트할 exe 또는 .pak 파일을 다시 선택해주세요.',
+      }
+    case 'CorruptTrailer':
+      return {
+        message: '실행 파일의 임베딩 데이터가 손상되었습니다.',
+        detail: error.detail,
+        hint: REBUILD_HINT,
+      }
+    case 'ManifestTooLarge':
+      return {
+        message: '실행 파일의 데이터 목록 크기가 올바르지 않습니다.',
+        detail: `${error.size} bytes (사용 가능 ${error.available} bytes)`,
+        hint: REBUILD_HINT,
+      }
+    case 'CorruptData':
+      return {
+        message: `기록된 데이터가 손상되었습니다 (위치 ${error.offset}).`,
+        detail: error.detail,
+        hint: '저장 장치 상태를 확인하고 다시 빌드해주세요.',
+      }
+    case 'DecryptFailed':
+      return {
+        message: '암호화된 콘텐츠를 열 수 없습니다.',
+        detail: error.detail,
+        hint: '빌드할 때 사용한 잠금 해제 암호를 정확히 입력했는지 확인해주세요.',
+      }
+    case 'InvalidProject':
+      return {
+        message: '프로젝트 데이터를 읽을 수 없습니다.',
+        detail: error.detail,
+      }
+    case 'InvalidSchedule':
+      return {
+        message: `'${error.project}' 프로젝트의 재생 일정이 올바르지 않습니다.`,
+        detail: error.detail,
+        hint: '프로젝트 설정의 재생 일정을 확인해주세요.',
+      }
+    case 'DuplicateProject':
+      return {
+        message: `같은 프로젝트가 두 번 포함되었습니다: ${error.project}`,
+        hint: '함께 빌드할 튜토리얼 목록을 확인해주세요.',
+      }
+    case 'NoProject':
+      return { message: '빌드할 프로젝트가 없습니다.' }
+    case 'InvalidVolumeSize':
+      return {
+        message: '데이터 팩 볼륨 크기는 0보다 커야 합니다.',
+        hint: '빌드 설정의 볼륨 크기를 확인해주세요.',
+      }
+    case 'ProbeFailed':
+      return {
+        message: `영상 정보를 읽을 수 없습니다: ${error.file}`,
+        detail: error.detail,
+      }
+    case 'EncodeFailed':
+      return {
+        message: `영상 압축에 실패했습니다: ${error.file}`,
+        detail: error.detail,
+        hint: '영상 압축을 끄고 빌드하거나 다른 형식으로 변환한 영상을 사용해주세요.',
+      }
+    case 'InvalidIcon':
+      return {
+        message: '앱 아이콘 이미지를 변환할 수 없습니다.',
+        detail: error.detail,
+        hint: 'PNG 또는 JPEG 이미지를 사용해주세요.',
+      }
+    case 'IconFailed':
+      return {
+        message: '실행 파일에 앱 아이콘을 설정하지 못했습니다.',
+        detail: error.detail,
+        hint: '백신 프로그램이 rcedit를 차단하지 않았는지 확인하거나 앱 아이콘 없이 빌드해주세요.',
+      }
+    case 'EncryptFailed':
+      return {
+        message: '콘텐츠를 암호화하지 못했습니다.',
+        detail: error.detail,
+        hint: '디스크 공간을 확인하거나 암호화를 끄고 빌드해주세요.',
+      }
+    case 'CompressFailed':
+      return {
+        message: '데이터를 압축하지 못했습니다.',
+        detail: error.detail,
+      }
+    case 'LicenseInvalid':
+      return {
+        message: '라이선스 설정이 올바르지 않습니다.',
+        detail: error.detail,
+        hint: '빌드 설정의 사용 기간과 허용 PC 목록을 확인해주세요.',
+      }
+    case 'ManifestSerialize':
+      return {
+        message: '데이터 목록을 기록하지 못했습니다.',
+        detail: error.detail,
+        hint: REBUILD_HINT,
+      }
+    case 'VerificationFailed': {
+      const cause = describeBuildError(error.cause)
+      return {
+        ...cause,
+        message: `빌드 검증에 실패했습니다. ${cause.message}`,
+      }
+    }
+    case 'Io':
+      return {
+        message: error.path
+          ? `파일 작업에 실패했습니다 (${error.operation}): ${error.path}`
+          : `파일 작업에 실패했습니다 (${error.operation}).`,
+        detail: error.detail,
+      }
+    default:
+      // 새 버전의 백엔드가 보낸 모르는 code
+      return { message: JSON.stringify(error) }
+  }
+}
+
+/** 빌드 보고서의 경고 (src-tauri/src/report.rs의 ReportWarning) */
+export type BuildWarning = (
+  | { code: 'emptySource' }
+  | { code: 'probeUnavailable' }
+  | { code: 'probeFailed'; detail: string }
+  | {
+      code: 'largerAfterCompression'
+      originalSize: number
+      compressedSize: number
+    }
+  | { code: 'compressionFallback'; detail: string }
+  | { code: 'checksumsNotWritten'; detail: string }
+) & {
+  file?: string
+  /** 영어 설명 (모르는 code일 때 표시) */
+  message: string
+}
+
+const formatWarningBytes = (bytes: number): string =>
+  `${(bytes / (1024 * 1024)).toFixed(1)}MB`
+
+/** 빌드 경고를 표시용 문자열로 변환 (파일 이름이 있으면 앞에 붙임) */
+export function formatBuildWarning(warning: BuildWarning): string {
+  const { file, message: fallback } = warning
+  const message = (() => {
+    switch (warning.code) {
+      case 'emptySource':
+        return '원본 파일이 없어 빈 데이터로 저장되었습니다.'
+      case 'probeUnavailable':
+        return 'FFmpeg를 찾을 수 없어 영상 길이와 코덱을 확인하지 못했습니다.'
+      case 'probeFailed':
+        return `영상 정보를 읽을 수 없습니다: ${warning.detail}`
+      case 'largerAfterCompression':
+        return `압축 후 크기가 더 큽니다 (${formatWarningBytes(warning.originalSize)} → ${formatWarningBytes(warning.compressedSize)}).`
+      case 'compressionFallback':
+        return `영상 압축에 실패하여 원본을 사용했습니다: ${warning.detail}`
+      case 'checksumsNotWritten':
+        return `데이터 팩 체크섬을 기록하지 못했습니다: ${warning.detail}`
+      default:
+        // 새 버전의 백엔드가 보낸 모르는 code
+        return fallback
+    }
+  })()
+  return file ? `${file}: ${message}` : message
+}
+
+/** 빌드 명령의 에러를 표시용 문자열로 변환 (문자열/Error도 처리) */
+export function formatBuildError(error: unknown): string {
+  if (typeof error === 'string') return error
+  if (error instanceof Error) return error.message
+  if (!isBuildError(error)) return String(error)
+
+  const { message, detail, hint } = describeBuildError(error)
+  return [message, detail, hint && `\n${hint}`].filter(Boolean).join('\n')
+}